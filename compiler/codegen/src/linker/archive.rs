@@ -5,7 +5,9 @@ use std::path::{Path, PathBuf};
 use anyhow::anyhow;
 use log::debug;
 
-use firefly_session::Options;
+use firefly_session::{Lto, Options};
+
+use crate::meta::CompiledModule;
 
 pub use self::builder::LlvmArchiveBuilder;
 
@@ -50,6 +52,27 @@ pub trait ArchiveBuilder<'a> {
     fn new(options: &'a Options, output: &Path, input: Option<&Path>) -> Self;
 
     fn add_file(&mut self, path: &Path);
+
+    /// Adds the files compiled for `module`
+    ///
+    /// With LTO enabled, the module's bitcode is added in place of its object file, so that the
+    /// module is optimized together with the rest of the program when the archive is linked.  A
+    /// module without bitcode falls back to its object file.
+    fn add_module(&mut self, module: &CompiledModule, lto: &Lto) {
+        match module.bytecode() {
+            Some(bytecode) if *lto != Lto::No => self.add_file(bytecode),
+            _ => {
+                if let Some(object) = module.object() {
+                    self.add_file(object);
+                }
+
+                if let Some(dwarf_object) = module.dwarf_object.as_ref() {
+                    self.add_file(dwarf_object);
+                }
+            }
+        }
+    }
+
     fn remove_file(&mut self, name: &str);
     fn src_files(&mut self) -> Vec<String>;
 
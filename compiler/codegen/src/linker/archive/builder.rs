@@ -8,6 +8,9 @@ use firefly_session::Options;
 
 use super::ArchiveBuilder;
 
+#[cfg(test)]
+mod tests;
+
 struct ArchiveConfig<'a> {
     pub sess: &'a Options,
     pub dst: PathBuf,
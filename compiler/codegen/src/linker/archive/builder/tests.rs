@@ -0,0 +1,87 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use firefly_diagnostics::{CodeMap, Reporter};
+use firefly_intern::Symbol;
+use firefly_session::{CodegenOptions, DebuggingOptions, LtoCli, Options};
+
+use crate::meta::CompiledModule;
+
+use super::*;
+
+#[test]
+fn without_lto_add_module_adds_object_files() {
+    assert_eq!(
+        added_names(LtoCli::No, &compiled_module()),
+        vec!["init.o", "init.dwo"]
+    );
+}
+
+#[test]
+fn with_thin_lto_add_module_adds_bitcode_in_place_of_object_files() {
+    assert_eq!(
+        added_names(LtoCli::Thin, &compiled_module()),
+        vec!["init.bc"]
+    );
+}
+
+#[test]
+fn with_fat_lto_add_module_adds_bitcode_in_place_of_object_files() {
+    assert_eq!(
+        added_names(LtoCli::Fat, &compiled_module()),
+        vec!["init.bc"]
+    );
+}
+
+#[test]
+fn with_thin_lto_without_bitcode_add_module_adds_object_files() {
+    let module = CompiledModule {
+        bytecode: None,
+        ..compiled_module()
+    };
+
+    assert_eq!(
+        added_names(LtoCli::Thin, &module),
+        vec!["init.o", "init.dwo"]
+    );
+}
+
+fn compiled_module() -> CompiledModule {
+    CompiledModule {
+        name: Symbol::intern("init"),
+        object: Some(PathBuf::from("init.o")),
+        dwarf_object: Some(PathBuf::from("init.dwo")),
+        bytecode: Some(PathBuf::from("init.bc")),
+    }
+}
+
+/// The names `module` is added to a static archive under when linking with `lto`
+fn added_names(lto: LtoCli, module: &CompiledModule) -> Vec<String> {
+    let codemap = Arc::new(CodeMap::new());
+    let reporter = Reporter::new();
+    let mut codegen_options = CodegenOptions::default();
+    codegen_options.lto = lto;
+    let options = Options::new_with_defaults(
+        &reporter,
+        codemap,
+        codegen_options,
+        DebuggingOptions::default(),
+        std::env::temp_dir(),
+        &Default::default(),
+    )
+    .unwrap();
+
+    let mut builder = LlvmArchiveBuilder::new(&options, Path::new("libinit.a"), None);
+    builder.add_module(module, &options.lto());
+
+    builder
+        .additions
+        .iter()
+        .map(|addition| match addition {
+            Addition::File {
+                name_in_archive, ..
+            } => name_in_archive.clone(),
+            Addition::Archive { path, .. } => panic!("unexpected archive {}", path.display()),
+        })
+        .collect()
+}
@@ -179,14 +179,9 @@ fn link_staticlib<'a>(
 
     let mut ab = LlvmArchiveBuilder::new(options, output_file, None);
 
+    let lto = options.lto();
     for module in codegen_results.modules.iter() {
-        if let Some(obj) = module.object.as_ref() {
-            ab.add_file(obj);
-        }
-
-        if let Some(dwarf_obj) = module.dwarf_object.as_ref() {
-            ab.add_file(dwarf_obj);
-        }
+        ab.add_module(module, &lto);
     }
 
     ab.build();
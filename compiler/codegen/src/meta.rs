@@ -3,6 +3,7 @@ use std::path::{Path, PathBuf};
 
 use firefly_intern::Symbol;
 use firefly_llvm as llvm;
+use firefly_mlir::{self as mlir, Operation};
 use firefly_session::Options;
 use firefly_target::spec::PanicStrategy;
 use firefly_util::fs::NativeLibraryKind;
@@ -27,6 +28,40 @@ impl CompiledModule {
     }
 }
 
+/// Represents the size of the MLIR module produced by lowering a single module
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LoweringStats {
+    /// The number of functions, including declarations
+    pub functions: usize,
+    /// The number of blocks nested within the module body
+    pub blocks: usize,
+    /// The number of operations nested within the module body, at any depth
+    pub ops: usize,
+}
+impl LoweringStats {
+    /// Computes statistics for the given module by walking every operation nested within it
+    pub fn new(module: &mlir::Module) -> Self {
+        let mut stats = Self::default();
+        stats.visit_ops(module.body());
+        stats
+    }
+
+    fn visit_ops(&mut self, block: mlir::Block) {
+        for op in block.iter() {
+            self.ops += 1;
+            if op.isa::<mlir::FuncOp>() {
+                self.functions += 1;
+            }
+            for index in 0..op.num_regions() {
+                for block in op.get_region(index).iter() {
+                    self.blocks += 1;
+                    self.visit_ops(block);
+                }
+            }
+        }
+    }
+}
+
 /// Represents the result of compiling a single application
 #[derive(Debug)]
 pub struct CodegenResults {
@@ -1,5 +1,7 @@
 mod queries;
 mod query_groups;
+#[cfg(test)]
+mod tests;
 
 use std::path::PathBuf;
 use std::sync::Arc;
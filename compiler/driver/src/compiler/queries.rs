@@ -5,8 +5,9 @@ use log::debug;
 
 use firefly_codegen::meta::CompiledModule;
 use firefly_intern::Symbol;
-use firefly_session::OutputType;
+use firefly_session::{Lto, OutputType, ProjectType};
 use firefly_syntax_base::ApplicationMetadata;
+use firefly_target::RelocModel;
 
 use super::prelude::*;
 
@@ -124,9 +125,22 @@ where
     db.maybe_emit_file_with_opts(&options, input, &module)?;
 
     debug!("generating llvm for {:?} on {:?}", input, thread_id);
-    let mut translation =
-        TranslateMLIRToLLVMIR::new(llvm_context.borrow(), source_name.to_string());
+    let mut translation = TranslateMLIRToLLVMIR::new(
+        llvm_context.borrow(),
+        source_name.to_string(),
+        options.debug_info,
+    );
     let module = unwrap_or_bail!(db, translation.run(&module));
+    match options.relocation_model() {
+        RelocModel::Pic => module.set_pic_level(),
+        RelocModel::Pie => {
+            module.set_pic_level();
+            if options.project_type == ProjectType::Executable {
+                module.set_pie_level();
+            }
+        }
+        _ => (),
+    }
 
     // Verify/optimize
     let mut optimizer = PassManagerPass::new(&options, target_machine.handle());
@@ -135,16 +149,24 @@ where
     // Emit LLVM IR
     db.maybe_emit_file_with_opts(&options, input, &module)?;
 
-    // Emit LLVM bitcode
-    db.maybe_emit_file_with_callback_and_opts(
-        &options,
-        input,
-        OutputType::LLVMBitcode,
-        |outfile| {
-            debug!("emitting llvm bitcode for {:?}", input);
-            module.emit_bc(outfile)
-        },
-    )?;
+    // Emit LLVM bitcode, which LTO needs even when it wasn't requested
+    let emit_bc = |outfile: &mut std::fs::File| {
+        debug!("emitting llvm bitcode for {:?}", input);
+        module.emit_bc(outfile)
+    };
+    let bc_path = if options.lto() == Lto::No {
+        db.maybe_emit_file_with_callback_and_opts(
+            &options,
+            input,
+            OutputType::LLVMBitcode,
+            emit_bc,
+        )?
+    } else {
+        let filename = options
+            .output_types
+            .always_emit(&input_info, OutputType::LLVMBitcode);
+        Some(db.emit_file_with_callback(db.output_dir().join(filename), emit_bc)?)
+    };
 
     // Bail early if we don't plan to run the code generator
     if !options.output_types.should_codegen() {
@@ -169,11 +191,6 @@ where
     )?;
 
     // Gather compiled module metadata
-    let bc_path = options
-        .output_types
-        .maybe_emit(&input_info, OutputType::LLVMBitcode)
-        .map(|filename| db.output_dir().join(filename));
-
     let compiled = CompiledModule {
         name: module_sym,
         object: obj_path,
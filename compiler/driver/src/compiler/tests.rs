@@ -0,0 +1,79 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::thread;
+
+use firefly_diagnostics::{CodeMap, Reporter};
+use firefly_intern::Symbol;
+use firefly_session::{CodegenOptions, DebugInfo, DebuggingOptions, Input, Options};
+use firefly_syntax_base::ApplicationMetadata;
+
+use crate::commands::create_diagnostics_handler;
+
+use super::prelude::{Compiler as CompilerQueryGroup, *};
+use super::Compiler;
+
+const SOURCE: &'static str = r#"
+-module(init).
+-export([start/0]).
+
+start() ->
+    add(1, 2).
+
+add(A, B) ->
+    A + B.
+"#;
+
+/// Compiles `SOURCE` to an object file with the given level of debug info, and returns its contents
+fn object_bytes(debug_info: DebugInfo, output_dir: &str) -> Vec<u8> {
+    let codemap = Arc::new(CodeMap::new());
+    let reporter = Reporter::new();
+    let mut options = Options::new_with_defaults(
+        &reporter,
+        codemap.clone(),
+        CodegenOptions::default(),
+        DebuggingOptions::default(),
+        std::env::temp_dir(),
+        &Default::default(),
+    )
+    .unwrap();
+    options.debug_info = debug_info;
+    options.output_dir = Some(std::env::temp_dir().join(output_dir));
+    let diagnostics = create_diagnostics_handler(&options, codemap.clone(), None);
+    let mut db = Compiler::new(codemap, diagnostics);
+    db.set_options(Arc::new(options));
+
+    let input = db.intern_input(Input::new("init.erl", SOURCE));
+    let app = Arc::new(ApplicationMetadata {
+        name: Symbol::intern("test"),
+        modules: BTreeMap::new(),
+    });
+    let compiled = db
+        .compile(thread::current().id(), input, app)
+        .unwrap()
+        .expect("expected an object file to be generated");
+    let object = compiled.object().expect("expected an object file path");
+
+    std::fs::read(object).unwrap()
+}
+
+/// Section names are stored in the object as strings, `.debug_line` on ELF and `__debug_line` on
+/// Mach-O, so this finds either
+fn contains_debug_line(object: &[u8]) -> bool {
+    object
+        .windows(b"debug_line".len())
+        .any(|window| window == b"debug_line")
+}
+
+#[test]
+fn with_debug_info_object_contains_debug_line_section() {
+    let object = object_bytes(DebugInfo::Full, "firefly_with_debug_info");
+
+    assert!(contains_debug_line(&object));
+}
+
+#[test]
+fn without_debug_info_object_does_not_contain_debug_line_section() {
+    let object = object_bytes(DebugInfo::None, "firefly_without_debug_info");
+
+    assert!(!contains_debug_line(&object));
+}
@@ -1,5 +1,7 @@
 mod queries;
 mod query_groups;
+#[cfg(test)]
+mod tests;
 
 pub use self::query_groups::{Parser, ParserStorage};
 
@@ -4,6 +4,7 @@ use std::thread::ThreadId;
 
 use log::debug;
 
+use firefly_codegen::meta::LoweringStats;
 use firefly_diagnostics::{Reporter, ToDiagnostic};
 use firefly_intern::{symbols, Symbol};
 use firefly_llvm as llvm;
@@ -433,6 +434,34 @@ where
     Ok(module)
 }
 
+pub(crate) fn input_mlir_text<P>(
+    db: &P,
+    thread_id: ThreadId,
+    input: InternedInput,
+    app: Arc<ApplicationMetadata>,
+) -> Result<Arc<String>, ErrorReported>
+where
+    P: Parser,
+{
+    let module = db.input_mlir(thread_id, input, app)?;
+
+    Ok(Arc::new(module.to_string()))
+}
+
+pub(crate) fn input_mlir_stats<P>(
+    db: &P,
+    thread_id: ThreadId,
+    input: InternedInput,
+    app: Arc<ApplicationMetadata>,
+) -> Result<Arc<LoweringStats>, ErrorReported>
+where
+    P: Parser,
+{
+    let module = db.input_mlir(thread_id, input, app)?;
+
+    Ok(Arc::new(LoweringStats::new(&module)))
+}
+
 pub fn find_sources<D, P>(db: &D, dir: P) -> anyhow::Result<Vec<InternedInput>>
 where
     D: Parser,
@@ -2,6 +2,7 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use std::thread::ThreadId;
 
+use firefly_codegen::meta::LoweringStats;
 use firefly_intern::Symbol;
 use firefly_llvm as llvm;
 use firefly_mlir as mlir;
@@ -104,4 +105,30 @@ pub trait Parser: CompilerOutput {
         input: InternedInput,
         app: Arc<ApplicationMetadata>,
     ) -> Result<mlir::OwnedModule, ErrorReported>;
+
+    /// Gets the mlir module associated with the given input, printed in MLIR's textual form
+    ///
+    /// This is primarily intended for inspecting the output of lowering prior to translation to LLVM IR.
+    ///
+    /// If an error occurred during parsing or lowering of the module, the result will be Err(ErrorReported).
+    #[salsa::invoke(queries::input_mlir_text)]
+    fn input_mlir_text(
+        &self,
+        thread_id: ThreadId,
+        input: InternedInput,
+        app: Arc<ApplicationMetadata>,
+    ) -> Result<Arc<String>, ErrorReported>;
+
+    /// Gets statistics about the mlir module associated with the given input
+    ///
+    /// This is useful for spotting modules whose lowering produces an unexpectedly large amount of code.
+    ///
+    /// If an error occurred during parsing or lowering of the module, the result will be Err(ErrorReported).
+    #[salsa::invoke(queries::input_mlir_stats)]
+    fn input_mlir_stats(
+        &self,
+        thread_id: ThreadId,
+        input: InternedInput,
+        app: Arc<ApplicationMetadata>,
+    ) -> Result<Arc<LoweringStats>, ErrorReported>;
 }
@@ -0,0 +1,223 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::thread;
+
+use firefly_diagnostics::{CodeMap, Reporter};
+use firefly_intern::Symbol;
+use firefly_mlir::Operation;
+use firefly_session::{CodegenOptions, DebuggingOptions, Input, Options};
+use firefly_syntax_base::ApplicationMetadata;
+use firefly_syntax_ssa as syntax_ssa;
+use firefly_target::{CodeModel, RelocModel};
+
+use crate::commands::create_diagnostics_handler;
+use crate::compiler::Compiler;
+
+use super::prelude::*;
+
+/// Constructs a compiler database using the default options
+fn compiler() -> Compiler {
+    compiler_with_codegen_options(CodegenOptions::default())
+}
+
+/// Constructs a compiler database using the given codegen options, and defaults for everything else
+fn compiler_with_codegen_options(codegen_options: CodegenOptions) -> Compiler {
+    let codemap = Arc::new(CodeMap::new());
+    let reporter = Reporter::new();
+    let options = Options::new_with_defaults(
+        &reporter,
+        codemap.clone(),
+        codegen_options,
+        DebuggingOptions::default(),
+        std::env::temp_dir(),
+        &Default::default(),
+    )
+    .unwrap();
+    let diagnostics = create_diagnostics_handler(&options, codemap.clone(), None);
+    let mut db = Compiler::new(codemap, diagnostics);
+    db.set_options(Arc::new(options));
+    db
+}
+
+fn app() -> Arc<ApplicationMetadata> {
+    Arc::new(ApplicationMetadata {
+        name: Symbol::intern("test"),
+        modules: BTreeMap::new(),
+    })
+}
+
+/// Lowers `source` all the way to SSA, and renders every function in the resulting module as text
+fn ssa_text(source: &'static str) -> String {
+    let db = compiler();
+    let input = db.intern_input(Input::new("init.erl", source));
+    let module = db.input_ssa(input, app()).unwrap();
+
+    let mut buffer = Vec::new();
+    for function in module.functions.iter() {
+        syntax_ssa::write::write_function(&mut buffer, function).unwrap();
+    }
+    String::from_utf8(buffer).unwrap()
+}
+
+#[test]
+fn record_update_lowers_to_tuple_set() {
+    let ssa = ssa_text(
+        r#"
+-module(init).
+-export([update/1]).
+
+-record(person, {name, age}).
+
+update(Person) ->
+    Person#person{age = 42}.
+"#,
+    );
+
+    assert!(ssa.contains("tuple.set"), "expected tuple.set in:\n{}", ssa);
+    assert!(
+        !ssa.contains("erlang:setelement"),
+        "unexpected call to erlang:setelement in:\n{}",
+        ssa
+    );
+}
+
+#[test]
+fn input_mlir_text_contains_module_op() {
+    let db = compiler();
+    let input = db.intern_input(Input::new(
+        "init.erl",
+        r#"
+-module(init).
+-export([start/0]).
+
+start() ->
+    ok.
+"#,
+    ));
+    let mlir = db
+        .input_mlir_text(std::thread::current().id(), input, app())
+        .unwrap();
+
+    assert!(
+        mlir.contains("module @init"),
+        "expected module op header in:\n{}",
+        mlir
+    );
+}
+
+#[test]
+fn input_mlir_stats_counts_lowered_module() {
+    let db = compiler();
+    let input = db.intern_input(Input::new(
+        "init.erl",
+        r#"
+-module(init).
+-export([start/0]).
+
+start() ->
+    add(1, 2).
+
+add(A, B) ->
+    A + B.
+"#,
+    ));
+    let stats = db
+        .input_mlir_stats(std::thread::current().id(), input, app())
+        .unwrap();
+
+    assert!(stats.functions >= 2, "{:?}", stats);
+    assert!(stats.blocks >= 2, "{:?}", stats);
+    assert!(stats.ops > stats.functions, "{:?}", stats);
+}
+
+#[test]
+fn block_iter_yields_every_operation_in_order() {
+    let db = compiler();
+    let input = db.intern_input(Input::new(
+        "init.erl",
+        r#"
+-module(init).
+-export([start/0]).
+
+start() ->
+    add(1, 2).
+
+add(A, B) ->
+    A + B.
+"#,
+    ));
+    let module = db
+        .input_mlir(std::thread::current().id(), input, app())
+        .unwrap();
+    let body = module.body();
+
+    let mut expected = Vec::new();
+    let mut next = body.first();
+    while let Some(op) = next {
+        expected.push(op);
+        next = op.next();
+    }
+
+    assert!(expected.len() > 1, "expected a multi-op block");
+    assert_eq!(body.iter().collect::<Vec<_>>(), expected);
+}
+
+/// Creates the target machine for the current thread with `relocation_model` requested
+fn target_machine_reloc_model(relocation_model: RelocModel) -> RelocModel {
+    let mut codegen_options = CodegenOptions::default();
+    codegen_options.relocation_model = Some(relocation_model);
+    let db = compiler_with_codegen_options(codegen_options);
+
+    db.target_machine(thread::current().id()).reloc_model()
+}
+
+#[test]
+fn target_machine_uses_requested_relocation_model() {
+    for relocation_model in [
+        RelocModel::Static,
+        RelocModel::Pic,
+        RelocModel::DynamicNoPic,
+    ] {
+        assert_eq!(
+            target_machine_reloc_model(relocation_model),
+            relocation_model
+        );
+    }
+}
+
+#[test]
+fn target_machine_with_pie_relocation_model_is_pic() {
+    // PIE is recorded on each module, the target machine itself only knows it is PIC
+    assert_eq!(target_machine_reloc_model(RelocModel::Pie), RelocModel::Pic);
+}
+
+#[test]
+fn target_machine_uses_requested_code_model() {
+    let mut codegen_options = CodegenOptions::default();
+    codegen_options.code_model = Some(CodeModel::Large);
+    let db = compiler_with_codegen_options(codegen_options);
+
+    assert_eq!(
+        db.target_machine(thread::current().id()).code_model(),
+        CodeModel::Large
+    );
+}
+
+#[test]
+fn tuple_match_without_record_update_does_not_cast_to_sized_tuple() {
+    let ssa = ssa_text(
+        r#"
+-module(init).
+-export([first/1]).
+
+first({First, _}) ->
+    First.
+"#,
+    );
+
+    assert!(
+        !ssa.contains("tuple<"),
+        "unexpected cast to a sized tuple in:\n{}",
+        ssa
+    );
+}
@@ -7,6 +7,7 @@
 #include "llvm/IR/IRBuilder.h"
 #include "llvm/IR/Instructions.h"
 #include "llvm/IR/LLVMContext.h"
+#include "llvm/IR/Module.h"
 #include "llvm/Support/Alignment.h"
 
 using namespace llvm;
@@ -72,3 +73,11 @@ extern "C" LLVMValueRef LLVMFireflyBuildInvoke(
       calleeTy, callee, unwrap(thenBlk), unwrap(catchBlk),
       makeArrayRef(unwrap(args), numArgs), bundles, name));
 }
+
+extern "C" void LLVMFireflySetModulePICLevel(LLVMModuleRef m) {
+  unwrap(m)->setPICLevel(PICLevel::BigPIC);
+}
+
+extern "C" void LLVMFireflySetModulePIELevel(LLVMModuleRef m) {
+  unwrap(m)->setPIELevel(PIELevel::Large);
+}
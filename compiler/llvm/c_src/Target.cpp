@@ -240,6 +240,8 @@ llvm::Reloc::Model firefly::toLLVM(firefly::RelocModel model) {
   case firefly::RelocModel::Static:
     return llvm::Reloc::Static;
   case firefly::RelocModel::Pic:
+  // LLVM has no separate model for PIE, it is PIC plus the module's PIE level
+  case firefly::RelocModel::Pie:
     return llvm::Reloc::PIC_;
   case firefly::RelocModel::DynamicNoPic:
     return llvm::Reloc::DynamicNoPIC;
@@ -254,6 +256,50 @@ llvm::Reloc::Model firefly::toLLVM(firefly::RelocModel model) {
   }
 }
 
+firefly::CodeModel firefly::fromLLVM(llvm::CodeModel::Model cm) {
+  switch (cm) {
+  case llvm::CodeModel::Small:
+    return firefly::CodeModel::Small;
+  case llvm::CodeModel::Kernel:
+    return firefly::CodeModel::Kernel;
+  case llvm::CodeModel::Medium:
+    return firefly::CodeModel::Medium;
+  case llvm::CodeModel::Large:
+    return firefly::CodeModel::Large;
+  default:
+    return firefly::CodeModel::Other;
+  }
+}
+
+extern "C" firefly::CodeModel
+LLVMFireflyGetTargetMachineCodeModel(LLVMTargetMachineRef tm) {
+  return firefly::fromLLVM(unwrap(tm)->getCodeModel());
+}
+
+firefly::RelocModel firefly::fromLLVM(llvm::Reloc::Model model) {
+  switch (model) {
+  case llvm::Reloc::Static:
+    return firefly::RelocModel::Static;
+  case llvm::Reloc::PIC_:
+    return firefly::RelocModel::Pic;
+  case llvm::Reloc::DynamicNoPIC:
+    return firefly::RelocModel::DynamicNoPic;
+  case llvm::Reloc::ROPI:
+    return firefly::RelocModel::Ropi;
+  case llvm::Reloc::RWPI:
+    return firefly::RelocModel::Rwpi;
+  case llvm::Reloc::ROPI_RWPI:
+    return firefly::RelocModel::RopiRwpi;
+  default:
+    llvm::report_fatal_error("invalid llvm reloc model");
+  }
+}
+
+extern "C" firefly::RelocModel
+LLVMFireflyGetTargetMachineRelocModel(LLVMTargetMachineRef tm) {
+  return firefly::fromLLVM(unwrap(tm)->getRelocationModel());
+}
+
 extern "C" void LLVM_InitializeAllTargetInfos(void) {
   LLVMInitializeAllTargetInfos();
 }
@@ -70,11 +70,15 @@ struct TargetMachineConfig {
 
 llvm::Optional<llvm::CodeModel::Model> toLLVM(CodeModel cm);
 
+CodeModel fromLLVM(llvm::CodeModel::Model cm);
+
 llvm::CodeGenOpt::Level toLLVM(OptLevel level);
 
 unsigned toLLVM(SizeLevel level);
 
 llvm::Reloc::Model toLLVM(RelocModel model);
+
+RelocModel fromLLVM(llvm::Reloc::Model model);
 } // namespace firefly
 
 #endif
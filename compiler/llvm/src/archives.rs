@@ -9,6 +9,9 @@ use anyhow::anyhow;
 
 use crate::support::*;
 
+#[cfg(test)]
+mod tests;
+
 extern "C" {
     type LlvmArchive;
     type LlvmArchiveMember;
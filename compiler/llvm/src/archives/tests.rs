@@ -0,0 +1,17 @@
+use firefly_target::spec::Target;
+
+use super::*;
+
+#[test]
+fn x86_64_pc_windows_msvc_uses_coff_archives() {
+    let target = Target::search("x86_64-pc-windows-msvc").unwrap();
+    let kind = target.options.archive_format.parse::<ArchiveKind>();
+    assert!(matches!(kind, Ok(ArchiveKind::Coff)));
+}
+
+#[test]
+fn x86_64_unknown_linux_gnu_uses_gnu_archives() {
+    let target = Target::search("x86_64-unknown-linux-gnu").unwrap();
+    let kind = target.options.archive_format.parse::<ArchiveKind>();
+    assert!(matches!(kind, Ok(ArchiveKind::Gnu)));
+}
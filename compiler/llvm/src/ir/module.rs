@@ -134,6 +134,24 @@ impl Module {
         unsafe { LLVMSetTarget(self, c_str.as_ptr()) }
     }
 
+    /// Marks this module as position-independent code
+    pub fn set_pic_level(self) {
+        extern "C" {
+            fn LLVMFireflySetModulePICLevel(m: Module);
+        }
+        unsafe { LLVMFireflySetModulePICLevel(self) }
+    }
+
+    /// Marks this module as part of a position-independent executable
+    ///
+    /// This allows LLVM to assume that symbols defined in this module are not preemptible
+    pub fn set_pie_level(self) {
+        extern "C" {
+            fn LLVMFireflySetModulePIELevel(m: Module);
+        }
+        unsafe { LLVMFireflySetModulePIELevel(self) }
+    }
+
     pub fn flags(self) -> ModuleFlags {
         extern "C" {
             fn LLVMCopyModuleFlagsMetadata(m: Module, len: *mut usize) -> *const LlvmModuleFlag;
@@ -297,13 +297,9 @@ impl TargetMachine {
         let cpu: &str = cpu.borrow();
         let abi: &str = options.target.options.llvm_abiname.borrow();
         let relax_elf_relocations = options.target.options.relax_elf_relocations;
-        let default_reloc_model = options.target.options.relocation_model;
-        let reloc_model = options
-            .codegen_opts
-            .relocation_model
-            .unwrap_or(default_reloc_model);
-        let position_independent_code =
-            options.project_type == ProjectType::Executable && reloc_model == RelocModel::Pic;
+        let reloc_model = options.relocation_model();
+        let position_independent_code = options.project_type == ProjectType::Executable
+            && matches!(reloc_model, RelocModel::Pic | RelocModel::Pie);
         let function_sections = options.target.options.function_sections;
         let data_sections = function_sections;
         let emit_stack_size_section = options.debugging_opts.emit_stack_sizes;
@@ -322,12 +318,7 @@ impl TargetMachine {
         } else {
             true
         };
-        let default_code_model = options.target.options.code_model;
-        let code_model = options
-            .codegen_opts
-            .code_model
-            .or(default_code_model)
-            .unwrap_or(CodeModel::None);
+        let code_model = options.code_model().unwrap_or(CodeModel::None);
         let (opt_level, size_level) = codegen::to_llvm_opt_settings(options.opt_level);
 
         let features = features_owned
@@ -405,6 +396,25 @@ impl TargetMachine {
         unsafe { StringRef::from_ptr(LLVMGetTargetMachineFeatureString(self)) }
     }
 
+    /// Returns the relocation model used in creating this target machine
+    ///
+    /// LLVM represents `RelocModel::Pie` as `RelocModel::Pic` at the target machine level, as PIE is
+    /// instead recorded on each module, so a PIE target machine reports `RelocModel::Pic`
+    pub fn reloc_model(self) -> RelocModel {
+        extern "C" {
+            fn LLVMFireflyGetTargetMachineRelocModel(t: TargetMachine) -> RelocModel;
+        }
+        unsafe { LLVMFireflyGetTargetMachineRelocModel(self) }
+    }
+
+    /// Returns the code model used in creating this target machine
+    pub fn code_model(self) -> CodeModel {
+        extern "C" {
+            fn LLVMFireflyGetTargetMachineCodeModel(t: TargetMachine) -> CodeModel;
+        }
+        unsafe { LLVMFireflyGetTargetMachineCodeModel(self) }
+    }
+
     /// Prints the available set of target CPUs to stdout
     pub fn print_target_cpus(self) {
         extern "C" {
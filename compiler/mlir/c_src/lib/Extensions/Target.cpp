@@ -4,6 +4,7 @@
 #include "mlir/IR/BuiltinOps.h"
 #include "mlir/Pass/PassManager.h"
 #include "mlir/Target/LLVMIR/Export.h"
+#include "llvm/IR/DebugInfo.h"
 #include "llvm/IR/LLVMContext.h"
 #include "llvm/IR/Module.h"
 
@@ -13,12 +14,27 @@ using llvm::LLVMContext;
 
 extern "C" LLVMModuleRef mlirTranslateModuleToLLVMIR(MlirModule module,
                                                      LLVMContextRef llvmContext,
-                                                     MlirStringRef name) {
+                                                     MlirStringRef name,
+                                                     bool emitDebugInfo) {
   LLVMContext *context = llvm::unwrap(llvmContext);
   auto llvmModPtr =
       translateModuleToLLVMIR(unwrap(module), *context, unwrap(name));
   if (!llvmModPtr)
     return nullptr;
 
-  return llvm::wrap(llvmModPtr.release());
+  // The translation derives a compile unit and subprograms from the source
+  // locations attached to each operation, so we either strip that debug info,
+  // or make sure the module flags required by the DWARF emitter are present
+  llvm::Module *llvmMod = llvmModPtr.release();
+  if (!emitDebugInfo) {
+    llvm::StripDebugInfo(*llvmMod);
+  } else if (!llvmMod->debug_compile_units().empty()) {
+    if (!llvmMod->getModuleFlag("Debug Info Version"))
+      llvmMod->addModuleFlag(llvm::Module::Warning, "Debug Info Version",
+                             llvm::DEBUG_METADATA_VERSION);
+    if (!llvmMod->getModuleFlag("Dwarf Version"))
+      llvmMod->addModuleFlag(llvm::Module::Max, "Dwarf Version", 4);
+  }
+
+  return llvm::wrap(llvmMod);
 }
@@ -675,7 +675,7 @@ impl Iterator for OperationIter {
             None => None,
             Some(op) => {
                 self.op = op.next();
-                self.op
+                Some(op)
             }
         }
     }
@@ -1,18 +1,21 @@
 use anyhow::anyhow;
 use firefly_llvm as llvm;
 use firefly_pass::Pass;
+use firefly_session::DebugInfo;
 
 use crate::{Module, OwnedModule, StringRef};
 
 pub struct TranslateMLIRToLLVMIR {
     llvm_context: llvm::Context,
     source_name: String,
+    debug_info: DebugInfo,
 }
 impl<'c> TranslateMLIRToLLVMIR {
-    pub fn new(llvm_context: llvm::Context, source_name: String) -> Self {
+    pub fn new(llvm_context: llvm::Context, source_name: String, debug_info: DebugInfo) -> Self {
         Self {
             llvm_context,
             source_name,
+            debug_info,
         }
     }
 }
@@ -26,10 +29,17 @@ impl Pass for TranslateMLIRToLLVMIR {
             .name()
             .ok_or_else(|| anyhow!("expected module to be named"))?;
         let source_name = StringRef::from(self.source_name.as_str());
+        // Source locations are translated to line tables for function entry points and
+        // instructions, so we only need to decide whether to keep them
+        let emit_debug_info = self.debug_info != DebugInfo::None;
 
         let llvm_module = unsafe {
-            let llvm_module =
-                mlir_translate_module_to_llvm_ir(**module, self.llvm_context, source_name);
+            let llvm_module = mlir_translate_module_to_llvm_ir(
+                **module,
+                self.llvm_context,
+                source_name,
+                emit_debug_info,
+            );
             if llvm_module.is_null() {
                 return Err(anyhow!("failed to lower mlir module to llvm ir"));
             }
@@ -48,5 +58,6 @@ extern "C" {
         module: Module,
         llvm_context: llvm::Context,
         name: StringRef,
+        emit_debug_info: bool,
     ) -> llvm::OwnedModule;
 }
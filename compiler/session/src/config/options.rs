@@ -22,7 +22,7 @@ use clap::ArgMatches;
 
 use firefly_diagnostics::{CodeMap, Reporter};
 use firefly_intern::Symbol;
-use firefly_target::spec::{CodeModel, RelocModel, SplitDebugInfo, TlsModel};
+use firefly_target::spec::{CodeModel, Endianness, RelocModel, SplitDebugInfo, TlsModel};
 use firefly_target::{self as target, Target};
 use firefly_util::diagnostics::{ColorArg, ColorChoice, FileName};
 use firefly_util::error::{HelpRequested, Verbosity};
@@ -265,6 +265,8 @@ impl Options {
                 .into())
             }
         }
+        validate_data_layout(&target)?;
+        validate_code_model(&target, &codegen_opts)?;
         let host_triple = target::host_triple();
         let host = Target::search(host_triple)?;
         let target_triple = target.triple();
@@ -405,6 +407,8 @@ impl Options {
                 .into())
             }
         }
+        validate_data_layout(&target)?;
+        validate_code_model(&target, &codegen_opts)?;
 
         let defines = default_configuration(&target);
         let sysroot = filesearch::get_or_default_sysroot();
@@ -864,6 +868,83 @@ fn parse_source_path_prefix<'a>(
     }
 }
 
+/// Checks that the endianness and pointer width encoded in the target's `data_layout` agree with
+/// the target's declared `endianness` and `pointer_width`, as LLVM trusts the layout
+fn validate_data_layout(target: &Target) -> clap::Result<()> {
+    let invalid = |message: String| {
+        Err(str_to_clap_err(
+            "target",
+            &format!(
+                "Invalid target: data layout ({}) {}",
+                target.data_layout, message
+            ),
+        ))
+    };
+    // LLVM defaults to 64-bit pointers when the layout does not specify them
+    let mut pointer_width = 64;
+
+    for specification in target.data_layout.split('-') {
+        match specification {
+            "e" if target.options.endianness == Endianness::Big => {
+                return invalid("is little-endian, but target is big-endian".to_string())
+            }
+            "E" if target.options.endianness == Endianness::Little => {
+                return invalid("is big-endian, but target is little-endian".to_string())
+            }
+            _ => {
+                let address_space_0 = specification
+                    .strip_prefix("p:")
+                    .or_else(|| specification.strip_prefix("p0:"));
+
+                if let Some(size_abi_preferred) = address_space_0 {
+                    match size_abi_preferred.split(':').next().unwrap().parse() {
+                        Ok(size) => pointer_width = size,
+                        Err(_) => {
+                            return invalid(format!(
+                                "has an invalid pointer specification ({})",
+                                specification
+                            ))
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if pointer_width == target.pointer_width {
+        Ok(())
+    } else {
+        invalid(format!(
+            "has {}-bit pointers, but target pointer width is {}",
+            pointer_width, target.pointer_width
+        ))
+    }
+}
+
+fn validate_code_model(target: &Target, codegen_opts: &CodegenOptions) -> clap::Result<()> {
+    match codegen_opts.code_model {
+        Some(code_model) if !supports_code_model(target, code_model) => Err(str_to_clap_err(
+            "code-model",
+            &format!(
+                "Invalid code model: {} does not support the {:?} code model",
+                target.triple(),
+                code_model
+            ),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Returns true if LLVM is able to generate code for `target` using `code_model`
+fn supports_code_model(target: &Target, code_model: CodeModel) -> bool {
+    match code_model {
+        CodeModel::Kernel => target.arch == "x86_64",
+        CodeModel::Medium => target.arch != "aarch64",
+        CodeModel::Large => !["riscv32", "riscv64"].contains(&&target.arch[..]),
+        CodeModel::Small | CodeModel::Other | CodeModel::None => true,
+    }
+}
+
 pub fn str_to_clap_err(opt: &str, err: &str) -> clap::Error {
     clap::Error {
         kind: clap::ErrorKind::InvalidValue,
@@ -871,3 +952,67 @@ pub fn str_to_clap_err(opt: &str, err: &str) -> clap::Error {
         info: Some(vec![opt.to_string()]),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn target() -> Target {
+        Target::search("x86_64-unknown-linux-gnu").unwrap()
+    }
+
+    #[test]
+    fn with_consistent_data_layout_is_valid() {
+        assert!(validate_data_layout(&target()).is_ok());
+        assert!(
+            validate_data_layout(&Target::search("i686-unknown-linux-gnu").unwrap()).is_ok()
+        );
+    }
+
+    #[test]
+    fn every_built_in_target_has_consistent_data_layout() {
+        let triples: Vec<String> = Target::all().collect();
+
+        for triple in &[
+            "thumbv7em-none-eabihf",
+            "powerpc64le-unknown-linux-gnu",
+            "mips64el-unknown-linux-gnuabi64",
+            "s390x-unknown-linux-gnu",
+        ] {
+            assert!(triples.iter().any(|t| t == triple), "{} is built-in", triple);
+        }
+
+        for triple in triples {
+            let target = Target::search(&triple).unwrap();
+
+            if let Err(error) = validate_data_layout(&target) {
+                panic!("{}: {}", triple, error.message);
+            }
+        }
+    }
+
+    #[test]
+    fn with_big_endian_data_layout_for_little_endian_target_is_invalid() {
+        let mut target = target();
+        target.options.endianness = Endianness::Little;
+        target.data_layout = target.data_layout.replacen('e', "E", 1).into();
+
+        let error = validate_data_layout(&target).unwrap_err();
+
+        assert!(error
+            .message
+            .contains("is big-endian, but target is little-endian"));
+    }
+
+    #[test]
+    fn with_32_bit_pointers_for_64_bit_target_is_invalid() {
+        let mut target = target();
+        target.data_layout = format!("{}-p:32:32", target.data_layout).into();
+
+        let error = validate_data_layout(&target).unwrap_err();
+
+        assert!(error
+            .message
+            .contains("has 32-bit pointers, but target pointer width is 64"));
+    }
+}
@@ -90,6 +90,7 @@ impl Pass for KernelToSsa {
                 ultimate_failure: Block::default(),
                 brk: vec![],
                 recv: Stack::new(),
+                tuple_arities: HashMap::new(),
             };
             let ir_function = pass.run(function)?;
             ir_module.define_function(ir_function);
@@ -131,6 +132,8 @@ struct LowerFunctionToSsa<'m> {
     // The current receive label stack
     #[allow(dead_code)]
     recv: Stack<Block>,
+    // The arity of variables known to be tuples of a specific arity, i.e. within select clauses
+    tuple_arities: HashMap<Symbol, usize>,
 }
 impl<'m> Pass for LowerFunctionToSsa<'m> {
     type Input<'a> = k::Function;
@@ -512,8 +515,11 @@ impl<'m> LowerFunctionToSsa<'m> {
                     .collect::<Vec<_>>();
                 builder.ins().switch(arity, arms, value_fail, span);
                 // Now, for each clause, lower the body of that clause in the appropriate block
-                for ((_, clause), block) in clauses.drain(..).zip(blocks.drain(..)) {
+                for ((arity, clause), block) in clauses.drain(..).zip(blocks.drain(..)) {
                     builder.switch_to_block(block);
+                    // Within this clause the source is known to have this exact arity, which
+                    // allows operations on it, e.g. record updates, to skip their checks
+                    let prev_arity = self.tuple_arities.insert(var.name(), arity as usize);
                     // Bind tuple elemnts in this block
                     let KExpr::Tuple(tuple) = *clause.value else { unreachable!() };
                     for (i, elem) in tuple.elements.iter().enumerate() {
@@ -525,6 +531,14 @@ impl<'m> LowerFunctionToSsa<'m> {
                         builder.define_var(var.name, elem);
                     }
                     self.lower_match(builder, value_fail, *clause.body)?;
+                    match prev_arity {
+                        Some(prev_arity) => {
+                            self.tuple_arities.insert(var.name(), prev_arity);
+                        }
+                        None => {
+                            self.tuple_arities.remove(&var.name());
+                        }
+                    }
                 }
                 Ok(())
             }
@@ -938,6 +952,16 @@ impl<'m> LowerFunctionToSsa<'m> {
                 let arity = arity.to_usize().unwrap();
                 self.lower_is_record_bif(builder, bif, tag, arity)
             }
+            (
+                symbols::Setelement,
+                [KExpr::Literal(Literal {
+                    value: Lit::Integer(index),
+                    ..
+                }), tuple, _],
+            ) if self.is_in_known_tuple_arity(builder, tuple, index) => {
+                let index = index.to_usize().unwrap();
+                self.lower_setelement_bif(builder, bif, index)
+            }
             _ if bif.op.is_safe() => {
                 // This bif can never fail, and has no side effects
                 let callee = self.module.get_or_register_builtin(bif.op);
@@ -1065,6 +1089,71 @@ impl<'m> LowerFunctionToSsa<'m> {
         Ok(())
     }
 
+    /// Returns true if `tuple` is known to be a tuple whose arity includes the 1-based `index`
+    fn is_in_known_tuple_arity(&self, builder: &IrBuilder, tuple: &KExpr, index: &Integer) -> bool {
+        let Some(arity) = self.known_tuple_arity(builder, tuple) else { return false; };
+        let Some(index) = index.to_usize() else { return false; };
+        0 < index && index <= arity
+    }
+
+    /// Returns the arity of `tuple`, if it is a variable known to be bound to a tuple of that arity
+    ///
+    /// This is either because it is the subject of an enclosing select clause on tuple arity, or
+    /// because its value has a sized tuple type, e.g. the result of a previous record update.
+    fn known_tuple_arity(&self, builder: &IrBuilder, tuple: &KExpr) -> Option<usize> {
+        let name = tuple.as_var()?.name();
+        if let Some(arity) = self.tuple_arities.get(&name) {
+            return Some(*arity);
+        }
+        match builder.value_type(builder.var(name)?) {
+            Type::Term(TermType::Tuple(Some(elements))) => Some(elements.len()),
+            _ => None,
+        }
+    }
+
+    /// Lowers `erlang:setelement/3` with a literal index on a tuple of known arity, as produced by
+    /// record updates, directly to a tuple copy, avoiding the call to the generic bif
+    fn lower_setelement_bif<'a>(
+        &mut self,
+        builder: &'a mut IrBuilder,
+        mut bif: k::Bif,
+        index: usize,
+    ) -> anyhow::Result<()> {
+        let span = bif.span();
+        let arity = self.known_tuple_arity(builder, &bif.args[1]).unwrap();
+        let value = self.ssa_value(builder, bif.args.pop().unwrap())?;
+        let tuple = self.ssa_value(builder, bif.args.pop().unwrap())?;
+        // Only the result of a previous update carries the arity in the type of the tuple
+        let tuple = match builder.value_type(tuple) {
+            Type::Term(TermType::Tuple(Some(_))) => tuple,
+            _ => builder.ins().cast(tuple, Type::tuple(arity), span),
+        };
+        let tuple_type = builder.value_type(tuple);
+
+        let result = builder.ins().set_element(tuple, index - 1, value, span);
+        // The copy has the same shape as the original, which lets nested updates of the same
+        // record be lowered the same way
+        builder.set_value_type(result, tuple_type);
+
+        match bif.ret.len() {
+            0 => (),
+            1 => {
+                builder.define_var(bif.ret[0].as_var().map(|v| v.name()).unwrap(), result);
+            }
+            2 => {
+                // The error flag is checked, but this can never fail
+                let is_err = builder.ins().bool(false, span);
+                builder.define_var(bif.ret[0].as_var().map(|v| v.name()).unwrap(), is_err);
+                builder.define_var(bif.ret[1].as_var().map(|v| v.name()).unwrap(), result);
+            }
+            n => panic!(
+                "expected bif {} to have 1 or 2 result values, but got {}",
+                bif.op, n
+            ),
+        }
+        Ok(())
+    }
+
     fn lower_internal<'a>(
         &mut self,
         builder: &'a mut IrBuilder,
@@ -0,0 +1,20 @@
+use crate::spec::{Endianness, Target};
+
+pub fn target() -> Target {
+    let mut base = super::linux_gnu_base::opts();
+    base.endianness = Endianness::Big;
+    // z10 is the oldest CPU supported by LLVM
+    base.cpu = "z10".into();
+    // We only support the no-vector ABI for now, so make sure LLVM doesn't assume otherwise
+    base.features = "-vector".into();
+    base.max_atomic_width = Some(64);
+    base.min_global_align = Some(16);
+
+    Target {
+        llvm_target: "s390x-unknown-linux-gnu".into(),
+        pointer_width: 64,
+        data_layout: "E-m:e-i1:8:16-i8:8:16-i64:64-f128:64-v128:64-a:8:16-n32:64".into(),
+        arch: "s390x".into(),
+        options: base,
+    }
+}
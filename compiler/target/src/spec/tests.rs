@@ -0,0 +1,53 @@
+use super::*;
+
+#[test]
+fn i686_unknown_linux_gnu_is_32_bit() {
+    let target = Target::search("i686-unknown-linux-gnu").unwrap();
+    assert_eq!(target.arch, "x86");
+    assert_eq!(target.pointer_width, 32);
+    assert_eq!(target.options.os, "linux");
+    assert_eq!(target.options.features, "+sse2");
+    assert_eq!(target.options.max_atomic_width, Some(64));
+}
+
+#[test]
+fn thumbv7em_none_eabihf_is_bare_metal() {
+    let target = Target::search("thumbv7em-none-eabihf").unwrap();
+    assert_eq!(target.arch, "arm");
+    assert_eq!(target.pointer_width, 32);
+    assert_eq!(target.options.os, "none");
+    assert_eq!(target.options.abi, "eabihf");
+    assert_eq!(target.options.max_atomic_width, Some(32));
+    assert_eq!(target.options.features, "+vfp4,-d32,-fp64");
+    assert!(!target.options.dynamic_linking);
+}
+
+#[test]
+fn powerpc64le_unknown_linux_gnu_is_little_endian() {
+    let target = Target::search("powerpc64le-unknown-linux-gnu").unwrap();
+    assert_eq!(target.arch, "powerpc64");
+    assert_eq!(target.pointer_width, 64);
+    assert_eq!(target.options.os, "linux");
+    assert_eq!(target.options.endianness, Endianness::Little);
+    assert_eq!(target.options.features, "+altivec,+vsx");
+    assert!(target.data_layout.starts_with("e-"));
+}
+
+#[test]
+fn mips64el_unknown_linux_gnuabi64_is_little_endian_n64() {
+    let target = Target::search("mips64el-unknown-linux-gnuabi64").unwrap();
+    assert_eq!(target.arch, "mips64");
+    assert_eq!(target.pointer_width, 64);
+    assert_eq!(target.options.endianness, Endianness::Little);
+    assert_eq!(target.options.llvm_abiname, "n64");
+}
+
+#[test]
+fn s390x_unknown_linux_gnu_is_big_endian() {
+    let target = Target::search("s390x-unknown-linux-gnu").unwrap();
+    assert_eq!(target.arch, "s390x");
+    assert_eq!(target.pointer_width, 64);
+    assert_eq!(target.options.endianness, Endianness::Big);
+    assert_eq!(target.options.max_atomic_width, Some(64));
+    assert!(target.data_layout.starts_with("E-"));
+}
@@ -0,0 +1,33 @@
+//! Bare-metal target for the Cortex-M4F and Cortex-M7F processors (ARMv7E-M)
+//!
+//! This target uses the "hard" floating point ABI, so floating point values are passed
+//! in FPU registers, and requires a processor with a single precision FPU.
+
+use crate::spec::{LinkerFlavor, LldFlavor, PanicStrategy, RelocModel, Target, TargetOptions};
+
+pub fn target() -> Target {
+    Target {
+        llvm_target: "thumbv7em-none-eabihf".into(),
+        pointer_width: 32,
+        data_layout: "e-m:e-p:32:32-Fi8-i64:64-v128:64:128-a:0:32-n32-S64".into(),
+        arch: "arm".into(),
+        options: TargetOptions {
+            abi: "eabihf".into(),
+            linker_flavor: LinkerFlavor::Lld(LldFlavor::Ld),
+            linker: Some("rust-lld".into()),
+            // `+vfp4` is the lowest common denominator between the Cortex-M4 (vfp4-sp) and the
+            // Cortex-M7 (vfp5); `-d32` because both processors only have 16 double-precision
+            // registers, and `-fp64` because the Cortex-M4 only supports single-precision
+            // floating point operations, whereas the Cortex-M7 supports both.
+            features: "+vfp4,-d32,-fp64".into(),
+            executables: true,
+            relocation_model: RelocModel::Static,
+            panic_strategy: PanicStrategy::Abort,
+            max_atomic_width: Some(32),
+            emit_debug_gdb_scripts: false,
+            // GCC and Clang default to 8 for arm-none here
+            c_enum_min_bits: 8,
+            ..Default::default()
+        },
+    }
+}
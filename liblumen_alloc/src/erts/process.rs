@@ -59,13 +59,7 @@ pub use self::monitor::Monitor;
 pub use self::priority::Priority;
 
 // 4000 in [BEAM](https://github.com/erlang/otp/blob/61ebe71042fce734a06382054690d240ab027409/erts/emulator/beam/erl_vm.h#L39)
-cfg_if::cfg_if! {
-  if #[cfg(target_arch = "wasm32")] {
-     pub const MAX_REDUCTIONS_PER_RUN: Reductions = 4_000;
-  } else {
-     pub const MAX_REDUCTIONS_PER_RUN: Reductions = 1_000;
-  }
-}
+pub const DEFAULT_REDUCTION_BUDGET: Reductions = 4_000;
 
 #[derive(Clone, Debug, Default)]
 #[repr(C)]
@@ -116,13 +110,13 @@ pub struct Process {
     /// ID of the scheduler that is running the process
     scheduler_id: Mutex<Option<scheduler::ID>>,
     /// The priority of the process in `scheduler`.
-    pub priority: Priority,
+    priority: Mutex<Priority>,
     /// Process flags, e.g. `Process.flag/1`
     flags: AtomicProcessFlags,
     /// Minimum size of the heap that this process will start with
     min_heap_size: usize,
-    /// The maximum size of the heap allowed for this process
-    max_heap_size: usize,
+    /// The maximum size of the heap allowed for this process in words.  `0` means no limit.
+    max_heap_size: AtomicUsize,
     /// Minimum virtual heap size for this process
     min_vheap_size: usize,
     /// The percentage of used to unused space at which a collection is triggered
@@ -141,8 +135,11 @@ pub struct Process {
     pid: Pid,
     pub initial_module_function_arity: ModuleFunctionArity,
     /// The number of reductions in the current `run`.  `code` MUST return when `run_reductions`
-    /// exceeds `MAX_REDUCTIONS_PER_RUN`.
+    /// exceeds `reduction_budget`.
     run_reductions: AtomicU16,
+    /// The number of reductions the process may use in a `run` before it is yielded back to the
+    /// run queue.
+    reduction_budget: AtomicU16,
     pub total_reductions: AtomicU64,
     pub frames: Mutex<Frames>,
     pub status: RwLock<Status>,
@@ -187,7 +184,7 @@ impl Process {
         Self {
             flags: AtomicProcessFlags::new(ProcessFlags::Default),
             min_heap_size: heap_size,
-            max_heap_size: 0,
+            max_heap_size: AtomicUsize::new(0),
             min_vheap_size: 0,
             gc_threshold: 0.75,
             max_gen_gcs: 65535,
@@ -202,11 +199,12 @@ impl Process {
             registers: Default::default(),
             frames: Default::default(),
             scheduler_id: Mutex::new(None),
-            priority,
+            priority: Mutex::new(priority),
             parent_pid,
             group_leader_pid: Mutex::new(group_leader_pid),
             initial_module_function_arity,
             run_reductions: Default::default(),
+            reduction_budget: AtomicU16::new(DEFAULT_REDUCTION_BUDGET),
             total_reductions: Default::default(),
             registered_name: Default::default(),
             linked_pid_set: Default::default(),
@@ -239,6 +237,15 @@ impl Process {
         *self.scheduler_id.lock()
     }
 
+    pub fn priority(&self) -> Priority {
+        *self.priority.lock()
+    }
+
+    /// Changes the priority used the next time the process is enqueued, returning the old priority.
+    pub fn set_priority(&self, priority: Priority) -> Priority {
+        mem::replace(&mut *self.priority.lock(), priority)
+    }
+
     pub fn schedule_with(&self, scheduler_id: scheduler::ID) {
         *self.scheduler_id.lock() = Some(scheduler_id);
     }
@@ -908,15 +915,17 @@ impl Process {
         self.list_from_slice(&entry_vec)
     }
 
-    /// Returns list of all keys from the process dictionary that have `value`.
+    /// Returns list of all keys from the process dictionary that have `value`.  Values are
+    /// compared with `=:=`, so integers and floats are never equal.
     pub fn get_keys_from_value(&self, value: Term) -> Term {
+        let value = value.decode().unwrap();
         let key_vec: Vec<Term> = self
             .dictionary
             .iter()
             .filter_map(|entry| {
                 let entry_key = entry.key();
-                let entry_value = entry.value();
-                if entry_value == &value {
+                let entry_value = entry.value().decode().unwrap();
+                if entry_value.exact_eq(&value) {
                     Some(*entry_key)
                 } else {
                     None
@@ -972,6 +981,78 @@ impl Process {
         self.off_heap_size.load(Ordering::Acquire)
     }
 
+    /// The size in words of the heap and any attached heap fragments
+    pub fn total_heap_size(&self) -> usize {
+        self.heap.lock().total_heap_size() + self.off_heap_size()
+    }
+
+    /// The maximum size in words the heap can grow to, or `0` if there is no limit
+    #[inline]
+    pub fn max_heap_size(&self) -> usize {
+        self.max_heap_size.load(Ordering::Acquire)
+    }
+
+    /// Limits the heap to `max_heap_size` words.  When `kill` is `true`, the process is killed
+    /// with reason `killed` once its heap grows beyond the limit.
+    pub fn set_max_heap_size(&self, max_heap_size: usize, kill: bool) {
+        self.max_heap_size.store(max_heap_size, Ordering::Release);
+
+        if kill {
+            self.set_flags(ProcessFlags::MaxHeapSizeKill);
+        } else {
+            self.clear_flags(ProcessFlags::MaxHeapSizeKill);
+        }
+    }
+
+    fn is_max_heap_size_exceeded(&self) -> bool {
+        let max_heap_size = self.max_heap_size();
+
+        max_heap_size > 0 && max_heap_size < self.total_heap_size()
+    }
+
+    /// Kills the process with reason `killed` if its heap has grown beyond `max_heap_size` and it
+    /// was configured to be killed when that happens.
+    fn kill_if_max_heap_size_exceeded(&self) -> bool {
+        if self.flags.are_set(ProcessFlags::MaxHeapSizeKill) && self.is_max_heap_size_exceeded() {
+            self.kill_for_max_heap_size();
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn kill_for_max_heap_size(&self) {
+        self.exit(Atom::str_to_term("killed"), trace::Trace::capture(), None);
+    }
+
+    /// Collects garbage like `garbage_collect`, but when the process is not killed for exceeding
+    /// `max_heap_size`, the limit is only reported and the heap is allowed to grow past it.
+    fn garbage_collect_within_max_heap_size(
+        &self,
+        need: usize,
+        roots: &mut [Term],
+    ) -> Result<usize, GcError> {
+        match self.garbage_collect(need, &mut *roots) {
+            Err(GcError::MaxHeapSizeExceeded)
+                if !self.flags.are_set(ProcessFlags::MaxHeapSizeKill) =>
+            {
+                let max_heap_size = self.max_heap_size.swap(0, Ordering::AcqRel);
+                log::warn!(
+                    "Process ({}) exceeded its maximum heap size of {} words",
+                    self,
+                    max_heap_size
+                );
+
+                let result = self.garbage_collect(need, roots);
+                self.max_heap_size.store(max_heap_size, Ordering::Release);
+
+                result
+            }
+            result => result,
+        }
+    }
+
     #[inline]
     fn is_gc_forced(&self) -> bool {
         self.flags.are_set(ProcessFlags::ForceGC)
@@ -1079,8 +1160,34 @@ impl Process {
         self.run_reductions.fetch_add(1, Ordering::SeqCst);
     }
 
+    /// Charges `reductions` to the current `run` at once, such as for work done by a single
+    /// native call that is proportional to the size of its arguments.
+    pub fn reduce_by(&self, reductions: Reductions) {
+        self.run_reductions
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |run_reductions| {
+                Some(run_reductions.saturating_add(reductions))
+            })
+            .unwrap();
+    }
+
     pub fn is_reduced(&self) -> bool {
-        MAX_REDUCTIONS_PER_RUN <= self.run_reductions.load(Ordering::SeqCst)
+        self.reduction_budget() <= self.run_reductions.load(Ordering::SeqCst)
+    }
+
+    /// Uses up the rest of the reduction budget, so that the process is yielded back to the run
+    /// queue once the current native returns.
+    pub fn yield_now(&self) {
+        self.run_reductions
+            .fetch_max(self.reduction_budget(), Ordering::SeqCst);
+    }
+
+    pub fn reduction_budget(&self) -> Reductions {
+        self.reduction_budget.load(Ordering::SeqCst)
+    }
+
+    pub fn set_reduction_budget(&self, reduction_budget: Reductions) {
+        self.reduction_budget
+            .store(reduction_budget, Ordering::SeqCst);
     }
 
     pub fn runnable<F>(&self, before_runnable: F)
@@ -1101,7 +1208,7 @@ impl Process {
         *writable_status = Status::Runnable;
     }
 
-    /// Run process until `reductions` exceeds `MAX_REDUCTIONS` or process exits
+    /// Run process until `reductions` exceeds `reduction_budget` or process exits
     pub fn run(&self) -> Ran {
         self.start_running();
 
@@ -1265,6 +1372,12 @@ impl Process {
 
     fn call_native_until_reduced(&self) -> Ran {
         while !self.is_reduced() {
+            // heap fragments grow the heap without a collection, so the limit has to be checked
+            // between calls too
+            if self.is_exiting() || self.kill_if_max_heap_size_exceeded() {
+                return Ran::RuntimeException;
+            }
+
             match self.call_current_native() {
                 CalledCurrentNative::Runnable => continue,
                 CalledCurrentNative::Waiting => return Ran::Waiting,
@@ -1386,7 +1499,9 @@ impl Process {
                             .flat_map(|frame_with_arguments| frame_with_arguments.arguments.iter()),
                     );
 
-                    match self.garbage_collect(roots.len(), roots.as_mut_slice()) {
+                    match self
+                        .garbage_collect_within_max_heap_size(roots.len(), roots.as_mut_slice())
+                    {
                         Ok(reductions) => {
                             let mut updated_arguments = roots.drain(..);
                             self.run_reductions
@@ -1409,6 +1524,14 @@ impl Process {
                                 .expect("Could not push arguments to stack after successful GC");
                             frames.push(frame);
                         }
+                        Err(GcError::MaxHeapSizeExceeded)
+                            if self.flags.are_set(ProcessFlags::MaxHeapSizeKill) =>
+                        {
+                            // the remaining frames are dropped as the process won't run again
+                            self.kill_for_max_heap_size();
+
+                            return;
+                        }
                         Err(_) => {
                             unimplemented!(
                                 "Could not GC while stacking queued frames with arguments"
@@ -1434,6 +1557,30 @@ impl Process {
             .map(|frame| frame.definition().clone())
     }
 
+    /// Discards the frames, and their stack slots, that the current frame would return to, so
+    /// that only the current frame with its arguments and the bottom frame remain.
+    ///
+    /// Used by `erlang:hibernate/3`, which never returns to its caller.
+    pub fn discard_stack(&self) {
+        let arity = self
+            .frames
+            .lock()
+            .current()
+            .map(|frame| frame.native().arity() as usize)
+            .unwrap_or(0);
+        let arguments: Vec<Term> = (1..=arity)
+            .map(|one_based_index| self.stack_peek(one_based_index).unwrap())
+            .collect();
+
+        self.stack_popn(self.stack_used());
+
+        for argument in arguments.into_iter().rev() {
+            self.stack_push(argument).expect("stack overflow");
+        }
+
+        self.frames.lock().discard_callers();
+    }
+
     pub fn queue_frame_with_arguments(&self, frame_with_arguments: FrameWithArguments) {
         self.frames.lock().queue(frame_with_arguments);
     }
@@ -1512,7 +1659,7 @@ impl PartialEq for Process {
 unsafe impl Send for Process {}
 unsafe impl Sync for Process {}
 
-type Reductions = u16;
+pub type Reductions = u16;
 
 #[derive(Debug)]
 enum CalledCurrentNative {
@@ -23,6 +23,12 @@ impl Frames {
         self.stack.top()
     }
 
+    /// Discards the frames the current frame would return to, except for the bottom frame that
+    /// exits the process when it runs out of code.
+    pub fn discard_callers(&mut self) {
+        self.stack.discard_middle();
+    }
+
     pub fn push(&mut self, frame: Frame) {
         self.stack.push(frame);
     }
@@ -12,6 +12,15 @@ impl Stack {
         self.0.len()
     }
 
+    /// Removes all frames between the top and the bottom frames.
+    pub fn discard_middle(&mut self) {
+        let len = self.0.len();
+
+        if len > 2 {
+            self.0.drain(1..(len - 1));
+        }
+    }
+
     pub fn pop(&mut self) -> Option<Frame> {
         self.0.pop_front()
     }
@@ -40,6 +40,16 @@ impl ProcessHeap {
         self.heap.should_collect(gc_threshold)
     }
 
+    /// Returns the size in words of both generations of the heap
+    #[inline]
+    pub fn total_heap_size(&self) -> usize {
+        let young = self.heap.young_generation();
+        let old = self.heap.old_generation();
+        let old_heap_size = if old.active() { old.heap_size() } else { 0 };
+
+        young.heap_size() + old_heap_size
+    }
+
     #[cfg(test)]
     pub(super) fn heap(&self) -> &SemispaceProcessHeap {
         &self.heap
@@ -118,7 +128,8 @@ impl ProcessHeap {
 
         // Verify that our projected heap size is not going to blow the max heap size, if set
         // NOTE: When this happens, we will be left with no choice but to kill the process
-        if process.max_heap_size > 0 && process.max_heap_size < new_heap_size {
+        let max_heap_size = process.max_heap_size();
+        if max_heap_size > 0 && max_heap_size < new_heap_size {
             return Err(GcError::MaxHeapSizeExceeded);
         }
 
@@ -248,7 +259,8 @@ impl ProcessHeap {
         // the max heap size, if one was configured.
         //
         // If a max heap size is set, make sure we're not going to exceed it
-        if process.max_heap_size > 0 {
+        let max_heap_size = process.max_heap_size();
+        if max_heap_size > 0 {
             // First, check if we have exceeded the max heap size
             let mut heap_size = size_before;
             // In this estimate, our stack size includes unused area between stack and heap
@@ -269,7 +281,7 @@ impl ProcessHeap {
             heap_size += alloc::next_heap_size(baseline_size);
 
             // When this error type is returned, a full sweep will be triggered
-            if heap_size > process.max_heap_size {
+            if heap_size > max_heap_size {
                 return Err(GcError::MaxHeapSizeExceeded);
             }
         }
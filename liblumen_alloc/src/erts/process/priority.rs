@@ -34,3 +34,16 @@ impl TryFrom<Term> for Priority {
         }
     }
 }
+
+impl From<Priority> for Term {
+    fn from(priority: Priority) -> Self {
+        let name = match priority {
+            Priority::Low => "low",
+            Priority::Normal => "normal",
+            Priority::High => "high",
+            Priority::Max => "max",
+        };
+
+        Atom::str_to_term(name)
+    }
+}
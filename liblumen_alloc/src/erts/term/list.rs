@@ -281,8 +281,31 @@ impl Display for Cons {
 
 impl Eq for Cons {}
 impl Ord for Cons {
+    /// > * Lists are compared element by element.
+    ///
+    /// Once the heads are equal, the tails are compared as terms, so that a proper tail (`[]` or
+    /// another cons) and an improper tail are ordered the same way as any other terms.
     fn cmp(&self, other: &Cons) -> cmp::Ordering {
-        self.iter().cmp(other.iter())
+        let mut self_cons = *self;
+        let mut other_cons = *other;
+
+        loop {
+            match self_cons.head.cmp(&other_cons.head) {
+                cmp::Ordering::Equal => (),
+                ordering => return ordering,
+            }
+
+            match (
+                self_cons.tail.decode().unwrap(),
+                other_cons.tail.decode().unwrap(),
+            ) {
+                (TypedTerm::List(self_tail), TypedTerm::List(other_tail)) => {
+                    self_cons = *self_tail;
+                    other_cons = *other_tail;
+                }
+                (self_tail, other_tail) => return self_tail.cmp(&other_tail),
+            }
+        }
     }
 }
 impl PartialEq for Cons {
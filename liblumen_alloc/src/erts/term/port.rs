@@ -28,8 +28,8 @@ impl Port {
 }
 
 impl Display for Port {
-    fn fmt(&self, _f: &mut fmt::Formatter) -> fmt::Result {
-        unimplemented!()
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#Port<0.{}>", self.0)
     }
 }
 
@@ -89,6 +89,11 @@ pub struct ExternalPort {
     port: Port,
 }
 impl_static_header!(ExternalPort, Term::HEADER_EXTERN_PORT);
+impl ExternalPort {
+    pub fn node(&self) -> &Node {
+        &self.node
+    }
+}
 impl CloneToProcess for ExternalPort {
     fn clone_to_heap<A>(&self, _heap: &mut A) -> AllocResult<Term>
     where
@@ -103,8 +108,8 @@ impl CloneToProcess for ExternalPort {
 }
 
 impl Display for ExternalPort {
-    fn fmt(&self, _f: &mut fmt::Formatter) -> fmt::Result {
-        unimplemented!()
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#Port<{}.{}>", self.node.id(), self.port.as_usize())
     }
 }
 
@@ -54,6 +54,20 @@ impl Reference {
     pub fn number(&self) -> ReferenceNumber {
         self.number
     }
+
+    /// Writes the BEAM-compatible `#Ref<node.scheduler.high.low>` form, where `high` and `low`
+    /// are the upper and lower 32 bits of the number, so that `Display`, `ref_to_list/1` and
+    /// `io_lib` all render references the same way.
+    fn fmt_on_node(&self, f: &mut fmt::Formatter, node_id: usize) -> fmt::Result {
+        write!(
+            f,
+            "#Ref<{}.{}.{}.{}>",
+            node_id,
+            self.scheduler_id,
+            self.number >> 32,
+            self.number as u32
+        )
+    }
 }
 
 impl CloneToProcess for Reference {
@@ -77,7 +91,7 @@ impl CloneToProcess for Reference {
 }
 impl Display for Reference {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "#Reference<0.{}.{}>", self.scheduler_id, self.number)
+        self.fmt_on_node(f, 0)
     }
 }
 impl Hash for Reference {
@@ -153,6 +167,11 @@ pub struct ExternalReference {
     reference: Reference,
 }
 impl_static_header!(ExternalReference, Term::HEADER_EXTERN_REF);
+impl ExternalReference {
+    pub fn arc_node(&self) -> Arc<Node> {
+        self.arc_node.clone()
+    }
+}
 impl CloneToProcess for ExternalReference {
     #[inline]
     fn clone_to_heap<A>(&self, _heap: &mut A) -> AllocResult<Term>
@@ -168,8 +187,8 @@ impl CloneToProcess for ExternalReference {
 }
 
 impl Display for ExternalReference {
-    fn fmt(&self, _f: &mut fmt::Formatter) -> fmt::Result {
-        unimplemented!()
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.reference.fmt_on_node(f, self.arc_node.id())
     }
 }
 
@@ -1,3 +1,13 @@
+pub mod decode_hex_1;
+pub mod encode_hex_1;
+pub mod encode_hex_2;
+pub mod longest_common_prefix_1;
+pub mod longest_common_suffix_1;
+pub mod part_2;
+pub mod part_3;
+pub mod pattern;
+pub mod replace_3;
+pub mod replace_4;
 pub mod to_term;
 
 use std::backtrace::Backtrace;
@@ -11,6 +21,12 @@ use liblumen_alloc::erts::exception::{self, ArcError, Exception, InternalExcepti
 use liblumen_alloc::erts::term::prelude::*;
 use liblumen_alloc::Process;
 
+use crate::runtime::context::*;
+
+fn module() -> Atom {
+    Atom::from_str("binary")
+}
+
 pub struct PartRange {
     pub byte_offset: usize,
     pub byte_len: usize,
@@ -124,6 +140,52 @@ pub fn bin_to_list(
     }
 }
 
+/// Copies the bytes of `binary`, which may be any kind of binary, but not a bitstring with trailing
+/// bits.
+pub fn term_try_into_bytes(name: &str, binary: Term) -> exception::Result<Vec<u8>> {
+    match binary.decode()? {
+        TypedTerm::HeapBinary(heap_binary) => Ok(heap_binary.as_bytes().to_vec()),
+        TypedTerm::ProcBin(process_binary) => Ok(process_binary.as_bytes().to_vec()),
+        TypedTerm::BinaryLiteral(binary_literal) => Ok(binary_literal.as_bytes().to_vec()),
+        TypedTerm::SubBinary(subbinary) if subbinary.is_binary() => {
+            Ok(subbinary.full_byte_iter().collect())
+        }
+        TypedTerm::MatchContext(match_context) if match_context.is_binary() => {
+            Ok(match_context.full_byte_iter().collect())
+        }
+        _ => Err(TypeError)
+            .with_context(|| term_is_not_binary(name, binary))
+            .map_err(From::from),
+    }
+}
+
+/// Copies the bytes of each binary in the non-empty proper list `binaries`
+fn binaries_try_into_byte_vecs(binaries: Term) -> exception::Result<Vec<Vec<u8>>> {
+    match binaries.decode()? {
+        TypedTerm::List(cons) => {
+            let mut byte_vecs = Vec::new();
+
+            for result in cons.into_iter() {
+                match result {
+                    Ok(element) => {
+                        byte_vecs.push(term_try_into_bytes("binaries element", element)?)
+                    }
+                    Err(_) => {
+                        return Err(ImproperListError)
+                            .with_context(|| format!("binaries ({}) is improper", binaries))
+                            .map_err(From::from)
+                    }
+                }
+            }
+
+            Ok(byte_vecs)
+        }
+        _ => Err(TypeError)
+            .with_context(|| term_is_not_non_empty_list("binaries", binaries))
+            .map_err(From::from),
+    }
+}
+
 pub fn start_length_to_part_range(
     start: usize,
     length: isize,
@@ -0,0 +1,45 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::term_try_into_bytes;
+
+/// Decodes each pair of hexadecimal digits, in either case, in `bin` to a byte
+#[native_implemented::function(binary:decode_hex/1)]
+pub fn result(process: &Process, bin: Term) -> exception::Result<Term> {
+    let hex_bytes = term_try_into_bytes("bin", bin)?;
+
+    if hex_bytes.len() % 2 != 0 {
+        return Err(anyhow!(
+            "bin ({}) does not have an even number of hexadecimal digits",
+            bin
+        )
+        .into());
+    }
+
+    let mut byte_vec = Vec::with_capacity(hex_bytes.len() / 2);
+
+    for pair in hex_bytes.chunks_exact(2) {
+        match (digit_value(pair[0]), digit_value(pair[1])) {
+            (Some(high), Some(low)) => byte_vec.push((high << 4) | low),
+            _ => {
+                return Err(anyhow!(
+                    "bin ({}) contains characters that are not hexadecimal digits",
+                    bin
+                )
+                .into())
+            }
+        }
+    }
+
+    Ok(process.binary_from_bytes(&byte_vec))
+}
+
+fn digit_value(digit: u8) -> Option<u8> {
+    (digit as char).to_digit(16).map(|value| value as u8)
+}
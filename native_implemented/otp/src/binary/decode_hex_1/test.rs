@@ -0,0 +1,65 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::{Just, Strategy};
+
+use crate::binary::{decode_hex_1, encode_hex_1};
+use crate::test::{strategy, with_process};
+
+#[test]
+fn with_encoded_binary_returns_original_bytes() {
+    run!(
+        |arc_process| {
+            (Just(arc_process.clone()), strategy::byte_vec()).prop_map(|(arc_process, byte_vec)| {
+                let binary = arc_process.binary_from_bytes(&byte_vec);
+
+                (arc_process, binary)
+            })
+        },
+        |(arc_process, binary)| {
+            let hex = encode_hex_1::result(&arc_process, binary).unwrap();
+
+            prop_assert_eq!(decode_hex_1::result(&arc_process, hex), Ok(binary));
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_lowercase_digits_returns_bytes() {
+    with_process(|process| {
+        assert_eq!(
+            decode_hex_1::result(process, process.binary_from_str("01abFF")),
+            Ok(process.binary_from_bytes(&[0x01, 0xAB, 0xFF]))
+        );
+    });
+}
+
+#[test]
+fn with_odd_number_of_digits_errors_badarg() {
+    with_process(|process| {
+        let bin = process.binary_from_str("ABC");
+
+        assert_badarg!(
+            decode_hex_1::result(process, bin),
+            format!(
+                "bin ({}) does not have an even number of hexadecimal digits",
+                bin
+            )
+        );
+    });
+}
+
+#[test]
+fn with_non_hexadecimal_digit_errors_badarg() {
+    with_process(|process| {
+        let bin = process.binary_from_str("0G");
+
+        assert_badarg!(
+            decode_hex_1::result(process, bin),
+            format!(
+                "bin ({}) contains characters that are not hexadecimal digits",
+                bin
+            )
+        );
+    });
+}
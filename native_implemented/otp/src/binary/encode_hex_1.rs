@@ -0,0 +1,11 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::encode_hex_2;
+
+/// Encodes each byte of `bin` as two uppercase hexadecimal digits
+#[native_implemented::function(binary:encode_hex/1)]
+pub fn result(process: &Process, bin: Term) -> exception::Result<Term> {
+    encode_hex_2::result(process, bin, Atom::str_to_term("uppercase"))
+}
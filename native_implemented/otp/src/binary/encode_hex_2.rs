@@ -0,0 +1,41 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::term_try_into_bytes;
+use crate::runtime::context::*;
+
+const UPPERCASE_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+const LOWERCASE_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Encodes each byte of `bin` as two hexadecimal digits in the given `case`
+#[native_implemented::function(binary:encode_hex/2)]
+pub fn result(process: &Process, bin: Term, case: Term) -> exception::Result<Term> {
+    let bytes = term_try_into_bytes("bin", bin)?;
+    let case_atom: Atom = case
+        .try_into()
+        .with_context(|| term_is_not_atom("case", case))?;
+    let digits = match case_atom.name() {
+        "uppercase" => UPPERCASE_DIGITS,
+        "lowercase" => LOWERCASE_DIGITS,
+        _ => {
+            return Err(anyhow!("case ({}) is not uppercase or lowercase", case).into());
+        }
+    };
+
+    let mut hex_vec = Vec::with_capacity(2 * bytes.len());
+
+    for byte in bytes {
+        hex_vec.push(digits[(byte >> 4) as usize]);
+        hex_vec.push(digits[(byte & 0xF) as usize]);
+    }
+
+    Ok(process.binary_from_bytes(&hex_vec))
+}
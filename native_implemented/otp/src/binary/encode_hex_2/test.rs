@@ -0,0 +1,46 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::encode_hex_2::result;
+use crate::test::with_process;
+
+#[test]
+fn with_lowercase_returns_lowercase_digits() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_bytes(&[0x01, 0xAB, 0xFF]),
+                Atom::str_to_term("lowercase")
+            ),
+            Ok(process.binary_from_str("01abff"))
+        );
+    });
+}
+
+#[test]
+fn with_uppercase_returns_uppercase_digits() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_bytes(&[0x01, 0xAB, 0xFF]),
+                Atom::str_to_term("uppercase")
+            ),
+            Ok(process.binary_from_str("01ABFF"))
+        );
+    });
+}
+
+#[test]
+fn with_unknown_case_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(
+                process,
+                process.binary_from_bytes(&[1]),
+                Atom::str_to_term("titlecase")
+            ),
+            "case (titlecase) is not uppercase or lowercase"
+        );
+    });
+}
@@ -0,0 +1,81 @@
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::longest_common_prefix_1::result;
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_list_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_list(arc_process.clone()),
+            )
+        },
+        |(arc_process, binaries)| {
+            prop_assert_badarg!(
+                result(&arc_process, binaries),
+                format!("binaries ({}) is not a non-empty list", binaries)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_empty_list_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, Term::NIL),
+            "binaries ([]) is not a non-empty list"
+        );
+    });
+}
+
+#[test]
+fn with_non_binary_element_errors_badarg() {
+    with_process(|process| {
+        let element = process.integer(1);
+
+        assert_badarg!(
+            result(
+                process,
+                process.list_from_slice(&[process.binary_from_str("ab"), element])
+            ),
+            format!("binaries element ({}) is not a binary", element)
+        );
+    });
+}
+
+#[test]
+fn with_three_binaries_sharing_two_byte_prefix_returns_two() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.list_from_slice(&[
+                    process.binary_from_str("abcd"),
+                    process.binary_from_str("abd"),
+                    process.binary_from_str("abxyz"),
+                ])
+            ),
+            Ok(process.integer(2))
+        );
+    });
+}
+
+#[test]
+fn with_one_binary_returns_its_byte_size() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.list_from_slice(&[process.binary_from_str("abc")])
+            ),
+            Ok(process.integer(3))
+        );
+    });
+}
@@ -0,0 +1,26 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::binaries_try_into_byte_vecs;
+
+/// Returns the length of the longest suffix shared by every binary in `binaries`
+#[native_implemented::function(binary:longest_common_suffix/1)]
+pub fn result(process: &Process, binaries: Term) -> exception::Result<Term> {
+    let byte_vecs = binaries_try_into_byte_vecs(binaries)?;
+    let (first, rest) = byte_vecs.split_first().unwrap();
+
+    let len = rest.iter().fold(first.len(), |len, bytes| {
+        first[first.len() - len..]
+            .iter()
+            .rev()
+            .zip(bytes.iter().rev())
+            .take_while(|(first_byte, byte)| first_byte == byte)
+            .count()
+    });
+
+    Ok(process.integer(len))
+}
@@ -0,0 +1,31 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::longest_common_suffix_1::result;
+use crate::test::with_process;
+
+#[test]
+fn with_empty_list_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, Term::NIL),
+            "binaries ([]) is not a non-empty list"
+        );
+    });
+}
+
+#[test]
+fn with_three_binaries_sharing_two_byte_suffix_returns_two() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.list_from_slice(&[
+                    process.binary_from_str("dcba"),
+                    process.binary_from_str("dba"),
+                    process.binary_from_str("zyxba"),
+                ])
+            ),
+            Ok(process.integer(2))
+        );
+    });
+}
@@ -0,0 +1,11 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang;
+
+/// Same as `erlang:binary_part/2`
+#[native_implemented::function(binary:part/2)]
+pub fn result(process: &Process, binary: Term, start_length: Term) -> exception::Result<Term> {
+    erlang::binary_part_2::result(process, binary, start_length)
+}
@@ -0,0 +1,19 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang;
+
+/// Same as `erlang:binary_part/3`
+#[native_implemented::function(binary:part/3)]
+pub fn result(
+    process: &Process,
+    binary: Term,
+    start: Term,
+    length: Term,
+) -> exception::Result<Term> {
+    erlang::binary_part_3::result(process, binary, start, length)
+}
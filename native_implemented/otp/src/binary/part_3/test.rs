@@ -0,0 +1,35 @@
+use crate::binary::part_3::result;
+use crate::erlang;
+use crate::test::with_process;
+
+#[test]
+fn with_positive_length_returns_same_part_as_erlang_binary_part() {
+    with_process(|process| {
+        let binary = process.binary_from_bytes(&[1, 2, 3, 4]);
+        let start = process.integer(1);
+        let length = process.integer(2);
+
+        assert_eq!(
+            result(process, binary, start, length),
+            erlang::binary_part_3::result(process, binary, start, length)
+        );
+        assert_eq!(
+            result(process, binary, start, length),
+            Ok(process.binary_from_bytes(&[2, 3]))
+        );
+    });
+}
+
+#[test]
+fn with_negative_length_returns_bytes_before_start() {
+    with_process(|process| {
+        let binary = process.binary_from_bytes(&[1, 2, 3, 4]);
+
+        assert_eq!(
+            result(process, binary, process.integer(3), process.integer(-2)),
+            Ok(process.binary_from_bytes(&[2, 3]))
+        );
+    });
+}
+
+// `with_negative_length_past_start_errors_badarg` in integration tests
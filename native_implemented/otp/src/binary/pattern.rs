@@ -0,0 +1,65 @@
+use std::ops::Range;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::term_try_into_bytes;
+
+/// The `Pattern` searched for in a subject binary by the `binary` module: a non-empty binary or a
+/// non-empty list of non-empty binaries.
+pub struct Pattern {
+    byte_vecs: Vec<Vec<u8>>,
+}
+
+impl Pattern {
+    pub fn try_from_term(pattern: Term) -> exception::Result<Self> {
+        let byte_vecs = match pattern.decode()? {
+            TypedTerm::List(cons) => {
+                let mut byte_vecs = Vec::new();
+
+                for result in cons.into_iter() {
+                    match result {
+                        Ok(element) => byte_vecs.push(non_empty_bytes(pattern, element)?),
+                        Err(_) => {
+                            return Err(ImproperListError)
+                                .with_context(|| format!("pattern ({}) is improper", pattern))
+                                .map_err(From::from)
+                        }
+                    }
+                }
+
+                byte_vecs
+            }
+            _ => vec![non_empty_bytes(pattern, pattern)?],
+        };
+
+        Ok(Self { byte_vecs })
+    }
+
+    /// Finds the leftmost match in `subject` at or after `start`.  When several binaries in the
+    /// pattern match at the same position, the longest is used.
+    pub fn find(&self, subject: &[u8], start: usize) -> Option<Range<usize>> {
+        (start..subject.len()).find_map(|position| {
+            let rest = &subject[position..];
+
+            self.byte_vecs
+                .iter()
+                .filter(|bytes| rest.starts_with(bytes))
+                .map(|bytes| bytes.len())
+                .max()
+                .map(|len| position..position + len)
+        })
+    }
+}
+
+fn non_empty_bytes(pattern: Term, element: Term) -> exception::Result<Vec<u8>> {
+    let bytes = term_try_into_bytes("pattern", element)?;
+
+    if bytes.is_empty() {
+        Err(anyhow!("pattern ({}) cannot contain an empty binary", pattern).into())
+    } else {
+        Ok(bytes)
+    }
+}
@@ -0,0 +1,49 @@
+use crate::binary::replace_3::result;
+use crate::test::with_process;
+
+#[test]
+fn with_multiple_matches_only_replaces_first_match() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("abcabc"),
+                process.binary_from_str("b"),
+                process.binary_from_str("XY"),
+            ),
+            Ok(process.binary_from_str("aXYcabc"))
+        );
+    });
+}
+
+#[test]
+fn without_match_returns_subject_bytes() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("abc"),
+                process.binary_from_str("d"),
+                process.binary_from_str("X"),
+            ),
+            Ok(process.binary_from_str("abc"))
+        );
+    });
+}
+
+#[test]
+fn with_empty_pattern_errors_badarg() {
+    with_process(|process| {
+        let pattern = process.binary_from_bytes(&[]);
+
+        assert_badarg!(
+            result(
+                process,
+                process.binary_from_str("abc"),
+                pattern,
+                process.binary_from_str("X"),
+            ),
+            format!("pattern ({}) cannot contain an empty binary", pattern)
+        );
+    });
+}
@@ -0,0 +1,76 @@
+mod options;
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::pattern::Pattern;
+use crate::binary::term_try_into_bytes;
+
+use options::Options;
+
+/// Replaces the first match of `pattern` in `subject` with `replacement`, or every match if the
+/// `global` option is given.
+#[native_implemented::function(binary:replace/4)]
+pub fn result(
+    process: &Process,
+    subject: Term,
+    pattern: Term,
+    replacement: Term,
+    options: Term,
+) -> exception::Result<Term> {
+    let subject_bytes = term_try_into_bytes("subject", subject)?;
+    let pattern = Pattern::try_from_term(pattern)?;
+    let replacement_bytes = term_try_into_bytes("replacement", replacement)?;
+    let options: Options = options.try_into()?;
+
+    let mut insert_replaced = options.insert_replaced;
+    insert_replaced.sort_unstable();
+
+    if let Some(position) = insert_replaced
+        .iter()
+        .find(|position| replacement_bytes.len() < **position)
+    {
+        return Err(anyhow!(
+            "insert_replaced position ({}) exceeds replacement ({}) byte size ({})",
+            position,
+            replacement,
+            replacement_bytes.len()
+        )
+        .into());
+    }
+
+    let mut byte_vec = Vec::with_capacity(subject_bytes.len());
+    let mut start = 0;
+
+    while let Some(range) = pattern.find(&subject_bytes, start) {
+        byte_vec.extend_from_slice(&subject_bytes[start..range.start]);
+
+        // Splice the matched part into the replacement at each `insert_replaced` position
+        let replaced = &subject_bytes[range.clone()];
+        let mut replacement_start = 0;
+
+        for position in insert_replaced.iter().copied() {
+            byte_vec.extend_from_slice(&replacement_bytes[replacement_start..position]);
+            byte_vec.extend_from_slice(replaced);
+            replacement_start = position;
+        }
+
+        byte_vec.extend_from_slice(&replacement_bytes[replacement_start..]);
+        start = range.end;
+
+        if !options.global {
+            break;
+        }
+    }
+
+    byte_vec.extend_from_slice(&subject_bytes[start..]);
+
+    Ok(process.binary_from_bytes(&byte_vec))
+}
@@ -0,0 +1,111 @@
+use std::convert::{TryFrom, TryInto};
+
+use anyhow::*;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::proplist::TryPropListFromTermError;
+
+pub struct Options {
+    pub global: bool,
+    /// Positions in the replacement at which the matched part of the subject is inserted
+    pub insert_replaced: Vec<usize>,
+}
+
+const SUPPORTED_OPTIONS_CONTEXT: &str =
+    "supported options are global or {insert_replaced, Pos | [Pos]}";
+
+impl Options {
+    fn put_option_term(&mut self, option: Term) -> Result<&Self, anyhow::Error> {
+        match option.decode().unwrap() {
+            TypedTerm::Atom(atom) => match atom.name() {
+                "global" => {
+                    self.global = true;
+
+                    Ok(self)
+                }
+                name => Err(TryPropListFromTermError::AtomName(name).into()),
+            },
+            TypedTerm::Tuple(tuple) => {
+                if tuple.len() == 2 {
+                    let atom: Atom = tuple[0]
+                        .try_into()
+                        .map_err(|_| TryPropListFromTermError::KeywordKeyType)?;
+
+                    match atom.name() {
+                        "insert_replaced" => {
+                            self.insert_replaced = positions(tuple[1])?;
+
+                            Ok(self)
+                        }
+                        name => Err(TryPropListFromTermError::KeywordKeyName(name).into()),
+                    }
+                } else {
+                    Err(TryPropListFromTermError::TupleNotPair.into())
+                }
+            }
+            _ => Err(TryPropListFromTermError::PropertyType.into()),
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            global: false,
+            insert_replaced: Vec::new(),
+        }
+    }
+}
+
+impl TryFrom<Term> for Options {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> Result<Self, Self::Error> {
+        let mut options: Options = Default::default();
+        let mut options_term = term;
+
+        loop {
+            match options_term.decode().unwrap() {
+                TypedTerm::Nil => return Ok(options),
+                TypedTerm::List(cons) => {
+                    options
+                        .put_option_term(cons.head)
+                        .context(SUPPORTED_OPTIONS_CONTEXT)?;
+                    options_term = cons.tail;
+
+                    continue;
+                }
+                _ => return Err(ImproperListError.into()),
+            }
+        }
+    }
+}
+
+fn positions(term: Term) -> anyhow::Result<Vec<usize>> {
+    match term.decode().unwrap() {
+        TypedTerm::Nil => Ok(Vec::new()),
+        TypedTerm::List(cons) => {
+            let mut position_vec = Vec::new();
+
+            for result in cons.into_iter() {
+                match result {
+                    Ok(element) => position_vec.push(position(element)?),
+                    Err(_) => return Err(ImproperListError.into()),
+                }
+            }
+
+            Ok(position_vec)
+        }
+        _ => Ok(vec![position(term)?]),
+    }
+}
+
+fn position(term: Term) -> anyhow::Result<usize> {
+    term.try_into().with_context(|| {
+        format!(
+            "insert_replaced position ({}) is not a non-negative integer",
+            term
+        )
+    })
+}
@@ -0,0 +1,82 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::replace_4::result;
+use crate::test::with_process;
+
+#[test]
+fn with_global_replaces_every_match() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("abcabc"),
+                process.binary_from_str("b"),
+                process.binary_from_str("XY"),
+                process.list_from_slice(&[Atom::str_to_term("global")]),
+            ),
+            Ok(process.binary_from_str("aXYcaXYc"))
+        );
+    });
+}
+
+#[test]
+fn with_list_pattern_replaces_longest_match_at_each_position() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("abcab"),
+                process.list_from_slice(&[
+                    process.binary_from_str("a"),
+                    process.binary_from_str("ab"),
+                ]),
+                process.binary_from_str("-"),
+                process.list_from_slice(&[Atom::str_to_term("global")]),
+            ),
+            Ok(process.binary_from_str("-c-"))
+        );
+    });
+}
+
+#[test]
+fn with_insert_replaced_inserts_matched_part_at_each_position() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("abcde"),
+                process.binary_from_str("cd"),
+                process.binary_from_str("[]"),
+                process.list_from_slice(&[process.tuple_from_slice(&[
+                    Atom::str_to_term("insert_replaced"),
+                    process.list_from_slice(&[process.integer(2), process.integer(1)]),
+                ])]),
+            ),
+            Ok(process.binary_from_str("ab[cd]cde"))
+        );
+    });
+}
+
+#[test]
+fn with_insert_replaced_position_past_replacement_errors_badarg() {
+    with_process(|process| {
+        let replacement = process.binary_from_str("[]");
+
+        assert_badarg!(
+            result(
+                process,
+                process.binary_from_str("abcde"),
+                process.binary_from_str("cd"),
+                replacement,
+                process.list_from_slice(&[process.tuple_from_slice(&[
+                    Atom::str_to_term("insert_replaced"),
+                    process.integer(3),
+                ])]),
+            ),
+            format!(
+                "insert_replaced position (3) exceeds replacement ({}) byte size (2)",
+                replacement
+            )
+        );
+    });
+}
@@ -2,6 +2,8 @@
 
 pub mod abs_1;
 pub mod add_2;
+pub mod adler32_1;
+pub mod adler32_2;
 pub mod and_2;
 pub mod andalso_2;
 pub mod append_element_2;
@@ -33,6 +35,7 @@ pub mod bor_2;
 pub mod bsl_2;
 pub mod bsr_2;
 pub mod bxor_2;
+pub mod bump_reductions_1;
 pub mod byte_size_1;
 pub mod cancel_timer_1;
 pub mod cancel_timer_2;
@@ -40,6 +43,8 @@ pub mod ceil_1;
 mod charlist_to_string;
 pub mod concatenate_2;
 pub mod convert_time_unit_3;
+pub mod crc32_1;
+pub mod crc32_2;
 pub mod date_0;
 pub mod delete_element_2;
 pub mod demonitor_1;
@@ -53,14 +58,17 @@ pub mod erase_1;
 pub mod error_1;
 pub mod error_2;
 pub mod exit_1;
+pub mod external_size_1;
 pub mod float_1;
 pub mod float_to_binary_1;
 pub mod float_to_binary_2;
 pub mod float_to_list_1;
 pub mod float_to_list_2;
-mod float_to_string;
+pub(crate) mod float_to_string;
 pub mod floor_1;
 pub mod function_exported_3;
+pub mod garbage_collect_0;
+pub mod garbage_collect_1;
 pub mod get_0;
 pub mod get_1;
 pub mod get_keys_0;
@@ -68,6 +76,10 @@ pub mod get_keys_1;
 pub mod get_stacktrace_0;
 pub mod group_leader_0;
 pub mod group_leader_2;
+pub mod halt_0;
+pub mod halt_1;
+pub mod halt_2;
+pub mod hibernate_3;
 pub mod hd_1;
 pub mod insert_element_3;
 pub mod integer_to_binary_1;
@@ -96,6 +108,7 @@ pub mod is_map_1;
 pub mod is_map_key_2;
 pub mod is_number_1;
 pub mod is_pid_1;
+pub mod is_port_1;
 pub mod is_process_alive_1;
 pub mod is_record_2;
 pub mod is_record_3;
@@ -111,10 +124,12 @@ pub mod list_to_float_1;
 pub mod list_to_integer_1;
 pub mod list_to_integer_2;
 pub mod list_to_pid_1;
-mod list_to_string;
+pub mod list_to_ref_1;
+pub(crate) mod list_to_string;
 pub mod list_to_tuple_1;
 pub mod load_nif_2;
 pub mod localtime_0;
+pub mod make_fun_3;
 pub mod make_ref_0;
 pub mod make_tuple_2;
 pub mod make_tuple_3;
@@ -130,18 +145,25 @@ pub mod multiply_2;
 pub mod negate_1;
 pub mod nif_error_1;
 pub mod node_0;
+pub mod node_1;
+pub mod nodes_0;
 pub mod not_1;
 pub mod now_0;
 pub mod number_or_badarith_1;
 mod number_to_integer;
 pub mod or_2;
 pub mod orelse_2;
+pub mod phash_2;
+pub mod pid_to_list_1;
+pub mod port_to_list_1;
 pub mod process_flag_2;
+pub mod process_info_1;
 pub mod process_info_2;
 pub mod put_2;
 pub mod raise_3;
 pub mod read_timer_1;
 pub mod read_timer_2;
+pub mod ref_to_list_1;
 pub mod register_2;
 pub mod registered_0;
 pub mod rem_2;
@@ -151,6 +173,8 @@ pub mod send_2;
 pub mod send_3;
 pub mod send_after_3;
 pub mod send_after_4;
+pub mod send_nosuspend_2;
+pub mod send_nosuspend_3;
 pub mod seq_trace;
 pub mod seq_trace_2;
 pub mod seq_trace_info_1;
@@ -168,6 +192,7 @@ pub mod spawn_monitor_1;
 pub mod spawn_monitor_3;
 pub mod spawn_opt_2;
 pub mod spawn_opt_4;
+pub mod spawn_request_4;
 pub mod split_binary_2;
 pub mod start_timer_3;
 pub mod start_timer_4;
@@ -198,6 +223,7 @@ pub mod unlink_1;
 pub mod unregister_1;
 pub mod whereis_1;
 pub mod xor_2;
+pub mod yield_0;
 
 use std::convert::TryInto;
 use std::sync::Arc;
@@ -8,6 +8,8 @@ use liblumen_alloc::erts::exception;
 use liblumen_alloc::erts::process::Process;
 use liblumen_alloc::erts::term::prelude::*;
 
+use crate::number;
+
 #[native_implemented::function(erlang:abs/1)]
 fn result(process: &Process, number: Term) -> exception::Result<Term> {
     match number.decode()? {
@@ -15,8 +17,7 @@ fn result(process: &Process, number: Term) -> exception::Result<Term> {
             let i: isize = small_integer.into();
 
             let abs_number = if i < 0 {
-                let positive = -i;
-                process.integer(positive)
+                number::negate_isize(process, i)
             } else {
                 number
             };
@@ -0,0 +1,14 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::adler32_2::adler32;
+use crate::erlang::iolist_or_binary;
+
+/// Computes the Adler-32 checksum of `data`
+#[native_implemented::function(erlang:adler32/1)]
+pub fn result(process: &Process, data: Term) -> exception::Result<Term> {
+    let bytes = iolist_or_binary::to_bytes(data)?;
+
+    Ok(process.integer(adler32(1, &bytes) as u64))
+}
@@ -0,0 +1,40 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::iolist_or_binary;
+use crate::runtime::context::*;
+
+/// Continues the Adler-32 checksum `old_adler` of previous data over `data`, so that
+/// `adler32(adler32(Data1), Data2)` is `adler32([Data1, Data2])`.
+#[native_implemented::function(erlang:adler32/2)]
+pub fn result(process: &Process, old_adler: Term, data: Term) -> exception::Result<Term> {
+    let old_adler_u32: u32 = old_adler
+        .try_into()
+        .with_context(|| term_is_not_type("old_adler", old_adler, "a 32-bit unsigned integer"))?;
+    let bytes = iolist_or_binary::to_bytes(data)?;
+
+    Ok(process.integer(adler32(old_adler_u32, &bytes) as u64))
+}
+
+/// The largest prime less than 2^16
+const MODULUS: u32 = 65521;
+
+pub fn adler32(old_adler: u32, bytes: &[u8]) -> u32 {
+    let mut a = old_adler & 0xFFFF;
+    let mut b = old_adler >> 16;
+
+    for byte in bytes {
+        a = (a + *byte as u32) % MODULUS;
+        b = (b + a) % MODULUS;
+    }
+
+    (b << 16) | a
+}
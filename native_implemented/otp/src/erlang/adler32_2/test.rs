@@ -0,0 +1,44 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::{Just, Strategy};
+
+use crate::erlang::{adler32_1, adler32_2};
+use crate::test::{strategy, with_process};
+
+#[test]
+fn with_chunks_equals_adler32_1_of_concatenation() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::byte_vec(),
+                strategy::byte_vec(),
+            )
+                .prop_map(|(arc_process, first_byte_vec, second_byte_vec)| {
+                    let first = arc_process.binary_from_bytes(&first_byte_vec);
+                    let second = arc_process.binary_from_bytes(&second_byte_vec);
+
+                    (arc_process, first, second)
+                })
+        },
+        |(arc_process, first, second)| {
+            let first_adler = adler32_1::result(&arc_process, first).unwrap();
+
+            prop_assert_eq!(
+                adler32_2::result(&arc_process, first_adler, second),
+                adler32_1::result(&arc_process, arc_process.list_from_slice(&[first, second]))
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_known_data_returns_known_checksum() {
+    with_process(|process| {
+        assert_eq!(
+            adler32_1::result(process, process.binary_from_str("Wikipedia")),
+            Ok(process.integer(0x11E6_0398_u64))
+        );
+    });
+}
@@ -1,7 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::cell::Cell;
+
 use anyhow::*;
 
+use liblumen_alloc::erts::apply::{find_symbol, DynamicCallee};
 use liblumen_alloc::erts::exception;
 use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::ModuleFunctionArity;
 
 use crate::runtime::context::term_is_not_type;
 
@@ -37,3 +44,42 @@ pub fn arguments_term_to_vec(arguments: Term) -> exception::Result<Vec<Term>> {
             .map_err(From::from),
     }
 }
+
+/// Like `find_symbol`, but remembers the last `module_function_arity` resolved on this scheduler
+/// thread, so that dynamic calls in a loop to the same function skip the symbol table.  The symbol
+/// table does not change after startup, so the remembered callee can't go stale.
+pub fn find_symbol_cached(module_function_arity: &ModuleFunctionArity) -> Option<DynamicCallee> {
+    LAST_RESOLVED.with(|last_resolved| match last_resolved.get() {
+        Some((last_module_function_arity, callee))
+            if last_module_function_arity == *module_function_arity =>
+        {
+            Some(callee)
+        }
+        _ => {
+            #[cfg(test)]
+            SYMBOL_TABLE_LOOKUPS.with(|lookups| lookups.set(lookups.get() + 1));
+
+            let option_callee = find_symbol(module_function_arity);
+
+            if let Some(callee) = option_callee {
+                last_resolved.set(Some((*module_function_arity, callee)));
+            }
+
+            option_callee
+        }
+    })
+}
+
+thread_local! {
+    static LAST_RESOLVED: Cell<Option<(ModuleFunctionArity, DynamicCallee)>> = Cell::new(None);
+}
+
+#[cfg(test)]
+thread_local! {
+    static SYMBOL_TABLE_LOOKUPS: Cell<usize> = Cell::new(0);
+}
+
+#[cfg(test)]
+pub fn symbol_table_lookups() -> usize {
+    SYMBOL_TABLE_LOOKUPS.with(|lookups| lookups.get())
+}
@@ -0,0 +1,49 @@
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::ModuleFunctionArity;
+
+use crate::erlang::apply::{find_symbol_cached, symbol_table_lookups};
+use crate::test;
+
+#[test]
+fn with_same_module_function_arity_resolves_from_cache() {
+    // initializes the symbol table
+    test::process::init();
+
+    let exit_1 = module_function_arity("exit", 1);
+    let self_0 = module_function_arity("self", 0);
+
+    assert!(find_symbol_cached(&exit_1).is_some());
+
+    let lookups_before = symbol_table_lookups();
+    let first_callee = find_symbol_cached(&self_0).unwrap();
+
+    assert_eq!(symbol_table_lookups(), lookups_before + 1);
+
+    let second_callee = find_symbol_cached(&self_0).unwrap();
+
+    assert_eq!(symbol_table_lookups(), lookups_before + 1);
+    assert_eq!(second_callee as usize, first_callee as usize);
+
+    assert!(find_symbol_cached(&exit_1).is_some());
+    assert_eq!(symbol_table_lookups(), lookups_before + 2);
+}
+
+#[test]
+fn without_exported_module_function_arity_does_not_cache() {
+    test::process::init();
+
+    let missing = module_function_arity("missing", 0);
+    let lookups_before = symbol_table_lookups();
+
+    assert!(find_symbol_cached(&missing).is_none());
+    assert!(find_symbol_cached(&missing).is_none());
+    assert_eq!(symbol_table_lookups(), lookups_before + 2);
+}
+
+fn module_function_arity(function: &str, arity: u8) -> ModuleFunctionArity {
+    ModuleFunctionArity {
+        module: Atom::from_str("erlang"),
+        function: Atom::from_str(function),
+        arity,
+    }
+}
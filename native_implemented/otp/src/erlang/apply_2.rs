@@ -39,6 +39,17 @@ fn apply_2_impl(
     let arity = function_boxed_closure.arity() as usize;
 
     if arguments_len == arity {
+        // export funs made with `erlang:make_fun/3` may not have been resolved
+        if function_boxed_closure.callee().is_none() {
+            let mfa = function_boxed_closure.module_function_arity();
+            let trace = Trace::capture();
+            trace.set_top_frame(&mfa, argument_vec.as_slice());
+
+            return Err(
+                exception::undef(trace, Some(anyhow!("{} is not exported", mfa).into())).into(),
+            );
+        }
+
         Ok(unsafe { runtime_apply_2(function_boxed_closure, argument_vec) })
     } else {
         let mfa = function_boxed_closure.module_function_arity();
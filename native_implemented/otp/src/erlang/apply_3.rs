@@ -2,7 +2,7 @@ use std::ptr::NonNull;
 
 use anyhow::*;
 
-use liblumen_alloc::erts::apply::{find_symbol, DynamicCallee};
+use liblumen_alloc::erts::apply::DynamicCallee;
 use liblumen_alloc::erts::exception;
 use liblumen_alloc::erts::process::ffi::ErlangResult;
 use liblumen_alloc::erts::process::trace::Trace;
@@ -10,7 +10,7 @@ use liblumen_alloc::erts::process::{Frame, Native};
 use liblumen_alloc::erts::term::prelude::*;
 use liblumen_alloc::{Arity, ModuleFunctionArity};
 
-use crate::erlang::apply::arguments_term_to_vec;
+use crate::erlang::apply::{arguments_term_to_vec, find_symbol_cached};
 
 extern "Rust" {
     #[link_name = "lumen_rt_apply_3"]
@@ -41,7 +41,7 @@ fn apply_3_impl(module: Term, function: Term, arguments: Term) -> exception::Res
         arity,
     };
 
-    match find_symbol(&module_function_arity) {
+    match find_symbol_cached(&module_function_arity) {
         Some(callee) => Ok(unsafe { runtime_apply_3(module_function_arity, callee, argument_vec) }),
         None => {
             let trace = Trace::capture();
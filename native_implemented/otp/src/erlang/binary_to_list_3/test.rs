@@ -1,4 +1,5 @@
 use proptest::strategy::{Just, Strategy};
+use proptest::{prop_assert, prop_assert_eq};
 
 use liblumen_alloc::erts::process::alloc::TermAlloc;
 
@@ -75,6 +76,83 @@ fn with_binary_with_positive_integer_start_without_integer_stop_errors_badarg()
 
 // `with_binary_with_start_less_than_or_equal_to_stop_returns_list_of_bytes` in integration tests
 
+#[test]
+fn with_subbinary_with_start_less_than_or_equal_to_stop_returns_list_of_bytes_in_range() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::byte_vec::with_size_range((1..=4).into()),
+            )
+                .prop_flat_map(|(arc_process, byte_vec)| {
+                    let max_stop = byte_vec.len();
+
+                    (
+                        Just(arc_process.clone()),
+                        Just(byte_vec.clone()),
+                        strategy::term::binary::sub::containing_bytes(
+                            byte_vec,
+                            arc_process.clone(),
+                        ),
+                        (1..=max_stop),
+                    )
+                })
+                .prop_flat_map(|(arc_process, byte_vec, binary, stop)| {
+                    (
+                        Just(arc_process.clone()),
+                        Just(byte_vec),
+                        Just(binary),
+                        1..=stop,
+                        Just(stop),
+                    )
+                })
+        },
+        |(arc_process, byte_vec, binary, start, stop)| {
+            let start_term = arc_process.integer(start);
+            let stop_term = arc_process.integer(stop);
+            let byte_terms = byte_vec[(start - 1)..stop]
+                .iter()
+                .map(|byte| arc_process.integer(*byte));
+
+            prop_assert_eq!(
+                result(&arc_process, binary, start_term, stop_term),
+                Ok(arc_process.list_from_iter(byte_terms))
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_binary_with_stop_greater_than_byte_size_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::byte_vec::with_size_range((1..=4).into()),
+            )
+                .prop_flat_map(|(arc_process, byte_vec)| {
+                    let stop = byte_vec.len() + 1;
+
+                    (
+                        Just(arc_process.clone()),
+                        strategy::term::binary::containing_bytes(byte_vec, arc_process.clone()),
+                        Just(stop),
+                    )
+                })
+        },
+        |(arc_process, binary, stop)| {
+            let start_term = arc_process.integer(1);
+            let stop_term = arc_process.integer(stop);
+
+            prop_assert!(result(&arc_process, binary, start_term, stop_term).is_err());
+
+            Ok(())
+        },
+    );
+}
+
 #[test]
 fn with_binary_with_start_greater_than_stop_errors_badarg() {
     run!(
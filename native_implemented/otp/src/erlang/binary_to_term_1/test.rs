@@ -1,7 +1,7 @@
 use proptest::strategy::Just;
 
 use crate::erlang::binary_to_term_1::result;
-use crate::test::strategy;
+use crate::test::{strategy, with_process};
 
 #[test]
 fn without_binary_errors_badarg() {
@@ -23,6 +23,32 @@ fn without_binary_errors_badarg() {
     );
 }
 
+#[test]
+fn with_binary_encoding_list_longer_than_binary_errors_badarg() {
+    with_process(|process| {
+        // LIST_EXT claiming 1_000_000_000 elements followed by only NIL_EXT
+        let binary = process.binary_from_bytes(&[131, 108, 59, 154, 202, 0, 106]);
+
+        assert_badarg!(
+            result(process, binary),
+            "length (1000000000) cannot fit in the 1 bytes available"
+        );
+    });
+}
+
+#[test]
+fn with_binary_encoding_map_longer_than_binary_errors_badarg() {
+    with_process(|process| {
+        // MAP_EXT claiming 1_000_000_000 pairs followed by only a single key
+        let binary = process.binary_from_bytes(&[131, 116, 59, 154, 202, 0, 106]);
+
+        assert_badarg!(
+            result(process, binary),
+            "length (1000000000) cannot fit in the 1 bytes available"
+        );
+    });
+}
+
 // `with_binary_encoding_atom_returns_atom` in integration tests
 // `with_binary_encoding_empty_list_returns_empty_list` in integration tests
 // `with_binary_encoding_list_returns_list` in integration tests
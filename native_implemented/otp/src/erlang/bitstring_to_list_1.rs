@@ -25,6 +25,12 @@ pub fn result(process: &Process, bitstring: Term) -> exception::Result<Term> {
 
             Ok(process.improper_list_from_iter(byte_term_iter, last))
         }
+        TypedTerm::BinaryLiteral(binary_literal) => {
+            let byte_term_iter = binary_literal.as_bytes().iter().map(|byte| (*byte).into());
+            let last = Term::NIL;
+
+            Ok(process.improper_list_from_iter(byte_term_iter, last))
+        }
         TypedTerm::SubBinary(subbinary) => {
             let last = if subbinary.is_binary() {
                 Term::NIL
@@ -0,0 +1,34 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+use num_bigint::BigInt;
+use num_traits::{Signed, ToPrimitive};
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::{Process, Reductions};
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_is_not_integer;
+
+/// Charges `reductions` to the process, so that work done outside of the normal reduction counting
+/// counts against the reduction budget.  The process is yielded back to the run queue if the
+/// budget is used up.
+#[native_implemented::function(erlang:bump_reductions/1)]
+pub fn result(process: &Process, reductions: Term) -> exception::Result<Term> {
+    let reductions_big_int: BigInt = reductions
+        .try_into()
+        .with_context(|| term_is_not_integer("reductions", reductions))?;
+
+    if reductions_big_int.is_positive() {
+        // Anything above the maximum already uses up any reduction budget
+        let reductions_reductions = reductions_big_int.to_u16().unwrap_or(Reductions::MAX);
+        process.reduce_by(reductions_reductions);
+
+        Ok(true.into())
+    } else {
+        Err(anyhow!("reductions ({}) is not a positive integer", reductions).into())
+    }
+}
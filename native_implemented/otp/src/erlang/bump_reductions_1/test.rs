@@ -0,0 +1,60 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::bump_reductions_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_integer_errors_badarg() {
+    with_process(|process| {
+        let reductions = Atom::str_to_term("many");
+
+        assert_badarg!(
+            result(process, reductions),
+            format!("reductions ({}) is not an integer", reductions)
+        );
+    });
+}
+
+#[test]
+fn with_non_positive_integer_errors_badarg() {
+    with_process(|process| {
+        for reductions in &[process.integer(0), process.integer(-1)] {
+            assert_badarg!(
+                result(process, *reductions),
+                format!("reductions ({}) is not a positive integer", reductions)
+            );
+        }
+    });
+}
+
+#[test]
+fn with_small_positive_integer_does_not_use_up_reduction_budget() {
+    with_process(|process| {
+        assert_eq!(result(process, process.integer(1)), Ok(true.into()));
+
+        assert!(!process.is_reduced());
+    });
+}
+
+#[test]
+fn with_large_positive_integer_uses_up_reduction_budget() {
+    with_process(|process| {
+        let reductions = process.integer(process.reduction_budget());
+
+        assert_eq!(result(process, reductions), Ok(true.into()));
+
+        assert!(process.is_reduced());
+    });
+}
+
+#[test]
+fn with_big_integer_uses_up_reduction_budget() {
+    with_process(|process| {
+        let reductions = process.integer(SmallInteger::MAX_VALUE + 1);
+
+        assert!(reductions.is_boxed_bigint());
+        assert_eq!(result(process, reductions), Ok(true.into()));
+
+        assert!(process.is_reduced());
+    });
+}
@@ -1,3 +1,6 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
 use std::convert::TryInto;
 
 use anyhow::*;
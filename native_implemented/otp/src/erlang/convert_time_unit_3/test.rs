@@ -0,0 +1,79 @@
+use liblumen_alloc::atom;
+
+use crate::erlang::convert_time_unit_3::result;
+use crate::test::with_process;
+
+#[test]
+fn with_coarser_to_unit_floors_positive_time() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.integer(1500),
+                atom!("millisecond"),
+                atom!("second")
+            ),
+            Ok(process.integer(1))
+        );
+    });
+}
+
+#[test]
+fn with_coarser_to_unit_floors_negative_time() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.integer(-1500),
+                atom!("millisecond"),
+                atom!("second")
+            ),
+            Ok(process.integer(-2))
+        );
+    });
+}
+
+#[test]
+fn with_finer_to_unit_multiplies() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.integer(2),
+                atom!("second"),
+                atom!("microsecond")
+            ),
+            Ok(process.integer(2_000_000))
+        );
+    });
+}
+
+#[test]
+fn with_hertz_that_do_not_divide_evenly_does_not_lose_precision() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.integer(3),
+                process.integer(3),
+                process.integer(1000)
+            ),
+            Ok(process.integer(1000))
+        );
+    });
+}
+
+#[test]
+fn with_unsupported_unit_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(
+                process,
+                process.integer(1),
+                atom!("fortnight"),
+                atom!("second")
+            ),
+            "supported units are"
+        );
+    });
+}
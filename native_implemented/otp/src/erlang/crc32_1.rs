@@ -0,0 +1,14 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::crc32_2::crc32;
+use crate::erlang::iolist_or_binary;
+
+/// Computes the CRC-32 (IEEE 802.3) checksum of `data`
+#[native_implemented::function(erlang:crc32/1)]
+pub fn result(process: &Process, data: Term) -> exception::Result<Term> {
+    let bytes = iolist_or_binary::to_bytes(data)?;
+
+    Ok(process.integer(crc32(0, &bytes) as u64))
+}
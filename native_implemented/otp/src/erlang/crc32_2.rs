@@ -0,0 +1,60 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::iolist_or_binary;
+use crate::runtime::context::*;
+
+/// Continues the CRC-32 checksum `old_crc` of previous data over `data`, so that
+/// `crc32(crc32(Data1), Data2)` is `crc32([Data1, Data2])`.
+#[native_implemented::function(erlang:crc32/2)]
+pub fn result(process: &Process, old_crc: Term, data: Term) -> exception::Result<Term> {
+    let old_crc_u32: u32 = old_crc
+        .try_into()
+        .with_context(|| term_is_not_type("old_crc", old_crc, "a 32-bit unsigned integer"))?;
+    let bytes = iolist_or_binary::to_bytes(data)?;
+
+    Ok(process.integer(crc32(old_crc_u32, &bytes) as u64))
+}
+
+/// The reflected CRC-32 polynomial used by zlib
+const POLYNOMIAL: u32 = 0xEDB8_8320;
+
+const TABLE: [u32; 256] = table();
+
+const fn table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut index = 0;
+
+    while index < 256 {
+        let mut crc = index as u32;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+
+        table[index] = crc;
+        index += 1;
+    }
+
+    table
+}
+
+pub fn crc32(old_crc: u32, bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!old_crc, |crc, byte| {
+        TABLE[((crc ^ (*byte as u32)) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
@@ -0,0 +1,69 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::{Just, Strategy};
+
+use crate::erlang::{crc32_1, crc32_2};
+use crate::test::{strategy, with_process};
+
+#[test]
+fn with_chunks_equals_crc32_1_of_concatenation() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::byte_vec(),
+                strategy::byte_vec(),
+            )
+                .prop_map(|(arc_process, first_byte_vec, second_byte_vec)| {
+                    let first = arc_process.binary_from_bytes(&first_byte_vec);
+                    let second = arc_process.binary_from_bytes(&second_byte_vec);
+
+                    (arc_process, first, second)
+                })
+        },
+        |(arc_process, first, second)| {
+            let first_crc = crc32_1::result(&arc_process, first).unwrap();
+
+            prop_assert_eq!(
+                crc32_2::result(&arc_process, first_crc, second),
+                crc32_1::result(&arc_process, arc_process.list_from_slice(&[first, second]))
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_known_data_returns_known_checksum() {
+    with_process(|process| {
+        assert_eq!(
+            crc32_1::result(process, process.binary_from_str("123456789")),
+            Ok(process.integer(0xCBF4_3926_u64))
+        );
+        assert_eq!(
+            crc32_2::result(
+                process,
+                process.integer(0),
+                process.binary_from_str("123456789")
+            ),
+            Ok(process.integer(0xCBF4_3926_u64))
+        );
+    });
+}
+
+#[test]
+fn with_iolist_equals_binary_of_same_bytes() {
+    with_process(|process| {
+        assert_eq!(
+            crc32_1::result(
+                process,
+                process.list_from_slice(&[
+                    process.integer(b'1'),
+                    process.binary_from_str("2345"),
+                    process.list_from_slice(&[process.binary_from_str("6789")]),
+                ])
+            ),
+            crc32_1::result(process, process.binary_from_str("123456789"))
+        );
+    });
+}
@@ -1,4 +1,4 @@
-mod options;
+pub(crate) mod options;
 
 use std::convert::TryInto;
 
@@ -21,7 +21,7 @@ pub fn result(process: &Process, reference: Term, options: Term) -> exception::R
 
 // Private
 
-pub(in crate::erlang) fn demonitor(
+pub(crate) fn demonitor(
     monitoring_process: &Process,
     reference: &Reference,
     Options { flush, info }: Options,
@@ -0,0 +1,16 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::Term;
+
+use crate::erlang::term_to_binary::external_size;
+
+/// The number of bytes `term_to_binary(term)` would return
+#[native_implemented::function(erlang:external_size/1)]
+pub fn result(process: &Process, term: Term) -> exception::Result<Term> {
+    let size = external_size(term)?;
+
+    Ok(process.integer(size))
+}
@@ -0,0 +1,56 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::external_size_1::result;
+use crate::erlang::{byte_size_1, term_to_binary_1};
+use crate::runtime::scheduler::SchedulerDependentAlloc;
+use crate::test::with_process;
+
+#[test]
+fn is_byte_size_of_term_to_binary() {
+    with_process(|process| {
+        let terms = [
+            Atom::str_to_term("atom"),
+            process.integer(0),
+            process.integer(-1),
+            process.integer(SmallInteger::MAX_VALUE + 1),
+            process.float(1.5),
+            Term::NIL,
+            process.charlist_from_str("charlist"),
+            process.list_from_slice(&[process.integer(1000), Atom::str_to_term("element")]),
+            process.tuple_from_slice(&[Atom::str_to_term("tuple"), process.integer(1)]),
+            process.binary_from_bytes(&[1, 2, 3]),
+            process.map_from_slice(&[(Atom::str_to_term("key"), process.integer(1))]),
+            Atom::str_to_term("ätom"),
+            process.improper_list_from_slice(&[process.integer(1)], Atom::str_to_term("tail")),
+            process.pid_term(),
+            process.next_reference(),
+            process.tuple_from_slice(&[
+                process.binary_from_str("nested"),
+                process.list_from_slice(&[process.float(2.5)]),
+            ]),
+        ];
+
+        for term in terms.iter() {
+            let binary = term_to_binary_1::result(process, *term).unwrap();
+
+            assert_eq!(
+                result(process, *term),
+                byte_size_1::result(process, binary),
+                "external_size({})",
+                term
+            );
+        }
+    });
+}
+
+#[test]
+fn with_term_without_external_term_format_errors_badarg() {
+    with_process(|process| {
+        let resource = process.resource(0_usize);
+
+        assert_badarg!(
+            result(process, resource),
+            "cannot be encoded in the external term format"
+        );
+    });
+}
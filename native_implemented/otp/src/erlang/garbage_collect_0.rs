@@ -0,0 +1,20 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::process::{Process, ProcessFlags};
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Forces a full sweep the next time the scheduler checks whether `process` should be collected.
+///
+/// The collection can't run here directly as the roots on the native stack of the caller are not
+/// known.
+#[native_implemented::function(erlang:garbage_collect/0)]
+pub fn result(process: &Process) -> Term {
+    force_full_sweep(process);
+
+    true.into()
+}
+
+pub(in crate::erlang) fn force_full_sweep(process: &Process) {
+    process.set_flags(ProcessFlags::ForceGC | ProcessFlags::NeedFullSweep);
+}
@@ -0,0 +1,12 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::garbage_collect_0::result;
+use crate::test::with_process;
+
+#[test]
+fn returns_true_and_forces_collection() {
+    with_process(|process| {
+        assert_eq!(result(process), true.into());
+        assert!(process.should_collect());
+    });
+}
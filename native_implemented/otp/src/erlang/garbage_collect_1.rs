@@ -0,0 +1,29 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::garbage_collect_0::force_full_sweep;
+use crate::runtime::registry::pid_to_process;
+
+#[native_implemented::function(erlang:garbage_collect/1)]
+pub fn result(process: &Process, pid: Term) -> exception::Result<Term> {
+    let pid_pid = term_try_into_local_pid!(pid)?;
+
+    if process.pid() == pid_pid {
+        force_full_sweep(process);
+
+        Ok(true.into())
+    } else {
+        match pid_to_process(&pid_pid) {
+            Some(pid_arc_process) if !pid_arc_process.is_exiting() => {
+                force_full_sweep(&pid_arc_process);
+
+                Ok(true.into())
+            }
+            _ => Ok(false.into()),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::garbage_collect_1::result;
+use crate::test;
+use crate::test::{strategy, with_process_arc};
+
+#[test]
+fn without_local_pid_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_local_pid(arc_process.clone()),
+            )
+        },
+        |(arc_process, pid)| {
+            prop_assert_is_not_local_pid!(result(&arc_process, pid), pid);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_self_returns_true() {
+    with_process_arc(|arc_process| {
+        assert_eq!(
+            result(&arc_process, arc_process.pid_term()),
+            Ok(true.into())
+        );
+    });
+}
+
+#[test]
+fn with_other_alive_process_returns_true() {
+    with_process_arc(|arc_process| {
+        let other_arc_process = test::process::child(&arc_process);
+
+        assert_eq!(
+            result(&arc_process, other_arc_process.pid_term()),
+            Ok(true.into())
+        );
+        assert!(other_arc_process.should_collect());
+    });
+}
+
+#[test]
+fn with_dead_process_returns_false() {
+    with_process_arc(|arc_process| {
+        let pid = Pid::next_term();
+
+        assert_eq!(result(&arc_process, pid), Ok(false.into()));
+    });
+}
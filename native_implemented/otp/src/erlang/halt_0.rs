@@ -0,0 +1,8 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::halt_2::{halt, Status};
+
+#[native_implemented::function(erlang:halt/0)]
+pub fn result() -> Term {
+    halt(Status::Code(0), Default::default())
+}
@@ -0,0 +1,16 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::halt_2::{halt, Status};
+
+#[native_implemented::function(erlang:halt/1)]
+pub fn result(status: Term) -> exception::Result<Term> {
+    let status_status: Status = status.try_into()?;
+
+    halt(status_status, Default::default())
+}
@@ -0,0 +1,35 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::halt_1::result;
+use crate::test::with_process;
+
+#[test]
+fn with_negative_integer_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process.integer(-1)),
+            "status (-1) is not a non-negative integer, the atom abort, or a string"
+        );
+    });
+}
+
+#[test]
+fn with_atom_other_than_abort_errors_badarg() {
+    assert_badarg!(
+        result(Atom::str_to_term("stop")),
+        "status (stop) is not a non-negative integer, the atom abort, or a string"
+    );
+}
+
+#[test]
+fn with_float_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process.float(1.0)),
+            "is not a non-negative integer, the atom abort, or a string"
+        );
+    });
+}
+
+// `with_zero_after_display_flushes_stdout` in integration tests
+// `with_string_halts_after_flushing_stdout` in integration tests
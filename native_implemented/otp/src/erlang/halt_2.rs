@@ -0,0 +1,46 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod options;
+mod status;
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime;
+
+use crate::erlang::halt_2::options::Options;
+pub(in crate::erlang) use crate::erlang::halt_2::status::Status;
+
+#[native_implemented::function(erlang:halt/2)]
+pub fn result(status: Term, options: Term) -> exception::Result<Term> {
+    let status_status: Status = status.try_into()?;
+    let options_options: Options = options.try_into()?;
+
+    halt(status_status, options_options)
+}
+
+// Private
+
+pub(in crate::erlang) fn halt(status: Status, Options { flush }: Options) -> ! {
+    match status {
+        Status::Code(code) => {
+            if flush {
+                runtime::sys::io::flush();
+            }
+
+            std::process::exit(code)
+        }
+        Status::Abort => std::process::abort(),
+        // BEAM writes a crash dump with `slogan`, but there is no crash dump support, so only the
+        // `slogan` is reported
+        Status::Slogan(slogan) => {
+            runtime::sys::io::flush();
+            eprintln!("Halted with slogan: {}", slogan);
+
+            std::process::exit(1)
+        }
+    }
+}
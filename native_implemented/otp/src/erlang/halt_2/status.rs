@@ -0,0 +1,53 @@
+use std::convert::{TryFrom, TryInto};
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception::{self, Exception};
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::charlist_to_string::charlist_to_string;
+use crate::runtime::context::*;
+
+pub enum Status {
+    /// Exit the OS process with this status code
+    Code(i32),
+    /// Abort the OS process, which produces a core dump if enabled
+    Abort,
+    /// Halt with a crash dump `slogan` instead of a status code
+    Slogan(String),
+}
+
+impl TryFrom<Term> for Status {
+    type Error = Exception;
+
+    fn try_from(term: Term) -> exception::Result<Self> {
+        match term.decode()? {
+            TypedTerm::Atom(atom) if atom == "abort" => Ok(Status::Abort),
+            TypedTerm::SmallInteger(small_integer) => {
+                let code: i32 = small_integer
+                    .try_into()
+                    .ok()
+                    .filter(|code| 0 <= *code)
+                    .with_context(|| term_is_not_status(term))?;
+
+                Ok(Status::Code(code))
+            }
+            TypedTerm::Nil | TypedTerm::List(_) => {
+                let slogan = charlist_to_string(term)?;
+
+                Ok(Status::Slogan(slogan))
+            }
+            _ => Err(TypeError)
+                .with_context(|| term_is_not_status(term))
+                .map_err(From::from),
+        }
+    }
+}
+
+fn term_is_not_status(term: Term) -> String {
+    term_is_not_type(
+        "status",
+        term,
+        "a non-negative integer, the atom abort, or a string",
+    )
+}
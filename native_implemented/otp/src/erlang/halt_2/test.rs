@@ -0,0 +1,41 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::halt_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_proper_list_options_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process.integer(0), Atom::str_to_term("flush")),
+            "supported option is {:flush, boolean}"
+        );
+    });
+}
+
+#[test]
+fn with_unsupported_option_errors_badarg() {
+    with_process(|process| {
+        let options = process.list_from_slice(&[
+            process.tuple_from_slice(&[Atom::str_to_term("sync"), true.into()])
+        ]);
+
+        assert_badarg!(
+            result(process.integer(0), options),
+            "supported option is {:flush, boolean}"
+        );
+    });
+}
+
+#[test]
+fn with_non_boolean_flush_errors_badarg() {
+    with_process(|process| {
+        let options = process.list_from_slice(&[
+            process.tuple_from_slice(&[Atom::str_to_term("flush"), process.integer(1)])
+        ]);
+
+        assert_badarg!(result(process.integer(0), options), "flush");
+    });
+}
+
+// `with_flush_true_after_display_flushes_stdout` in integration tests
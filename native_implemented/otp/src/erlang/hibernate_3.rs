@@ -0,0 +1,44 @@
+//! ```elixir
+//! def hibernate(module, function, arguments) do
+//!   discard_stack()
+//!   garbage_collect()
+//!   receive_any_without_removing()
+//!   apply(module, function, arguments)
+//! end
+//! ```
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply::arguments_term_to_vec;
+use crate::erlang::garbage_collect_0::force_full_sweep;
+
+/// Never returns to its caller: the stack is discarded, and once a message arrives the process
+/// resumes by calling `module:function(arguments...)`.  When that call returns, the process
+/// exits.
+#[native_implemented::function(erlang:hibernate/3)]
+pub fn result(
+    process: &Process,
+    module: Term,
+    function: Term,
+    arguments: Term,
+) -> exception::Result<Term> {
+    // Validate before discarding the stack, so that `badarg` is raised to the caller
+    term_try_into_atom!(module)?;
+    term_try_into_atom!(function)?;
+    arguments_term_to_vec(arguments)?;
+
+    process.discard_stack();
+    force_full_sweep(process);
+    process.queue_frame_with_arguments(
+        label_1::frame().with_arguments(false, &[module, function, arguments]),
+    );
+
+    Ok(Term::NONE)
+}
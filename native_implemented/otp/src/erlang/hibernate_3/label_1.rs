@@ -0,0 +1,47 @@
+//! ```elixir
+//! # label 1
+//! # pushed to stack: (module, function, arguments)
+//! # returned from call: N/A
+//! # full stack: (module, function, arguments)
+//! # returns: value
+//! garbage_collect()
+//! receive_any_without_removing()
+//! apply(module, function, arguments)
+//! ```
+
+use liblumen_alloc::erts::process::{Process, ProcessFlags};
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_3;
+
+// Private
+
+#[native_implemented::label]
+fn result(process: &Process, module: Term, function: Term, arguments: Term) -> Term {
+    let is_empty = process.mailbox.lock().borrow().is_empty();
+
+    if is_empty {
+        let mut roots = [module, function, arguments];
+        collect_before_waiting(process, &mut roots);
+
+        process.wait();
+        process.queue_frame_with_arguments(frame().with_arguments(false, &roots));
+    } else {
+        process.queue_frame_with_arguments(
+            apply_3::frame().with_arguments(false, &[module, function, arguments]),
+        );
+    }
+
+    Term::NONE
+}
+
+/// Runs the full sweep forced by `hibernate/3` before the process starts waiting, so that the
+/// heap is as small as possible while the process is hibernating.
+///
+/// The stack was discarded by `hibernate/3`, so `roots` (this label's arguments) are the only
+/// roots outside of the process dictionary.  Spurious wake ups find the collection already done.
+fn collect_before_waiting(process: &Process, roots: &mut [Term]) {
+    if process.should_collect() && process.garbage_collect(0, roots).is_ok() {
+        process.clear_flags(ProcessFlags::ForceGC);
+    }
+}
@@ -0,0 +1,89 @@
+use std::convert::TryInto;
+
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::Status;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::hibernate_3::result;
+use crate::erlang::spawn_3;
+use crate::runtime::registry::pid_to_process;
+use crate::runtime::scheduler::Scheduled;
+use crate::test::*;
+
+#[test]
+fn without_atom_module_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_atom(arc_process.clone()),
+            )
+        },
+        |(arc_process, module)| {
+            let function = atom!("hibernate_function");
+            let arguments = Term::NIL;
+
+            prop_assert_is_not_atom!(result(&arc_process, module, function, arguments), module);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn without_atom_function_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_atom(arc_process.clone()),
+            )
+        },
+        |(arc_process, function)| {
+            let module = atom!("hibernate_module");
+            let arguments = Term::NIL;
+
+            prop_assert_is_not_atom!(result(&arc_process, module, function, arguments), function);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_garbage_shrinks_heap_before_waiting() {
+    let parent_arc_process = process::init();
+    let scheduler = parent_arc_process.scheduler().unwrap();
+
+    let arguments =
+        parent_arc_process.list_from_slice(&[atom!("erlang"), atom!("self"), Term::NIL]);
+    let child_pid_term = spawn_3::result(
+        &parent_arc_process,
+        atom!("erlang"),
+        atom!("hibernate"),
+        arguments,
+    )
+    .unwrap();
+    let child_pid: Pid = child_pid_term.try_into().unwrap();
+    let child_arc_process = pid_to_process(&child_pid).unwrap();
+
+    // Fill the heap and spill into heap fragments with binaries that nothing references
+    for _ in 0..1_000 {
+        child_arc_process.binary_from_bytes(&[0; HeapBin::MAX_SIZE]);
+    }
+
+    let garbage_heap_size = child_arc_process.total_heap_size();
+
+    for _ in 0..10 {
+        if *child_arc_process.status.read() == Status::Waiting {
+            break;
+        }
+
+        scheduler.run_once();
+    }
+
+    assert_eq!(*child_arc_process.status.read(), Status::Waiting);
+    assert!(child_arc_process.total_heap_size() < garbage_heap_size);
+}
@@ -38,16 +38,42 @@ pub fn result(
         | TypedTerm::ProcBin(_)
         | TypedTerm::SubBinary(_) => try_into(process, iolist_or_binary),
         _ => Err(TypeError)
-            .context(term_is_not_type(
-                "iolist_or_binary",
-                iolist_or_binary,
-                &format!("an iolist ({}) or binary", r#type::IOLIST),
-            ))
+            .context(term_is_not_iolist_or_binary(iolist_or_binary))
             .map_err(From::from),
     }
 }
 
+/// Returns the bytes of `iolist_or_binary`, which, unlike an element of an iolist, cannot be a byte
+pub fn to_bytes(iolist_or_binary: Term) -> exception::Result<Vec<u8>> {
+    match iolist_or_binary.decode()? {
+        TypedTerm::Nil
+        | TypedTerm::List(_)
+        | TypedTerm::BinaryLiteral(_)
+        | TypedTerm::HeapBinary(_)
+        | TypedTerm::MatchContext(_)
+        | TypedTerm::ProcBin(_)
+        | TypedTerm::SubBinary(_) => iolist_to_bytes("iolist_or_binary", iolist_or_binary),
+        _ => Err(TypeError)
+            .context(term_is_not_iolist_or_binary(iolist_or_binary))
+            .map_err(From::from),
+    }
+}
+
+fn term_is_not_iolist_or_binary(iolist_or_binary: Term) -> String {
+    term_is_not_type(
+        "iolist_or_binary",
+        iolist_or_binary,
+        &format!("an iolist ({}) or binary", r#type::IOLIST),
+    )
+}
+
 pub fn to_binary(process: &Process, name: &'static str, value: Term) -> exception::Result<Term> {
+    let byte_vec = iolist_to_bytes(name, value)?;
+
+    Ok(process.binary_from_bytes(byte_vec.as_slice()))
+}
+
+fn iolist_to_bytes(name: &'static str, value: Term) -> exception::Result<Vec<u8>> {
     let mut byte_vec: Vec<u8> = Vec::new();
     let mut stack: Vec<Term> = vec![value];
 
@@ -102,6 +128,9 @@ pub fn to_binary(process: &Process, name: &'static str, value: Term) -> exceptio
             TypedTerm::ProcBin(procbin) => {
                 byte_vec.extend_from_slice(procbin.as_bytes());
             }
+            TypedTerm::BinaryLiteral(binary_literal) => {
+                byte_vec.extend_from_slice(binary_literal.as_bytes());
+            }
             _ => {
                 return Err(TypeError)
                     .context(element_context(name, value, top))
@@ -110,10 +139,10 @@ pub fn to_binary(process: &Process, name: &'static str, value: Term) -> exceptio
         }
     }
 
-    Ok(process.binary_from_bytes(byte_vec.as_slice()))
+    Ok(byte_vec)
 }
 
-fn element_context(name: &'static str, value: Term, element: Term) -> String {
+pub fn element_context(name: &'static str, value: Term, element: Term) -> String {
     format!(
         "{} ({}) element ({}) is not a byte, binary, or nested iolist",
         name, value, element
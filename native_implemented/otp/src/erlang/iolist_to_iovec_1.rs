@@ -1,13 +1,20 @@
 #[cfg(all(not(target_arch = "wasm32"), test))]
 mod test;
 
+use std::convert::TryInto;
+
+use anyhow::*;
+
 use liblumen_alloc::erts::exception;
 use liblumen_alloc::erts::process::Process;
 use liblumen_alloc::erts::term::prelude::*;
 
-use crate::erlang::iolist_or_binary;
+use crate::erlang::iolist_or_binary::{self, element_context};
 
-/// Returns a binary that is made from the integers and binaries given in iolist
+/// Returns a list of binaries that is made from the integers and binaries given in iolist
+///
+/// Bytes and small binaries are coalesced into new binaries, but binaries that are large enough
+/// to be reference-counted are included as-is, so that their bytes are not copied.
 #[native_implemented::function(erlang:iolist_to_iovec/1)]
 pub fn result(process: &Process, iolist_or_binary: Term) -> exception::Result<Term> {
     iolist_or_binary::result(process, iolist_or_binary, iolist_or_binary_to_iovec)
@@ -17,7 +24,113 @@ pub fn iolist_or_binary_to_iovec(
     process: &Process,
     iolist_or_binary: Term,
 ) -> exception::Result<Term> {
-    let binary = iolist_or_binary::to_binary(process, "iolist_or_binary", iolist_or_binary)?;
+    let mut iovec = IOVec::new(process);
+    let mut stack: Vec<Term> = vec![iolist_or_binary];
+
+    while let Some(top) = stack.pop() {
+        match top.decode()? {
+            TypedTerm::SmallInteger(small_integer) => {
+                let top_byte = small_integer
+                    .try_into()
+                    .with_context(|| element_context(NAME, iolist_or_binary, top))?;
+
+                iovec.byte_vec.push(top_byte);
+            }
+            TypedTerm::Nil => (),
+            TypedTerm::List(boxed_cons) => {
+                // @type iolist :: maybe_improper_list(byte() | binary() | iolist(),
+                // binary() | []) means that `byte()` isn't allowed
+                // for `tail`s unlike `head`.
+
+                let tail = boxed_cons.tail;
+                let result_u8: Result<u8, _> = tail.try_into();
+
+                match result_u8 {
+                    Ok(_) => {
+                        return Err(TypeError)
+                            .context(format!(
+                                "{} ({}) tail ({}) cannot be a byte",
+                                NAME, iolist_or_binary, tail
+                            ))
+                            .map_err(From::from)
+                    }
+                    Err(_) => stack.push(tail),
+                };
+
+                stack.push(boxed_cons.head);
+            }
+            TypedTerm::HeapBinary(heap_binary) => {
+                iovec.byte_vec.extend_from_slice(heap_binary.as_bytes());
+            }
+            TypedTerm::BinaryLiteral(binary_literal) => {
+                iovec.push_binary(top, binary_literal.as_bytes());
+            }
+            TypedTerm::ProcBin(procbin) => {
+                iovec.push_binary(top, procbin.as_bytes());
+            }
+            TypedTerm::SubBinary(subbinary) => {
+                if subbinary.is_binary() {
+                    if subbinary.is_aligned() {
+                        iovec.push_binary(top, unsafe { subbinary.as_bytes_unchecked() });
+                    } else {
+                        iovec.byte_vec.extend(subbinary.full_byte_iter());
+                    }
+                } else {
+                    return Err(NotABinary)
+                        .context(element_context(NAME, iolist_or_binary, top))
+                        .map_err(From::from);
+                }
+            }
+            _ => {
+                return Err(TypeError)
+                    .context(element_context(NAME, iolist_or_binary, top))
+                    .map_err(From::from)
+            }
+        }
+    }
+
+    Ok(iovec.finish())
+}
+
+// Private
+
+const NAME: &str = "iolist_or_binary";
+
+struct IOVec<'a> {
+    process: &'a Process,
+    binaries: Vec<Term>,
+    byte_vec: Vec<u8>,
+}
+
+impl<'a> IOVec<'a> {
+    fn new(process: &'a Process) -> Self {
+        Self {
+            process,
+            binaries: Vec::new(),
+            byte_vec: Vec::new(),
+        }
+    }
+
+    fn finish(mut self) -> Term {
+        self.flush();
+
+        self.process.list_from_slice(&self.binaries)
+    }
+
+    fn flush(&mut self) {
+        if !self.byte_vec.is_empty() {
+            self.binaries
+                .push(self.process.binary_from_bytes(&self.byte_vec));
+            self.byte_vec.clear();
+        }
+    }
 
-    Ok(process.list_from_slice(&[binary]))
+    fn push_binary(&mut self, binary: Term, bytes: &[u8]) {
+        if HeapBin::MAX_SIZE < bytes.len() {
+            self.flush();
+            self.binaries.push(binary);
+        } else {
+            self.byte_vec.extend_from_slice(bytes);
+        }
+    }
 }
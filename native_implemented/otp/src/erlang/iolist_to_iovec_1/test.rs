@@ -161,3 +161,48 @@ fn with_atom_in_iolist_errors_badarg() {
         )
     });
 }
+
+#[test]
+fn with_large_binary_in_iolist_references_binary_without_copying() {
+    with_process(|process| {
+        let procbin = process.binary_from_bytes(&[7; 65]);
+        let iolist = process.list_from_slice(&[
+            process.integer(1),
+            process.binary_from_bytes(&[2, 3]),
+            procbin,
+            process.integer(4),
+        ]);
+
+        let iovec = result(process, iolist).unwrap();
+
+        assert_eq!(
+            iovec,
+            process.list_from_slice(&[
+                process.binary_from_bytes(&[1, 2, 3]),
+                process.binary_from_bytes(&[7; 65]),
+                process.binary_from_bytes(&[4])
+            ])
+        );
+
+        let iovec_cons: Boxed<Cons> = iovec.try_into().unwrap();
+        let iovec_procbin = iovec_cons.into_iter().nth(1).unwrap().unwrap();
+
+        assert_eq!(procbin_ptr(iovec_procbin), procbin_ptr(procbin));
+    });
+}
+
+#[test]
+fn with_empty_iolist_returns_empty_list() {
+    with_process(|process| {
+        let iolist = process.list_from_slice(&[Term::NIL, process.binary_from_bytes(&[])]);
+
+        assert_eq!(result(process, iolist), Ok(Term::NIL));
+    });
+}
+
+fn procbin_ptr(term: Term) -> *mut ProcBin {
+    match term.decode().unwrap() {
+        TypedTerm::ProcBin(procbin) => procbin.as_ptr(),
+        typed_term => panic!("{} is not a procbin", typed_term),
+    }
+}
@@ -8,6 +8,7 @@ mod with_list_left;
 mod with_local_pid_left;
 mod with_local_reference_left;
 mod with_map_left;
+mod with_nested_left;
 mod with_small_integer_left;
 mod with_subbinary_left;
 mod with_tuple_left;
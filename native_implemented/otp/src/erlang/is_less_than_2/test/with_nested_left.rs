@@ -0,0 +1,169 @@
+use super::*;
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use proptest::collection::{btree_map, vec};
+use proptest::prelude::*;
+
+#[test]
+fn with_same_size_tuple_with_lesser_nested_list_right_returns_false() {
+    is_less_than(
+        |process| nested_tuple(process, 4),
+        |_, process| nested_tuple(process, 3),
+        false,
+    );
+}
+
+#[test]
+fn with_same_size_tuple_with_greater_nested_list_right_returns_true() {
+    is_less_than(
+        |process| nested_tuple(process, 3),
+        |_, process| nested_tuple(process, 4),
+        true,
+    );
+}
+
+#[test]
+fn with_smaller_tuple_with_greater_elements_right_returns_false() {
+    is_less_than(
+        |process| process.tuple_from_slice(&[process.integer(1), process.integer(2)]),
+        |_, process| process.tuple_from_slice(&[process.integer(3)]),
+        false,
+    );
+}
+
+#[test]
+fn with_proper_list_left_and_improper_list_with_same_heads_right_returns_false() {
+    // `[1, 2] < [1 | 2]` compares the tails `[2]` and `2`, and lists are greater than numbers
+    is_less_than(
+        |process| process.list_from_slice(&[process.integer(1), process.integer(2)]),
+        |_, process| process.cons(process.integer(1), process.integer(2)),
+        false,
+    );
+}
+
+#[test]
+fn with_nested_tuples_lists_and_maps_agrees_with_reference_order() {
+    with_process_arc(|arc_process| {
+        TestRunner::new(Config::with_source_file(file!()))
+            .run(&(nested(), nested()), |(left, right)| {
+                let left_term = left.to_term(&arc_process);
+                let right_term = right.to_term(&arc_process);
+
+                prop_assert_eq!(
+                    result(left_term, right_term),
+                    (left.cmp(&right) == Ordering::Less).into(),
+                    "{} < {}",
+                    left_term,
+                    right_term
+                );
+
+                Ok(())
+            })
+            .unwrap();
+    });
+}
+
+/// A term built only from small integers, tuples, maps with small integer keys, and proper lists,
+/// so that its order can be computed without `Term`'s `Ord`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Nested {
+    Integer(i8),
+    Tuple(Vec<Nested>),
+    Map(BTreeMap<i8, Nested>),
+    List(Vec<Nested>),
+}
+
+impl Nested {
+    /// number < tuple < map < list
+    fn rank(&self) -> u8 {
+        match self {
+            Nested::Integer(_) => 0,
+            Nested::Tuple(_) => 1,
+            Nested::Map(_) => 2,
+            Nested::List(_) => 3,
+        }
+    }
+
+    fn to_term(&self, process: &Process) -> Term {
+        match self {
+            Nested::Integer(integer) => process.integer(*integer as isize),
+            Nested::Tuple(elements) => {
+                let element_vec: Vec<Term> = elements
+                    .iter()
+                    .map(|element| element.to_term(process))
+                    .collect();
+
+                process.tuple_from_slice(&element_vec)
+            }
+            Nested::Map(entries) => {
+                let entry_vec: Vec<(Term, Term)> = entries
+                    .iter()
+                    .map(|(key, value)| (process.integer(*key as isize), value.to_term(process)))
+                    .collect();
+
+                process.map_from_slice(&entry_vec)
+            }
+            Nested::List(elements) => {
+                let element_vec: Vec<Term> = elements
+                    .iter()
+                    .map(|element| element.to_term(process))
+                    .collect();
+
+                process.list_from_slice(&element_vec)
+            }
+        }
+    }
+}
+
+impl Ord for Nested {
+    fn cmp(&self, other: &Nested) -> Ordering {
+        match (self, other) {
+            (Nested::Integer(left), Nested::Integer(right)) => left.cmp(right),
+            // Tuples are compared by size, then element by element
+            (Nested::Tuple(left), Nested::Tuple(right)) => left
+                .len()
+                .cmp(&right.len())
+                .then_with(|| left.iter().cmp(right.iter())),
+            // Maps are compared by size, then by keys in ascending order, then by values in key
+            // order
+            (Nested::Map(left), Nested::Map(right)) => left
+                .len()
+                .cmp(&right.len())
+                .then_with(|| left.keys().cmp(right.keys()))
+                .then_with(|| left.values().cmp(right.values())),
+            // Lists are compared element by element, so a prefix is less
+            (Nested::List(left), Nested::List(right)) => left.iter().cmp(right.iter()),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl PartialOrd for Nested {
+    fn partial_cmp(&self, other: &Nested) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn nested() -> BoxedStrategy<Nested> {
+    // A narrow integer range makes equal prefixes, and so deeper comparisons, likely
+    (-2_i8..=2)
+        .prop_map(Nested::Integer)
+        .prop_recursive(3, 24, 3, |element| {
+            prop_oneof![
+                vec(element.clone(), 0..=3).prop_map(Nested::Tuple),
+                btree_map(-2_i8..=2, element.clone(), 0..=3).prop_map(Nested::Map),
+                vec(element, 0..=3).prop_map(Nested::List),
+            ]
+        })
+        .boxed()
+}
+
+/// `{1, [2, Last]}`
+fn nested_tuple(process: &Process, last: isize) -> Term {
+    process.tuple_from_slice(&[
+        process.integer(1),
+        process.list_from_slice(&[process.integer(2), process.integer(last)]),
+    ])
+}
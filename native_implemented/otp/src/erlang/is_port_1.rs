@@ -0,0 +1,6 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(erlang:is_port/1)]
+pub fn result(term: Term) -> Term {
+    term.is_port().into()
+}
@@ -58,18 +58,24 @@ pub fn result(process: &Process, bitstring_list: Term) -> exception::Result<Term
 
                         stack.push(boxed_cons.head);
                     }
-                    TypedTerm::HeapBinary(heap_binary) => {
-                        if partial_byte_bit_count == 0 {
-                            byte_vec.extend_from_slice(heap_binary.as_bytes());
-                        } else {
-                            for byte in heap_binary.as_bytes() {
-                                partial_byte |= byte >> partial_byte_bit_count;
-                                byte_vec.push(partial_byte);
-
-                                partial_byte = byte << (8 - partial_byte_bit_count);
-                            }
-                        }
-                    }
+                    TypedTerm::HeapBinary(heap_binary) => extend_bytes(
+                        &mut byte_vec,
+                        &mut partial_byte,
+                        partial_byte_bit_count,
+                        heap_binary.as_bytes(),
+                    ),
+                    TypedTerm::ProcBin(process_binary) => extend_bytes(
+                        &mut byte_vec,
+                        &mut partial_byte,
+                        partial_byte_bit_count,
+                        process_binary.as_bytes(),
+                    ),
+                    TypedTerm::BinaryLiteral(binary_literal) => extend_bytes(
+                        &mut byte_vec,
+                        &mut partial_byte,
+                        partial_byte_bit_count,
+                        binary_literal.as_bytes(),
+                    ),
                     TypedTerm::SubBinary(subbinary) => {
                         if partial_byte_bit_count == 0 {
                             if subbinary.is_aligned() {
@@ -130,6 +136,26 @@ pub fn result(process: &Process, bitstring_list: Term) -> exception::Result<Term
     }
 }
 
+/// Appends `bytes` after the `partial_byte_bit_count` bits already in `partial_byte`.  As whole
+/// bytes are appended, the number of bits left in `partial_byte` does not change.
+fn extend_bytes(
+    byte_vec: &mut Vec<u8>,
+    partial_byte: &mut u8,
+    partial_byte_bit_count: u8,
+    bytes: &[u8],
+) {
+    if partial_byte_bit_count == 0 {
+        byte_vec.extend_from_slice(bytes);
+    } else {
+        for byte in bytes {
+            *partial_byte |= byte >> partial_byte_bit_count;
+            byte_vec.push(*partial_byte);
+
+            *partial_byte = byte << (8 - partial_byte_bit_count);
+        }
+    }
+}
+
 fn element_context(bitstring_list: Term, element: Term) -> String {
     format!(
         "bitstring_list ({}) element ({}) is not a byte, bitstring, or nested bitstring_list",
@@ -88,6 +88,26 @@ fn with_recursive_lists_of_bitstrings_and_bytes_ending_in_bitstring_or_empty_lis
     });
 }
 
+#[test]
+fn with_procbin_after_partial_byte_returns_bitstring() {
+    with_process(|process| {
+        let bytes = [0b1111_0000; 65];
+        let procbin = process.binary_from_bytes(&bytes);
+        // We expect this to be a procbin, since it's > 64 bytes. Make sure it is.
+        assert!(procbin.is_boxed_procbin());
+        let nibble = process.subbinary_from_original(process.binary_from_bytes(&[0]), 0, 0, 0, 4);
+        let list = process.list_from_slice(&[nibble, procbin]);
+
+        // Each byte is split across the partial byte: 0b0000_1111, ..., 0b0000:4
+        let mut expected_bytes = [0b0000_1111; 66];
+        expected_bytes[65] = 0;
+        let expected_original = process.binary_from_bytes(&expected_bytes);
+        let expected = process.subbinary_from_original(expected_original, 0, 0, 65, 4);
+
+        assert_eq!(result(process, list), Ok(expected));
+    });
+}
+
 fn byte(arc_process: Arc<Process>) -> BoxedStrategy<Term> {
     any::<u8>()
         .prop_map(move |byte| arc_process.integer(byte))
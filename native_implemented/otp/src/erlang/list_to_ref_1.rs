@@ -0,0 +1,71 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::str::FromStr;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::list_to_string::list_to_string;
+use crate::runtime::distribution::nodes::node;
+
+/// Parses the BEAM `#Ref<node.scheduler.high.low>` format produced by `ref_to_list/1`.
+#[native_implemented::function(erlang:list_to_ref/1)]
+pub fn result(process: &Process, string: Term) -> exception::Result<Term> {
+    let string_string = list_to_string(string)?;
+    let (node_id, scheduler_id, number) = parse(&string_string).with_context(|| {
+        format!(
+            "string ({}) is not formatted as #Ref<node.scheduler.high.low>",
+            string
+        )
+    })?;
+
+    if node_id == node::id() {
+        Ok(process.reference_from_scheduler(scheduler_id.into(), number))
+    } else {
+        Err(anyhow!(
+            "string ({}) is for node ({}), but only local references are supported",
+            string,
+            node_id
+        ))
+        .map_err(From::from)
+    }
+}
+
+// Private
+
+fn parse(string: &str) -> anyhow::Result<(usize, u32, ReferenceNumber)> {
+    let inner = string
+        .strip_prefix("#Ref<")
+        .context("first characters must be '#Ref<'")?
+        .strip_suffix('>')
+        .context("last character must be '>'")?;
+    let mut parts = inner.split('.');
+
+    let node_id = decimal(parts.next(), "node id")?;
+    let scheduler_id = decimal(parts.next(), "scheduler id")?;
+    let high: u32 = decimal(parts.next(), "number high bits")?;
+    let low: u32 = decimal(parts.next(), "number low bits")?;
+
+    match parts.next() {
+        Some(_) => Err(anyhow!("extra '.'-separated parts beyond number low bits")),
+        None => Ok((
+            node_id,
+            scheduler_id,
+            ((high as ReferenceNumber) << 32) | (low as ReferenceNumber),
+        )),
+    }
+}
+
+fn decimal<T: FromStr>(part: Option<&str>, name: &str) -> anyhow::Result<T> {
+    match part {
+        Some(digits) if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) => digits
+            .parse()
+            .map_err(|_| anyhow!("{} ({}) is out of range", name, digits)),
+        Some(other) => Err(anyhow!("{} ({}) must be a decimal integer", name, other)),
+        None => Err(anyhow!("{} is missing", name)),
+    }
+}
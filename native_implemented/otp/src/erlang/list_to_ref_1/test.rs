@@ -0,0 +1,42 @@
+use crate::erlang::list_to_ref_1::result;
+use crate::test::with_process;
+
+#[test]
+fn with_malformed_string_errors_badarg() {
+    with_process(|process| {
+        for malformed in &[
+            "",
+            "#Ref<",
+            "#Ref<0.1.2>",
+            "#Ref<0.1.2.3",
+            "Ref<0.1.2.3>",
+            "#Ref<0.1.2.3.4>",
+            "#Ref<0..2.3>",
+            "#Ref<0.a.2.3>",
+            "#Ref<0.1.2.-3>",
+            "#Ref<0.1.4294967296.3>",
+        ] {
+            let string = process.charlist_from_str(malformed);
+
+            assert_badarg!(
+                result(process, string),
+                format!(
+                    "string ({}) is not formatted as #Ref<node.scheduler.high.low>",
+                    string
+                )
+            );
+        }
+    });
+}
+
+#[test]
+fn with_well_formed_string_returns_reference() {
+    with_process(|process| {
+        let string = process.charlist_from_str("#Ref<0.1.2.3>");
+
+        assert_eq!(
+            result(process, string),
+            Ok(process.reference_from_scheduler(1.into(), (2 << 32) | 3))
+        );
+    });
+}
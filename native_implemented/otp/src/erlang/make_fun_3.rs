@@ -0,0 +1,40 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::ffi::c_void;
+use std::mem;
+use std::ptr::NonNull;
+
+use liblumen_alloc::erts::apply::find_symbol;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::ModuleFunctionArity;
+
+use crate::runtime::context::*;
+
+/// Equivalent to `fun Module:Function/Arity`.  The function does not need to be exported yet: like
+/// in BEAM, calling the fun is what fails with `undef` when it is not.
+#[native_implemented::function(erlang:make_fun/3)]
+pub fn result(
+    process: &Process,
+    module: Term,
+    function: Term,
+    arity: Term,
+) -> exception::Result<Term> {
+    let module_atom = term_try_into_atom("module", module)?;
+    let function_atom = term_try_into_atom("function", function)?;
+    let arity_u8 = term_try_into_arity(arity)?;
+
+    let module_function_arity = ModuleFunctionArity {
+        module: module_atom,
+        function: function_atom,
+        arity: arity_u8,
+    };
+    let option_native = find_symbol(&module_function_arity).map(|dynamic_callee| unsafe {
+        let ptr = mem::transmute::<_, *mut c_void>(dynamic_callee);
+        NonNull::new_unchecked(ptr)
+    });
+
+    Ok(process.export_closure(module_atom, function_atom, arity_u8, option_native))
+}
@@ -0,0 +1,105 @@
+use std::convert::TryInto;
+
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::ModuleFunctionArity;
+
+use crate::erlang::make_fun_3::result;
+use crate::test::strategy;
+
+#[test]
+fn without_atom_module_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_atom(arc_process.clone()),
+                strategy::term::atom(),
+                strategy::term::function::arity(arc_process.clone()),
+            )
+        },
+        |(arc_process, module, function, arity)| {
+            prop_assert_is_not_atom!(result(&arc_process, module, function, arity), module);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn without_atom_function_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::atom(),
+                strategy::term::is_not_atom(arc_process.clone()),
+                strategy::term::function::arity(arc_process.clone()),
+            )
+        },
+        |(arc_process, module, function, arity)| {
+            prop_assert_is_not_atom!(result(&arc_process, module, function, arity), function);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn without_arity_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::atom(),
+                strategy::term::atom(),
+                strategy::term::is_not_arity(arc_process.clone()),
+            )
+        },
+        |(arc_process, module, function, arity)| {
+            prop_assert_is_not_arity!(result(&arc_process, module, function, arity), arity);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_arity_returns_export_function() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::atom(),
+                strategy::atom(),
+                strategy::term::function::arity_u8(),
+            )
+        },
+        |(arc_process, module, function, arity)| {
+            let fun = result(
+                &arc_process,
+                module.encode().unwrap(),
+                function.encode().unwrap(),
+                arc_process.integer(arity),
+            )
+            .unwrap();
+            let boxed_closure: Boxed<Closure> = fun.try_into().unwrap();
+
+            prop_assert_eq!(
+                boxed_closure.module_function_arity(),
+                ModuleFunctionArity {
+                    module,
+                    function,
+                    arity
+                }
+            );
+
+            Ok(())
+        },
+    );
+}
+
+// `with_exported_function_can_be_applied` in integration tests
+// `without_exported_function_when_applied_exits_undef` in integration tests
@@ -2,6 +2,8 @@ mod with_big_integer_multiplier;
 mod with_float_multiplier;
 mod with_small_integer_multiplier;
 
+use num_bigint::BigInt;
+
 use proptest::prop_assert;
 use proptest::strategy::Just;
 
@@ -70,6 +70,27 @@ fn with_small_integer_multiplicand_with_overflow_returns_big_integer() {
     })
 }
 
+#[test]
+fn with_small_integer_multiplicand_with_isize_overflow_returns_big_integer() {
+    with_process(|process| {
+        // The product of the largest small integers overflows `isize` itself, not just the small
+        // integer range, so it can only be computed by falling back to `BigInt`
+        let multiplier = process.integer(SmallInteger::MAX_VALUE);
+        let multiplicand = process.integer(SmallInteger::MAX_VALUE);
+
+        assert!(multiplier.is_smallint());
+        assert!(multiplicand.is_smallint());
+
+        let big_int = BigInt::from(SmallInteger::MAX_VALUE);
+        let expected_product = process.integer(&big_int * &big_int);
+
+        assert_eq!(
+            result(process, multiplier, multiplicand),
+            Ok(expected_product)
+        );
+    })
+}
+
 #[test]
 fn with_big_integer_multiplicand_returns_big_integer() {
     run!(
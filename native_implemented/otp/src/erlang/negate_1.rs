@@ -9,14 +9,15 @@ use liblumen_alloc::erts::process::trace::Trace;
 use liblumen_alloc::erts::process::Process;
 use liblumen_alloc::erts::term::prelude::*;
 
+use crate::number;
+
 /// `-/1` prefix operator.
 #[native_implemented::function(erlang:-/1)]
 pub fn result(process: &Process, number: Term) -> exception::Result<Term> {
     match number.decode().unwrap() {
         TypedTerm::SmallInteger(small_integer) => {
             let number_isize: isize = small_integer.into();
-            let negated_isize = -number_isize;
-            let negated_number: Term = process.integer(negated_isize);
+            let negated_number = number::negate_isize(process, number_isize);
 
             Ok(negated_number)
         }
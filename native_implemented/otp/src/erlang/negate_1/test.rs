@@ -1,8 +1,12 @@
+use num_bigint::BigInt;
+
 use proptest::strategy::{Just, Strategy};
 use proptest::{prop_assert_eq, prop_oneof};
 
+use liblumen_alloc::erts::term::prelude::*;
+
 use crate::erlang::negate_1::result;
-use crate::test::strategy;
+use crate::test::{strategy, with_process};
 
 #[test]
 fn without_number_errors_badarith() {
@@ -44,6 +48,24 @@ fn with_integer_returns_integer_of_opposite_sign() {
     );
 }
 
+#[test]
+fn with_small_integer_min_returns_big_integer() {
+    with_process(|process| {
+        let number = process.integer(SmallInteger::MIN_VALUE);
+
+        assert!(number.is_smallint());
+
+        let negated = result(process, number).unwrap();
+
+        assert!(negated.is_boxed_bigint());
+        assert_eq!(
+            negated,
+            process.integer(-BigInt::from(SmallInteger::MIN_VALUE))
+        );
+        assert_eq!(result(process, negated), Ok(number));
+    });
+}
+
 #[test]
 fn with_float_returns_float_of_opposite_sign() {
     run!(
@@ -0,0 +1,28 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_is_not_type;
+use crate::runtime::distribution::nodes::node;
+
+/// Returns the name of the node where `arg`, a pid, port, or reference, originates.
+#[native_implemented::function(erlang:node/1)]
+pub fn result(arg: Term) -> exception::Result<Term> {
+    let node_atom = match arg.decode()? {
+        TypedTerm::Pid(_) | TypedTerm::Port(_) | TypedTerm::Reference(_) => node::atom(),
+        TypedTerm::ExternalPid(external_pid) => external_pid.arc_node().name(),
+        TypedTerm::ExternalPort(external_port) => external_port.node().name(),
+        TypedTerm::ExternalReference(external_reference) => external_reference.arc_node().name(),
+        _ => {
+            return Err(TypeError)
+                .context(term_is_not_type("arg", arg, "a pid, port, or reference"))
+                .map_err(From::from)
+        }
+    };
+
+    Ok(node_atom.encode()?)
+}
@@ -0,0 +1,35 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::node_1::result;
+use crate::runtime::scheduler::SchedulerDependentAlloc;
+use crate::test::with_process;
+
+#[test]
+fn without_pid_port_or_reference_errors_badarg() {
+    let arg = Atom::str_to_term("arg");
+
+    assert_badarg!(
+        result(arg),
+        format!("arg ({}) is not a pid, port, or reference", arg)
+    );
+}
+
+#[test]
+fn with_local_pid_returns_nonode_at_nohost() {
+    with_process(|process| {
+        assert_eq!(
+            result(process.pid_term()),
+            Ok(Atom::str_to_term("nonode@nohost"))
+        );
+    });
+}
+
+#[test]
+fn with_local_reference_returns_nonode_at_nohost() {
+    with_process(|process| {
+        assert_eq!(
+            result(process.next_reference()),
+            Ok(Atom::str_to_term("nonode@nohost"))
+        );
+    });
+}
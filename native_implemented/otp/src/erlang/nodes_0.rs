@@ -0,0 +1,8 @@
+use liblumen_alloc::erts::term::prelude::Term;
+
+/// Distribution is not supported at this time, so there are no other visible nodes.  Always
+/// returns `[]`.
+#[native_implemented::function(erlang:nodes/0)]
+pub fn result() -> Term {
+    Term::NIL
+}
@@ -0,0 +1,283 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::collections::hash_map::DefaultHasher;
+use std::convert::TryInto;
+use std::hash::{Hash, Hasher};
+
+use anyhow::*;
+use num_bigint::{BigInt, Sign};
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::closure::Definition;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::*;
+
+/// Returns the legacy hash of `term` in `1..=range`, where `range` is at most `2^32`.
+///
+/// This is the hash used before `erlang:phash2/2`, so unlike `phash2`, it is computed with
+/// wrapping 32-bit arithmetic by the same algorithm as C-BEAM's `make_hash`, so that hashes of
+/// integers, atoms, floats, binaries, lists, and tuples match those persisted by C-BEAM.
+#[native_implemented::function(erlang:phash/2)]
+pub fn result(process: &Process, term: Term, range: Term) -> exception::Result<Term> {
+    let range_u64: u64 = range
+        .try_into()
+        .ok()
+        .filter(|range_u64| (1..=(1 << 32)).contains(range_u64))
+        .with_context(|| term_is_not_type("range", range, "an integer in 1..2^32"))?;
+
+    Ok(process.integer(1 + (make_hash(term) as u64) % range_u64))
+}
+
+const FUNNY_NUMBER1: u32 = 268440163;
+const FUNNY_NUMBER2: u32 = 268439161;
+const FUNNY_NUMBER3: u32 = 268435459;
+const FUNNY_NUMBER4: u32 = 268436141;
+const FUNNY_NUMBER5: u32 = 268438633;
+const FUNNY_NUMBER6: u32 = 268437017;
+const FUNNY_NUMBER8: u32 = 268437511;
+const FUNNY_NUMBER9: u32 = 268439627;
+const FUNNY_NUMBER10: u32 = 268440479;
+const FUNNY_NUMBER11: u32 = 268440577;
+const FUNNY_NUMBER12: u32 = 268440581;
+const FUNNY_NUMBER13: u32 = 268440593;
+const FUNNY_NUMBER14: u32 = 268440611;
+
+fn make_hash(term: Term) -> u32 {
+    hash_term(0, term)
+}
+
+fn hash_term(hash: u32, term: Term) -> u32 {
+    match term.decode().unwrap() {
+        TypedTerm::Nil => hash.wrapping_mul(FUNNY_NUMBER3).wrapping_add(1),
+        TypedTerm::Atom(atom) => hash
+            .wrapping_mul(FUNNY_NUMBER1)
+            .wrapping_add(atom_hash(atom.name().as_bytes())),
+        TypedTerm::SmallInteger(small_integer) => {
+            let i: isize = small_integer.into();
+
+            hash_integer(hash, &BigInt::from(i))
+        }
+        TypedTerm::BigInteger(big_integer) => {
+            let big_int: &BigInt = big_integer.as_ref().into();
+
+            hash_integer(hash, big_int)
+        }
+        TypedTerm::Float(float) => {
+            let f: f64 = float.into();
+            // -0.0 hashes like 0.0
+            let bits = if f == 0.0 { 0 } else { f.to_bits() };
+
+            hash.wrapping_mul(FUNNY_NUMBER6)
+                .wrapping_add((bits as u32) ^ ((bits >> 32) as u32))
+        }
+        TypedTerm::HeapBinary(heap_binary) => hash_bytes(hash, heap_binary.as_bytes(), 0, 0),
+        TypedTerm::ProcBin(process_binary) => hash_bytes(hash, process_binary.as_bytes(), 0, 0),
+        TypedTerm::BinaryLiteral(binary_literal) => {
+            hash_bytes(hash, binary_literal.as_bytes(), 0, 0)
+        }
+        TypedTerm::SubBinary(subbinary) => hash_bits(
+            hash,
+            subbinary.full_byte_iter().collect(),
+            subbinary.partial_byte_bit_iter(),
+            subbinary.partial_byte_bit_len(),
+        ),
+        TypedTerm::MatchContext(match_context) => hash_bits(
+            hash,
+            match_context.full_byte_iter().collect(),
+            match_context.partial_byte_bit_iter(),
+            match_context.partial_byte_bit_len(),
+        ),
+        TypedTerm::List(cons) => {
+            let mut hash = hash;
+            let mut tail = Term::NIL;
+
+            for result in cons.into_iter() {
+                match result {
+                    Ok(element) => {
+                        hash = match element.decode().unwrap() {
+                            // Bytes, such as those in strings, hash differently than other
+                            // small integers
+                            TypedTerm::SmallInteger(small_integer) => {
+                                match TryInto::<u8>::try_into(small_integer) {
+                                    Ok(byte) => {
+                                        hash.wrapping_mul(FUNNY_NUMBER2).wrapping_add(byte as u32)
+                                    }
+                                    Err(_) => hash_term(hash, element),
+                                }
+                            }
+                            _ => hash_term(hash, element),
+                        };
+                    }
+                    Err(ImproperList {
+                        tail: improper_tail,
+                    }) => {
+                        tail = improper_tail;
+                    }
+                }
+            }
+
+            hash_term(hash, tail).wrapping_mul(FUNNY_NUMBER8)
+        }
+        TypedTerm::Tuple(tuple) => {
+            let hash = tuple
+                .iter()
+                .fold(hash, |hash, element| hash_term(hash, *element));
+
+            hash.wrapping_mul(FUNNY_NUMBER9)
+                .wrapping_add(tuple.len() as u32)
+        }
+        // Map entries are hashed independently and summed, so that the hash does not depend on
+        // iteration order.
+        TypedTerm::Map(map) => {
+            let entries_hash = map.iter().fold(0_u32, |entries_hash, (key, value)| {
+                entries_hash.wrapping_add(hash_term(hash_term(0, *key), *value))
+            });
+
+            hash.wrapping_mul(FUNNY_NUMBER13)
+                .wrapping_add(FUNNY_NUMBER14)
+                .wrapping_add(entries_hash)
+        }
+        TypedTerm::Closure(closure) => match closure.definition() {
+            Definition::Export { function } => hash
+                .wrapping_mul(FUNNY_NUMBER11)
+                .wrapping_add(closure.arity() as u32)
+                .wrapping_mul(FUNNY_NUMBER1)
+                .wrapping_add(atom_hash(closure.module().name().as_bytes()))
+                .wrapping_mul(FUNNY_NUMBER1)
+                .wrapping_add(atom_hash(function.name().as_bytes())),
+            Definition::Anonymous {
+                index, old_unique, ..
+            } => {
+                let hash = hash
+                    .wrapping_mul(FUNNY_NUMBER10)
+                    .wrapping_add(closure.env_len() as u32)
+                    .wrapping_mul(FUNNY_NUMBER1)
+                    .wrapping_add(atom_hash(closure.module().name().as_bytes()))
+                    .wrapping_mul(FUNNY_NUMBER2)
+                    .wrapping_add(*index as u32)
+                    .wrapping_mul(FUNNY_NUMBER2)
+                    .wrapping_add(*old_unique);
+
+                closure
+                    .env_iter()
+                    .fold(hash, |hash, element| hash_term(hash, *element))
+            }
+        },
+        TypedTerm::Pid(pid) => {
+            hash_u32(hash, pid.number() as u32, FUNNY_NUMBER5).wrapping_mul(FUNNY_NUMBER6)
+        }
+        TypedTerm::ExternalPid(external_pid) => {
+            hash_u32(hash, external_pid.number() as u32, FUNNY_NUMBER5).wrapping_mul(FUNNY_NUMBER6)
+        }
+        TypedTerm::Port(port) => {
+            hash_u32(hash, port.as_usize() as u32, FUNNY_NUMBER9).wrapping_mul(FUNNY_NUMBER10)
+        }
+        TypedTerm::Reference(reference) => {
+            hash_u32(hash, reference.number() as u32, FUNNY_NUMBER9).wrapping_mul(FUNNY_NUMBER10)
+        }
+        // There is no C-BEAM hash to match for these, as they either carry node state that isn't
+        // encoded the same way or have no external representation, so fall back to the term's
+        // `Hash` implementation, which agrees with its equality.
+        typed_term @ (TypedTerm::ExternalPort(_)
+        | TypedTerm::ExternalReference(_)
+        | TypedTerm::ResourceReference(_)) => {
+            hash_u32(hash, std_hash(&typed_term), FUNNY_NUMBER9).wrapping_mul(FUNNY_NUMBER10)
+        }
+    }
+}
+
+/// `hashpjw` of the atom's name, with 2-byte UTF-8 sequences for Latin-1 characters folded back
+/// to Latin-1, as C-BEAM did when atoms were Latin-1.
+fn atom_hash(bytes: &[u8]) -> u32 {
+    let mut hash: u32 = 0;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let mut byte = bytes[index];
+        index += 1;
+
+        if index < bytes.len() && (byte & 0xFE) == 0xC2 && (bytes[index] & 0xC0) == 0x80 {
+            byte = (byte << 6) | (bytes[index] & 0x3F);
+            index += 1;
+        }
+
+        hash = (hash << 4).wrapping_add(byte as u32);
+
+        let high_nibble = hash & 0xF000_0000;
+
+        if high_nibble != 0 {
+            hash ^= high_nibble >> 24;
+            hash ^= high_nibble;
+        }
+    }
+
+    hash
+}
+
+fn hash_bits(
+    hash: u32,
+    byte_vec: Vec<u8>,
+    partial_byte_bit_iter: Box<dyn BitIterator>,
+    partial_byte_bit_len: u8,
+) -> u32 {
+    let partial_byte = partial_byte_bit_iter.fold(0, |partial_byte, bit| (partial_byte << 1) | bit);
+
+    hash_bytes(hash, &byte_vec, partial_byte, partial_byte_bit_len)
+}
+
+fn hash_bytes(hash: u32, bytes: &[u8], partial_byte: u8, partial_byte_bit_len: u8) -> u32 {
+    let mut hash = bytes.iter().fold(hash, |hash, byte| {
+        hash.wrapping_mul(FUNNY_NUMBER1).wrapping_add(*byte as u32)
+    });
+
+    if partial_byte_bit_len > 0 {
+        hash = hash
+            .wrapping_mul(FUNNY_NUMBER1)
+            .wrapping_add(partial_byte as u32)
+            .wrapping_mul(FUNNY_NUMBER12)
+            .wrapping_add(partial_byte_bit_len as u32);
+    }
+
+    hash.wrapping_mul(FUNNY_NUMBER4)
+        .wrapping_add(bytes.len() as u32)
+}
+
+/// Integers are hashed by the bytes of their magnitude in 64-bit digits, except that the most
+/// significant digit only contributes 4 bytes if it fits in 32 bits, so small and big integers
+/// hash alike.
+fn hash_integer(hash: u32, big_int: &BigInt) -> u32 {
+    let (sign, mut byte_vec) = big_int.to_bytes_le();
+    let padded_len = ((byte_vec.len() + 7) / 8) * 8;
+    byte_vec.resize(padded_len, 0);
+
+    if byte_vec[padded_len - 4..].iter().all(|byte| *byte == 0) {
+        byte_vec.truncate(padded_len - 4);
+    }
+
+    let hash = byte_vec.iter().fold(hash, |hash, byte| {
+        hash.wrapping_mul(FUNNY_NUMBER2).wrapping_add(*byte as u32)
+    });
+
+    hash.wrapping_mul(if sign == Sign::Minus {
+        FUNNY_NUMBER4
+    } else {
+        FUNNY_NUMBER3
+    })
+}
+
+fn hash_u32(hash: u32, x: u32, prime: u32) -> u32 {
+    x.to_le_bytes().iter().fold(hash, |hash, byte| {
+        hash.wrapping_mul(prime).wrapping_add(*byte as u32)
+    })
+}
+
+fn std_hash(typed_term: &TypedTerm) -> u32 {
+    let mut hasher = DefaultHasher::new();
+    typed_term.hash(&mut hasher);
+    let hash = hasher.finish();
+
+    (hash as u32) ^ ((hash >> 32) as u32)
+}
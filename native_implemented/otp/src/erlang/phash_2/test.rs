@@ -0,0 +1,155 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::phash_2::result;
+use crate::test::with_process;
+
+const RANGE: u64 = 1 << 32;
+
+#[test]
+fn with_range_zero_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, Atom::str_to_term("a"), process.integer(0)),
+            "range (0) is not an integer in 1..2^32"
+        );
+    });
+}
+
+#[test]
+fn with_range_greater_than_2_to_the_32_errors_badarg() {
+    with_process(|process| {
+        let range = process.integer(RANGE + 1);
+
+        assert_badarg!(
+            result(process, Atom::str_to_term("a"), range),
+            format!("range ({}) is not an integer in 1..2^32", range)
+        );
+    });
+}
+
+#[test]
+fn matches_legacy_hash_of_c_beam() {
+    with_process(|process| {
+        let cases = [
+            (process.integer(0), 1_u64, 1_u64),
+            (Term::NIL, 2, 2),
+            (Atom::str_to_term("a"), 98, 98),
+            (process.integer(1), 2788898428, 28),
+            (process.integer(-1), 1680185270, 70),
+            (process.charlist_from_str("abc"), 3654580166, 66),
+            (process.binary_from_bytes(&[1, 2, 3]), 687692590, 90),
+            (
+                process.tuple_from_slice(&[Atom::str_to_term("a"), process.integer(1)]),
+                3187717805,
+                5,
+            ),
+            (process.float(1.5), 1073217537, 37),
+            (process.tuple_from_slice(&[]), 1, 1),
+        ];
+
+        for (term, full_range_hash, hundred_range_hash) in cases.iter() {
+            assert_eq!(
+                result(process, *term, process.integer(RANGE)),
+                Ok(process.integer(*full_range_hash)),
+                "phash({}, {})",
+                term,
+                RANGE
+            );
+            assert_eq!(
+                result(process, *term, process.integer(100)),
+                Ok(process.integer(*hundred_range_hash)),
+                "phash({}, 100)",
+                term
+            );
+        }
+    });
+}
+
+#[test]
+fn with_map_does_not_depend_on_entry_order() {
+    with_process(|process| {
+        let a = Atom::str_to_term("a");
+        let b = Atom::str_to_term("b");
+        let one = process.integer(1);
+        let two = process.integer(2);
+        let range = process.integer(RANGE);
+
+        let map = process.map_from_slice(&[(a, one), (b, two)]);
+        let reordered_map = process.map_from_slice(&[(b, two), (a, one)]);
+        let swapped_values_map = process.map_from_slice(&[(a, two), (b, one)]);
+
+        assert_eq!(
+            result(process, map, range),
+            result(process, reordered_map, range)
+        );
+        assert_ne!(
+            result(process, map, range),
+            result(process, swapped_values_map, range)
+        );
+        assert_ne!(
+            result(process, map, range),
+            result(process, process.map_from_slice(&[]), range)
+        );
+    });
+}
+
+#[test]
+fn with_export_closure_hashes_module_function_arity() {
+    with_process(|process| {
+        let erlang = Atom::from_str("erlang");
+        let range = process.integer(RANGE);
+
+        let self_0 = process.export_closure(erlang, Atom::from_str("self"), 0, None);
+        let same_self_0 = process.export_closure(erlang, Atom::from_str("self"), 0, None);
+        let node_0 = process.export_closure(erlang, Atom::from_str("node"), 0, None);
+        let node_1 = process.export_closure(erlang, Atom::from_str("node"), 1, None);
+
+        assert_eq!(
+            result(process, self_0, range),
+            result(process, same_self_0, range)
+        );
+        assert_ne!(
+            result(process, self_0, range),
+            result(process, node_0, range)
+        );
+        assert_ne!(
+            result(process, node_0, range),
+            result(process, node_1, range)
+        );
+    });
+}
+
+#[test]
+fn with_anonymous_closure_hashes_environment() {
+    with_process(|process| {
+        let range = process.integer(RANGE);
+        let closure_with_env = |env: &[Term]| {
+            process.anonymous_closure_with_env_from_slice(
+                Atom::from_str("module"),
+                1,
+                2,
+                [0; 16],
+                0,
+                None,
+                process.pid().into(),
+                env,
+            )
+        };
+
+        let one = process.integer(1);
+        let two = process.integer(2);
+
+        assert_eq!(
+            result(process, closure_with_env(&[one]), range),
+            result(process, closure_with_env(&[one]), range)
+        );
+        assert_ne!(
+            result(process, closure_with_env(&[one]), range),
+            result(process, closure_with_env(&[two]), range)
+        );
+        assert_ne!(
+            result(process, closure_with_env(&[]), range),
+            result(process, closure_with_env(&[one]), range)
+        );
+    });
+}
@@ -0,0 +1,32 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_is_not_type;
+use crate::runtime::distribution::nodes::node;
+
+/// Formats `pid` as `<node.number.serial>`, which `list_to_pid/1` parses back.
+#[native_implemented::function(erlang:pid_to_list/1)]
+pub fn result(process: &Process, pid: Term) -> exception::Result<Term> {
+    let string = match pid.decode()? {
+        TypedTerm::Pid(pid) => format!("<{}.{}.{}>", node::id(), pid.number(), pid.serial()),
+        TypedTerm::ExternalPid(external_pid) => format!(
+            "<{}.{}.{}>",
+            external_pid.arc_node().id(),
+            external_pid.number(),
+            external_pid.serial()
+        ),
+        _ => {
+            return Err(TypeError)
+                .context(term_is_not_type("pid", pid, "a pid"))
+                .map_err(From::from)
+        }
+    };
+
+    Ok(process.charlist_from_str(&string))
+}
@@ -0,0 +1,54 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::list_to_pid_1;
+use crate::erlang::pid_to_list_1::result;
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_pid_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_pid(arc_process.clone()),
+            )
+        },
+        |(arc_process, pid)| {
+            prop_assert_badarg!(
+                result(&arc_process, pid),
+                format!("pid ({}) is not a pid", pid)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_local_pid_round_trips_through_list_to_pid() {
+    run!(
+        |arc_process| (Just(arc_process.clone()), strategy::term::pid::local()),
+        |(arc_process, pid)| {
+            let string = result(&arc_process, pid).unwrap();
+
+            prop_assert_eq!(list_to_pid_1::result(&arc_process, string), Ok(pid));
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_self_returns_node_number_serial() {
+    with_process(|process| {
+        let pid: Pid = process.pid();
+
+        assert_eq!(
+            result(process, process.pid_term()),
+            Ok(process.charlist_from_str(&format!("<0.{}.{}>", pid.number(), pid.serial())))
+        );
+    });
+}
@@ -0,0 +1,26 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_is_not_type;
+
+/// Formats `port` as `#Port<node.number>`, matching BEAM.
+#[native_implemented::function(erlang:port_to_list/1)]
+pub fn result(process: &Process, port: Term) -> exception::Result<Term> {
+    let string = match port.decode()? {
+        TypedTerm::Port(port) => port.to_string(),
+        TypedTerm::ExternalPort(external_port) => external_port.to_string(),
+        _ => {
+            return Err(TypeError)
+                .context(term_is_not_type("port", port, "a port"))
+                .map_err(From::from)
+        }
+    };
+
+    Ok(process.charlist_from_str(&string))
+}
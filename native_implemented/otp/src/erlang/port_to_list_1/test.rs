@@ -0,0 +1,39 @@
+use proptest::strategy::{Just, Strategy};
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::port_to_list_1::result;
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_port_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone())
+                    .prop_filter("Term must not be a port", |term| !term.is_port()),
+            )
+        },
+        |(arc_process, port)| {
+            prop_assert_badarg!(
+                result(&arc_process, port),
+                format!("port ({}) is not a port", port)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_local_port_returns_port_form() {
+    with_process(|process| {
+        let port = unsafe { Port::from_raw(7) }.encode().unwrap();
+
+        assert_eq!(
+            result(process, port),
+            Ok(process.charlist_from_str("#Port<0.7>"))
+        );
+    });
+}
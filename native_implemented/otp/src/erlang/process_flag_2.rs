@@ -1,10 +1,12 @@
 #[cfg(all(not(target_arch = "wasm32"), test))]
 mod test;
 
+use std::convert::TryInto;
+
 use anyhow::*;
 
 use liblumen_alloc::erts::exception;
-use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::process::{Priority, Process};
 use liblumen_alloc::erts::term::prelude::*;
 
 use crate::runtime::context::*;
@@ -19,7 +21,11 @@ pub fn result(process: &Process, flag: Term, value: Term) -> exception::Result<T
         "message_queue_data" => unimplemented!(),
         "min_bin_vheap_size" => unimplemented!(),
         "min_heap_size" => unimplemented!(),
-        "priority" => unimplemented!(),
+        "priority" => {
+            let priority: Priority = value.try_into()?;
+
+            Ok(process.set_priority(priority).into())
+        }
         "save_calls" => unimplemented!(),
         "sensitive" => unimplemented!(),
         "trap_exit" => {
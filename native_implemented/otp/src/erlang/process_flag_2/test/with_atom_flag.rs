@@ -1,3 +1,4 @@
+mod with_priority_flag;
 mod with_trap_exit_flag;
 
 use super::*;
@@ -26,7 +27,7 @@ fn unsupported_flag_atom() -> BoxedStrategy<Term> {
             let atom_atom: Atom = (*atom).try_into().unwrap();
 
             match atom_atom.name() {
-                "trap_exit" => false,
+                "priority" | "trap_exit" => false,
                 _ => true,
             }
         })
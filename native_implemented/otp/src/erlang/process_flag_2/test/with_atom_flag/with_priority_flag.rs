@@ -0,0 +1,38 @@
+use super::*;
+
+use liblumen_alloc::erts::process::Priority;
+
+#[test]
+fn without_priority_value_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, flag(), Atom::str_to_term("urgent")),
+            "supported priorities are low, normal, high, or max"
+        );
+    });
+}
+
+#[test]
+fn with_priority_value_returns_old_priority() {
+    with_process(|process| {
+        assert_eq!(process.priority(), Priority::Normal);
+
+        assert_eq!(
+            result(process, flag(), Atom::str_to_term("high")),
+            Ok(Atom::str_to_term("normal"))
+        );
+        assert_eq!(process.priority(), Priority::High);
+
+        assert_eq!(
+            result(process, flag(), Atom::str_to_term("low")),
+            Ok(Atom::str_to_term("high"))
+        );
+        assert_eq!(process.priority(), Priority::Low);
+    });
+}
+
+// `with_high_priority_is_scheduled_before_normal_priority` in integration tests
+
+fn flag() -> Term {
+    Atom::str_to_term("priority")
+}
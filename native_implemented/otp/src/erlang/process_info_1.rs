@@ -0,0 +1,55 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::borrow::clone_to_process::CloneToProcess;
+use liblumen_alloc::erts::exception::{self, InternalResult};
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::process_info_2::process_info;
+use crate::runtime::registry::pid_to_process;
+
+/// Items in the order BEAM documents for `process_info/1`, limited to those `process_info/2`
+/// supports.  `registered_name` is only included when the process is registered.
+const ITEMS: &[&str] = &[
+    "registered_name",
+    "current_function",
+    "status",
+    "message_queue_len",
+    "links",
+    "trap_exit",
+    "group_leader",
+];
+
+#[native_implemented::function(erlang:process_info/1)]
+pub fn result(process: &Process, pid: Term) -> exception::Result<Term> {
+    let pid_pid = term_try_into_local_pid!(pid)?;
+
+    if process.pid() == pid_pid {
+        process_info_list(process, process)
+    } else {
+        match pid_to_process(&pid_pid) {
+            Some(pid_arc_process) => process_info_list(process, &pid_arc_process),
+            None => Ok(atom!("undefined")),
+        }
+    }
+    .map_err(From::from)
+}
+
+// Private
+
+fn process_info_list(process: &Process, pid_process: &Process) -> InternalResult<Term> {
+    let mut vec = Vec::with_capacity(ITEMS.len());
+
+    for name in ITEMS {
+        let item = process_info(pid_process, Atom::from_str(name))?;
+
+        // `registered_name` returns `[]` when the process is not registered
+        if item != Term::NIL {
+            vec.push(item.clone_to_process(process));
+        }
+    }
+
+    Ok(process.list_from_slice(&vec))
+}
@@ -0,0 +1,42 @@
+use std::convert::TryInto;
+
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::process_info_1::result;
+use crate::test::{strategy, with_process_arc};
+
+#[test]
+fn without_local_pid_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_local_pid(arc_process.clone()),
+            )
+        },
+        |(arc_process, pid)| {
+            prop_assert_is_not_local_pid!(result(&arc_process, pid), pid);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_self_contains_message_queue_len() {
+    with_process_arc(|arc_process| {
+        arc_process.send_from_self(Atom::str_to_term("first"));
+        arc_process.send_from_self(Atom::str_to_term("second"));
+
+        let info = result(&arc_process, arc_process.pid_term()).unwrap();
+        let info_cons: Boxed<Cons> = info.try_into().unwrap();
+        let expected = arc_process.tuple_from_slice(&[
+            Atom::str_to_term("message_queue_len"),
+            arc_process.integer(2),
+        ]);
+
+        assert!(info_cons.into_iter().any(|item| item.unwrap() == expected));
+    });
+}
@@ -7,7 +7,7 @@ use liblumen_alloc::atom;
 use liblumen_alloc::borrow::clone_to_process::CloneToProcess;
 use liblumen_alloc::erts::exception::{self, InternalResult};
 use liblumen_alloc::erts::message::{self, MessageData};
-use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::process::{Process, Status};
 use liblumen_alloc::erts::term::prelude::*;
 
 use crate::runtime::registry::pid_to_process;
@@ -30,25 +30,25 @@ pub fn result(process: &Process, pid: Term, item: Term) -> exception::Result<Ter
 
 // Private
 
-fn process_info(process: &Process, item: Atom) -> InternalResult<Term> {
+pub(crate) fn process_info(process: &Process, item: Atom) -> InternalResult<Term> {
     match item.name() {
         "backtrace" => unimplemented!(),
         "binary" => unimplemented!(),
         "catchlevel" => unimplemented!(),
-        "current_function" => unimplemented!(),
+        "current_function" => Ok(current_function(process)),
         "current_location" => unimplemented!(),
         "current_stacktrace" => unimplemented!(),
         "dictionary" => unimplemented!(),
         "error_handler" => unimplemented!(),
         "garbage_collection" => unimplemented!(),
         "garbage_collection_info" => unimplemented!(),
-        "group_leader" => unimplemented!(),
+        "group_leader" => Ok(group_leader(process)),
         "heap_size" => unimplemented!(),
         "initial_call" => unimplemented!(),
         "links" => Ok(links(process)),
         "last_calls" => unimplemented!(),
         "memory" => unimplemented!(),
-        "message_queue_len" => unimplemented!(),
+        "message_queue_len" => Ok(message_queue_len(process)),
         "messages" => Ok(messages(process)),
         "min_heap_size" => unimplemented!(),
         "min_bin_vheap_size" => unimplemented!(),
@@ -60,7 +60,7 @@ fn process_info(process: &Process, item: Atom) -> InternalResult<Term> {
         "registered_name" => Ok(registered_name(process)),
         "sequential_trace_token" => unimplemented!(),
         "stack_size" => unimplemented!(),
-        "status" => unimplemented!(),
+        "status" => Ok(status(process)),
         "suspending" => unimplemented!(),
         "total_heap_size" => unimplemented!(),
         "trace" => unimplemented!(),
@@ -80,6 +80,28 @@ fn process_info(process: &Process, item: Atom) -> InternalResult<Term> {
     }
 }
 
+fn current_function(process: &Process) -> Term {
+    let tag = atom!("current_function");
+
+    let module_function_arity = process
+        .current_module_function_arity()
+        .unwrap_or(process.initial_module_function_arity);
+    let value = process.tuple_from_slice(&[
+        module_function_arity.module.encode().unwrap(),
+        module_function_arity.function.encode().unwrap(),
+        process.integer(module_function_arity.arity),
+    ]);
+
+    process.tuple_from_slice(&[tag, value])
+}
+
+fn group_leader(process: &Process) -> Term {
+    let tag = atom!("group_leader");
+    let value = process.get_group_leader_pid_term();
+
+    process.tuple_from_slice(&[tag, value])
+}
+
 fn links(process: &Process) -> Term {
     let tag = atom!("links");
 
@@ -114,6 +136,13 @@ fn messages(process: &Process) -> Term {
     process.tuple_from_slice(&[tag, value])
 }
 
+fn message_queue_len(process: &Process) -> Term {
+    let tag = atom!("message_queue_len");
+    let value = process.integer(process.mailbox.lock().borrow().len());
+
+    process.tuple_from_slice(&[tag, value])
+}
+
 fn monitored_by(process: &Process) -> Term {
     let tag = atom!("monitored_by");
 
@@ -156,6 +185,18 @@ fn registered_name(process: &Process) -> Term {
     }
 }
 
+fn status(process: &Process) -> Term {
+    let tag = atom!("status");
+    let value = match *process.status.read() {
+        Status::Unrunnable | Status::Runnable => atom!("runnable"),
+        Status::Running => atom!("running"),
+        Status::Waiting => atom!("waiting"),
+        Status::Exited | Status::RuntimeException(_) => atom!("exiting"),
+    };
+
+    process.tuple_from_slice(&[tag, value])
+}
+
 fn trap_exit(process: &Process) -> Term {
     let tag = atom!("trap_exit");
     let value = process.traps_exit().into();
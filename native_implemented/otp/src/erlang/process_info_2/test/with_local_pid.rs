@@ -26,6 +26,21 @@ fn without_supported_item_errors_badarg() {
     });
 }
 
+#[test]
+fn with_message_queue_len_returns_number_of_messages() {
+    with_process_arc(|arc_process| {
+        let item = Atom::str_to_term("message_queue_len");
+
+        arc_process.send_from_self(Atom::str_to_term("first"));
+        arc_process.send_from_self(Atom::str_to_term("second"));
+
+        assert_eq!(
+            result(&arc_process, arc_process.pid_term(), item),
+            Ok(arc_process.tuple_from_slice(&[item, arc_process.integer(2)]))
+        );
+    });
+}
+
 fn unsupported_item_atom() -> BoxedStrategy<Term> {
     strategy::atom()
         .prop_filter("Item cannot be supported", |atom| match atom.name() {
@@ -0,0 +1,26 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_is_not_type;
+
+/// Formats `reference` as BEAM's `#Ref<node.scheduler.high.low>`, which `list_to_ref/1` parses
+/// back.
+#[native_implemented::function(erlang:ref_to_list/1)]
+pub fn result(process: &Process, reference: Term) -> exception::Result<Term> {
+    match reference.decode()? {
+        TypedTerm::Reference(reference) => Ok(process.charlist_from_str(&reference.to_string())),
+        _ => Err(TypeError)
+            .context(term_is_not_type(
+                "reference",
+                reference,
+                "a local reference",
+            ))
+            .map_err(From::from),
+    }
+}
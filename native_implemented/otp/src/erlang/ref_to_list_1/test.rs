@@ -0,0 +1,59 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::list_to_ref_1;
+use crate::erlang::ref_to_list_1::result;
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_reference_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_reference(arc_process.clone()),
+            )
+        },
+        |(arc_process, reference)| {
+            prop_assert_badarg!(
+                result(&arc_process, reference),
+                format!("reference ({}) is not a local reference", reference)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_local_reference_round_trips_through_list_to_ref() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::local_reference(arc_process.clone()),
+            )
+        },
+        |(arc_process, reference)| {
+            let string = result(&arc_process, reference).unwrap();
+
+            prop_assert_eq!(list_to_ref_1::result(&arc_process, string), Ok(reference));
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_local_reference_returns_beam_ref_form() {
+    with_process(|process| {
+        let reference = process.reference_from_scheduler(1.into(), (2 << 32) | 3);
+
+        assert_eq!(
+            result(process, reference),
+            Ok(process.charlist_from_str("#Ref<0.1.2.3>"))
+        );
+    });
+}
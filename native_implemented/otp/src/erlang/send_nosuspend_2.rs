@@ -0,0 +1,31 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::send::{self, send, Sent};
+
+#[native_implemented::function(erlang:send_nosuspend/2)]
+pub fn result(process: &Process, destination: Term, message: Term) -> exception::Result<Term> {
+    send_nosuspend(process, destination, message, Default::default())
+}
+
+/// Sends with `nosuspend` set, so that the caller gets `false` instead of being suspended.
+pub(in crate::erlang) fn send_nosuspend(
+    process: &Process,
+    destination: Term,
+    message: Term,
+    mut options: send::Options,
+) -> exception::Result<Term> {
+    options.suspend = false;
+
+    send(destination, message, options, process)
+        .map(|sent| match sent {
+            Sent::Sent => true,
+            Sent::ConnectRequired | Sent::SuspendRequired => false,
+        })
+        .map(From::from)
+        .map_err(From::from)
+}
@@ -0,0 +1,45 @@
+use proptest::strategy::Just;
+use proptest::{prop_assert, prop_assert_eq};
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::send_nosuspend_2::result;
+use crate::test::{has_process_message, strategy};
+
+#[test]
+fn with_same_process_adds_process_message_to_mailbox_and_returns_true() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+            )
+        },
+        |(arc_process, message)| {
+            let destination = arc_process.pid_term();
+
+            prop_assert_eq!(result(&arc_process, destination, message), Ok(true.into()));
+            prop_assert!(has_process_message(&arc_process, message));
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn without_process_returns_true() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::pid::local(),
+                strategy::term(arc_process.clone()),
+            )
+        },
+        |(arc_process, destination, message)| {
+            prop_assert_eq!(result(&arc_process, destination, message), Ok(true.into()));
+
+            Ok(())
+        },
+    );
+}
@@ -0,0 +1,23 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::send_nosuspend_2::send_nosuspend;
+use crate::runtime::send;
+
+#[native_implemented::function(erlang:send_nosuspend/3)]
+pub fn result(
+    process: &Process,
+    destination: Term,
+    message: Term,
+    options: Term,
+) -> exception::Result<Term> {
+    let send_options: send::Options = options.try_into()?;
+
+    send_nosuspend(process, destination, message, send_options)
+}
@@ -1,5 +1,16 @@
 use super::*;
 
+use std::convert::TryInto;
+use std::sync::Arc;
+use std::thread;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::registry::pid_to_process;
+use crate::runtime::scheduler::{self, Scheduled};
+use crate::test;
+
 // `with_empty_list_arguments` in integration tests
 // `with_non_empty_proper_list_arguments` in integration tests
 
@@ -24,3 +35,61 @@ fn without_atom_function_errors_badarg() {
         },
     );
 }
+
+#[test]
+fn with_many_short_lived_processes_idle_scheduler_steals_and_runs_some() {
+    const COUNT: usize = 100;
+
+    let parent_arc_process = test::process::init();
+    let victim = parent_arc_process.scheduler().unwrap();
+    let module = Atom::str_to_term("erlang");
+    let function = Atom::str_to_term("self");
+    let arc_processes: Vec<Arc<Process>> = (0..COUNT)
+        .map(|_| {
+            let pid_term =
+                spawn_3::result(&parent_arc_process, module, function, Term::NIL).unwrap();
+            let pid: Pid = pid_term.try_into().unwrap();
+
+            pid_to_process(&pid).unwrap()
+        })
+        .collect();
+
+    let thief_id = {
+        // Only the victim is a candidate, so that schedulers of other tests aren't stolen from
+        let candidates = vec![victim.clone()];
+
+        thread::spawn(move || {
+            let thief = scheduler::current();
+
+            // Bounded, so that the test can't hang if processes keep being stolen back and forth
+            for _ in 0..(2 * COUNT) {
+                if !thief.run_once() && thief.steal(&candidates).is_none() {
+                    break;
+                }
+            }
+
+            thief.id()
+        })
+        .join()
+        .unwrap()
+    };
+
+    let stolen: Vec<&Arc<Process>> = arc_processes
+        .iter()
+        .filter(|arc_process| arc_process.scheduler_id() == Some(thief_id))
+        .collect();
+
+    assert!(!stolen.is_empty());
+    // The thief ran what it stole before the victim ran anything
+    assert!(stolen.iter().all(|arc_process| arc_process.is_exiting()));
+
+    for _ in 0..(2 * COUNT) {
+        if !victim.run_once() {
+            break;
+        }
+    }
+
+    assert!(arc_processes
+        .iter()
+        .all(|arc_process| arc_process.is_exiting()));
+}
@@ -0,0 +1,212 @@
+use std::convert::TryInto;
+use std::sync::atomic::Ordering;
+
+use liblumen_alloc::erts::process::{Process, Status};
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::{spawn_3, spawn_opt_4};
+use crate::runtime::registry::pid_to_process;
+use crate::runtime::scheduler::Scheduled;
+use crate::test;
+
+#[test]
+fn with_reduction_budget_tight_loop_yields_to_other_processes() {
+    const REDUCTION_BUDGET: u16 = 10;
+
+    let parent_arc_process = test::process::init();
+    let scheduler = parent_arc_process.scheduler().unwrap();
+
+    let options = parent_arc_process.list_from_slice(&[parent_arc_process.tuple_from_slice(&[
+        Atom::str_to_term("reduction_budget"),
+        parent_arc_process.integer(REDUCTION_BUDGET),
+    ])]);
+    let spinning_pid_term = spawn_opt_4::result(
+        &parent_arc_process,
+        Atom::str_to_term("test"),
+        Atom::str_to_term("spin"),
+        Term::NIL,
+        options,
+    )
+    .unwrap();
+    let spinning_pid: Pid = spinning_pid_term.try_into().unwrap();
+    let spinning_arc_process = pid_to_process(&spinning_pid).unwrap();
+
+    assert_eq!(spinning_arc_process.reduction_budget(), REDUCTION_BUDGET);
+
+    let other_pid_term = spawn_3::result(
+        &parent_arc_process,
+        Atom::str_to_term("erlang"),
+        Atom::str_to_term("self"),
+        Term::NIL,
+    )
+    .unwrap();
+    let other_pid: Pid = other_pid_term.try_into().unwrap();
+    let other_arc_process = pid_to_process(&other_pid).unwrap();
+
+    // Without preemption, the first `run_once` would never return from the spinning process
+    for _ in 0..10 {
+        if other_arc_process.is_exiting() {
+            break;
+        }
+
+        scheduler.run_once();
+    }
+
+    assert!(other_arc_process.is_exiting());
+    assert!(!spinning_arc_process.is_exiting());
+    assert!(
+        (REDUCTION_BUDGET as u64) <= spinning_arc_process.total_reductions.load(Ordering::SeqCst)
+    );
+}
+
+#[test]
+fn with_zero_reduction_budget_errors_badarg() {
+    test::with_process(|process| {
+        let options = process
+            .list_from_slice(&[process
+                .tuple_from_slice(&[Atom::str_to_term("reduction_budget"), process.integer(0)])]);
+
+        assert_badarg!(
+            spawn_opt_4::result(
+                process,
+                Atom::str_to_term("test"),
+                Atom::str_to_term("spin"),
+                Term::NIL,
+                options,
+            ),
+            "reduction_budget (0) is not in 1..=65535"
+        );
+    });
+}
+
+#[test]
+fn with_max_heap_size_with_kill_exceeding_heap_kills_process() {
+    exceeding_max_heap_size_kills_process(|process| {
+        process.map_from_slice(&[
+            (Atom::str_to_term("size"), process.integer(MAX_HEAP_SIZE)),
+            (Atom::str_to_term("kill"), true.into()),
+        ])
+    });
+}
+
+#[test]
+fn with_max_heap_size_words_exceeding_heap_kills_process() {
+    // `kill` defaults to `true` when only the size is given
+    exceeding_max_heap_size_kills_process(|process| process.integer(MAX_HEAP_SIZE));
+}
+
+#[test]
+fn with_max_heap_size_zero_does_not_limit_heap() {
+    let parent_arc_process = test::process::init();
+    let scheduler = parent_arc_process.scheduler().unwrap();
+
+    let options = parent_arc_process.list_from_slice(&[parent_arc_process.tuple_from_slice(&[
+        Atom::str_to_term("max_heap_size"),
+        parent_arc_process.integer(0),
+    ])]);
+    let arguments = parent_arc_process.list_from_slice(&[
+        parent_arc_process.integer(10 * MAX_HEAP_SIZE),
+        Atom::str_to_term("element"),
+    ]);
+    let child_pid_term = spawn_opt_4::result(
+        &parent_arc_process,
+        Atom::str_to_term("erlang"),
+        Atom::str_to_term("make_tuple"),
+        arguments,
+        options,
+    )
+    .unwrap();
+    let child_pid: Pid = child_pid_term.try_into().unwrap();
+    let child_arc_process = pid_to_process(&child_pid).unwrap();
+
+    assert_eq!(child_arc_process.max_heap_size(), 0);
+
+    for _ in 0..10 {
+        if child_arc_process.is_exiting() {
+            break;
+        }
+
+        scheduler.run_once();
+    }
+
+    match *child_arc_process.status.read() {
+        Status::Exited => (),
+        Status::RuntimeException(ref exception) => {
+            assert_ne!(exception.reason(), Atom::str_to_term("killed"))
+        }
+        ref status => panic!("Child process did not exit and is {:?}", status),
+    };
+}
+
+#[test]
+fn with_min_heap_size_above_max_heap_size_spawns_with_min_heap_size() {
+    let parent_arc_process = test::process::init();
+
+    let options = parent_arc_process.list_from_slice(&[
+        parent_arc_process.tuple_from_slice(&[
+            Atom::str_to_term("min_heap_size"),
+            parent_arc_process.integer(10 * MAX_HEAP_SIZE),
+        ]),
+        parent_arc_process.tuple_from_slice(&[
+            Atom::str_to_term("max_heap_size"),
+            parent_arc_process.integer(MAX_HEAP_SIZE),
+        ]),
+    ]);
+    let child_pid_term = spawn_opt_4::result(
+        &parent_arc_process,
+        Atom::str_to_term("erlang"),
+        Atom::str_to_term("self"),
+        Term::NIL,
+        options,
+    )
+    .unwrap();
+    let child_pid: Pid = child_pid_term.try_into().unwrap();
+    let child_arc_process = pid_to_process(&child_pid).unwrap();
+
+    // `max_heap_size` is only enforced when the heap is garbage collected
+    assert_eq!(child_arc_process.max_heap_size(), MAX_HEAP_SIZE);
+    assert!(10 * MAX_HEAP_SIZE <= child_arc_process.total_heap_size());
+}
+
+const MAX_HEAP_SIZE: usize = 1_000;
+
+fn exceeding_max_heap_size_kills_process(max_heap_size: fn(&Process) -> Term) {
+    let parent_arc_process = test::process::init();
+    let scheduler = parent_arc_process.scheduler().unwrap();
+
+    let max_heap_size = max_heap_size(&parent_arc_process);
+    let options = parent_arc_process
+        .list_from_slice(&[parent_arc_process
+            .tuple_from_slice(&[Atom::str_to_term("max_heap_size"), max_heap_size])]);
+    let arguments = parent_arc_process.list_from_slice(&[
+        parent_arc_process.integer(10 * MAX_HEAP_SIZE),
+        Atom::str_to_term("element"),
+    ]);
+    let child_pid_term = spawn_opt_4::result(
+        &parent_arc_process,
+        Atom::str_to_term("erlang"),
+        Atom::str_to_term("make_tuple"),
+        arguments,
+        options,
+    )
+    .unwrap();
+    let child_pid: Pid = child_pid_term.try_into().unwrap();
+    let child_arc_process = pid_to_process(&child_pid).unwrap();
+
+    assert_eq!(child_arc_process.max_heap_size(), MAX_HEAP_SIZE);
+
+    for _ in 0..10 {
+        if child_arc_process.is_exiting() {
+            break;
+        }
+
+        scheduler.run_once();
+    }
+
+    match *child_arc_process.status.read() {
+        Status::RuntimeException(ref exception) => {
+            assert_eq!(exception.reason(), Atom::str_to_term("killed"))
+        }
+        ref status => panic!("Child process did not get killed and is {:?}", status),
+    };
+}
@@ -0,0 +1,196 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::{TryFrom, TryInto};
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception::{self, Alloc};
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply::arguments_term_to_vec;
+use crate::runtime::process::spawn::options::Options;
+use crate::runtime::scheduler::{Scheduled, SchedulerDependentAlloc};
+
+/// Spawns `module:function(arguments...)` asynchronously, returning a request reference.
+///
+/// Once the spawn operation completes, a `{spawn_reply, ReqId, ok, Pid}` or
+/// `{spawn_reply, ReqId, error, Reason}` message is sent to the calling process, unless
+/// suppressed by the `reply` option.  When `monitor` is given, `ReqId` is also the monitor
+/// reference, so `'DOWN'` messages for the child can be matched against it.
+#[native_implemented::function(erlang:spawn_request/4)]
+pub fn result(
+    process: &Process,
+    module: Term,
+    function: Term,
+    arguments: Term,
+    options: Term,
+) -> exception::Result<Term> {
+    let module_atom = term_try_into_atom!(module)?;
+    let function_atom = term_try_into_atom!(function)?;
+    let argument_vec = arguments_term_to_vec(arguments)?;
+    let RequestOptions {
+        reply,
+        reply_tag,
+        spawn_options,
+    } = RequestOptions::try_from(options)?;
+    let spawn_options: Options = process.list_from_slice(&spawn_options).try_into()?;
+
+    // The reply is sent before the child is scheduled, so that it always arrives before any
+    // `'DOWN'` message for the child.
+    let mut spawned_request_id = None;
+    let spawned_result = process
+        .scheduler()
+        .unwrap()
+        .spawn_module_function_arguments_before_schedule(
+            Some(process),
+            module_atom,
+            function_atom,
+            argument_vec,
+            spawn_options,
+            &mut |child, connection| {
+                let request_id = match connection.monitor_reference {
+                    Some(monitor_reference) => monitor_reference,
+                    None => process.next_reference(),
+                };
+
+                if reply == Reply::Yes || reply == Reply::SuccessOnly {
+                    send_reply(
+                        process,
+                        reply_tag,
+                        request_id,
+                        atom!("ok"),
+                        child.pid_term(),
+                    );
+                }
+
+                spawned_request_id = Some(request_id);
+            },
+        );
+
+    match spawned_result {
+        Ok(_) => Ok(spawned_request_id.unwrap()),
+        Err(error) if error.is::<Alloc>() => {
+            let request_id = process.next_reference();
+
+            if reply == Reply::Yes || reply == Reply::ErrorOnly {
+                send_reply(
+                    process,
+                    reply_tag,
+                    request_id,
+                    atom!("error"),
+                    atom!("system_limit"),
+                );
+            }
+
+            Ok(request_id)
+        }
+        Err(error) => Err(error.into()),
+    }
+}
+
+// Private
+
+const SUPPORTED_OPTIONS_CONTEXT: &str = "supported options are the spawn_opt options, \
+     {reply, yes | no | error_only | success_only}, and {reply_tag, term()}";
+
+fn send_reply(process: &Process, reply_tag: Term, request_id: Term, tag: Term, result: Term) {
+    let message = process.tuple_from_slice(&[reply_tag, request_id, tag, result]);
+    process.send_from_self(message);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Reply {
+    Yes,
+    No,
+    ErrorOnly,
+    SuccessOnly,
+}
+
+impl TryFrom<Term> for Reply {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> Result<Self, Self::Error> {
+        let atom: Atom = term.try_into().context("reply")?;
+
+        match atom.name() {
+            "yes" => Ok(Self::Yes),
+            "no" => Ok(Self::No),
+            "error_only" => Ok(Self::ErrorOnly),
+            "success_only" => Ok(Self::SuccessOnly),
+            name => Err(anyhow!("reply ({}) is not a supported value", name)),
+        }
+    }
+}
+
+/// The options only `spawn_request` understands, with the remaining options to be parsed as
+/// `spawn_opt` options.
+struct RequestOptions {
+    reply: Reply,
+    reply_tag: Term,
+    spawn_options: Vec<Term>,
+}
+
+impl RequestOptions {
+    fn put_option_term(&mut self, term: Term) -> Result<(), anyhow::Error> {
+        if let TypedTerm::Tuple(tuple) = term.decode().unwrap() {
+            if tuple.len() == 2 {
+                let key_result: Result<Atom, _> = tuple[0].try_into();
+
+                match key_result.as_ref().map(|key| key.name()) {
+                    Ok("reply") => {
+                        self.reply = tuple[1].try_into()?;
+
+                        return Ok(());
+                    }
+                    Ok("reply_tag") => {
+                        self.reply_tag = tuple[1];
+
+                        return Ok(());
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        self.spawn_options.push(term);
+
+        Ok(())
+    }
+}
+
+impl Default for RequestOptions {
+    fn default() -> Self {
+        Self {
+            reply: Reply::Yes,
+            reply_tag: atom!("spawn_reply"),
+            spawn_options: Vec::new(),
+        }
+    }
+}
+
+impl TryFrom<Term> for RequestOptions {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> Result<Self, Self::Error> {
+        let mut options: RequestOptions = Default::default();
+        let mut options_term = term;
+
+        loop {
+            match options_term.decode().unwrap() {
+                TypedTerm::Nil => return Ok(options),
+                TypedTerm::List(cons) => {
+                    options
+                        .put_option_term(cons.head)
+                        .context(SUPPORTED_OPTIONS_CONTEXT)?;
+                    options_term = cons.tail;
+
+                    continue;
+                }
+                _ => return Err(ImproperListError).context(SUPPORTED_OPTIONS_CONTEXT),
+            };
+        }
+    }
+}
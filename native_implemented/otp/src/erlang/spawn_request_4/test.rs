@@ -0,0 +1,71 @@
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::spawn_request_4::result;
+use crate::test::*;
+
+#[test]
+fn without_atom_module_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_atom(arc_process.clone()),
+            )
+        },
+        |(arc_process, module)| {
+            let function = atom!("spawn_request_function");
+            let arguments = Term::NIL;
+            let options = Term::NIL;
+
+            prop_assert_is_not_atom!(
+                result(&arc_process, module, function, arguments, options),
+                module
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn without_atom_function_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_atom(arc_process.clone()),
+            )
+        },
+        |(arc_process, function)| {
+            let module = atom!("spawn_request_module");
+            let arguments = Term::NIL;
+            let options = Term::NIL;
+
+            prop_assert_is_not_atom!(
+                result(&arc_process, module, function, arguments, options),
+                function
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_unsupported_reply_errors_badarg() {
+    with_process(|process| {
+        let module = atom!("spawn_request_module");
+        let function = atom!("spawn_request_function");
+        let arguments = Term::NIL;
+        let options =
+            process.list_from_slice(&[process.tuple_from_slice(&[atom!("reply"), atom!("maybe")])]);
+
+        assert_badarg!(
+            result(process, module, function, arguments, options),
+            "reply (maybe) is not a supported value"
+        );
+    });
+}
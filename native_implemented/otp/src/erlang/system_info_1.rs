@@ -1,10 +1,19 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
 use anyhow::*;
 
 use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
 use liblumen_alloc::erts::term::prelude::*;
 
+use crate::runtime::{registry, scheduler};
+
+/// The OTP release whose semantics the runtime follows
+const OTP_RELEASE: &str = "23";
+
 #[native_implemented::function(erlang:system_info/1)]
-pub fn result(item: Term) -> exception::Result<Term> {
+pub fn result(process: &Process, item: Term) -> exception::Result<Term> {
     match item.decode().unwrap() {
         TypedTerm::Atom(atom) => match atom.name() {
             "alloc_util_allocators" => unimplemented!(),
@@ -56,24 +65,24 @@ pub fn result(item: Term) -> exception::Result<Term> {
             "normal_multi_scheduling_blockers" => unimplemented!(),
             "os_monotonic_time_source" => unimplemented!(),
             "os_system_time_source" => unimplemented!(),
-            "otp_release" => unimplemented!(),
+            "otp_release" => Ok(process.charlist_from_str(OTP_RELEASE)),
             "port_count" => unimplemented!(),
             "port_limit" => unimplemented!(),
             "port_parallelism" => unimplemented!(),
-            "process_count" => unimplemented!(),
+            "process_count" => Ok(process.integer(registry::process_count())),
             "process_limit" => unimplemented!(),
             "procs" => unimplemented!(),
             "scheduler_bind_type" => unimplemented!(),
             "scheduler_bindings" => unimplemented!(),
             "scheduler_id" => unimplemented!(),
-            "schedulers" => unimplemented!(),
+            "schedulers" => Ok(process.integer(scheduler::count())),
             "schedulers_online" => unimplemented!(),
             "sequential_tracer" => unimplemented!(),
             "smp_support" => unimplemented!(),
             "start_time" => unimplemented!(),
             "system_architecture" => unimplemented!(),
             "system_logger" => unimplemented!(),
-            "system_version" => unimplemented!(),
+            "system_version" => Ok(process.charlist_from_str(&system_version())),
             "thread_pool_size" => unimplemented!(),
             "threads" => unimplemented!(),
             "time_correction" => unimplemented!(),
@@ -83,7 +92,7 @@ pub fn result(item: Term) -> exception::Result<Term> {
             "trace_control_word" => unimplemented!(),
             "update_cpu_info" => unimplemented!(),
             "version" => unimplemented!(),
-            "wordsize" => unimplemented!(),
+            "wordsize" => Ok(process.integer(WORDSIZE)),
             _ => Err(anyhow!(
                 "item ({}) is not a supported atom ({})",
                 item,
@@ -144,6 +153,21 @@ const SUPPORTED_ATOMS: &'static str = "`allocated_areas`, `allocator`, \
 const SUPPORTED_TUPLES: &'static str = "`{allocator, Alloc}`, `{allocator_sizes, Alloc}`, \
           `{cpu_topology, defined | detected | used}`, or `{wordsize, internal | external}`";
 
+/// The size of a word in bytes
+#[cfg(target_pointer_width = "32")]
+const WORDSIZE: usize = 4;
+#[cfg(target_pointer_width = "64")]
+const WORDSIZE: usize = 8;
+
+fn system_version() -> String {
+    format!(
+        "Lumen/OTP {} [erts-{}] [{}-bit]\n",
+        OTP_RELEASE,
+        env!("CARGO_PKG_VERSION"),
+        WORDSIZE * 8
+    )
+}
+
 fn item_is_not_supported_tuple(item: Term) -> exception::Result<Term> {
     Err(anyhow!(
         "item ({}) is not a supported tuple ({})",
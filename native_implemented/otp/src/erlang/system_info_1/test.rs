@@ -0,0 +1,44 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::system_info_1::result;
+use crate::test::with_process;
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn with_wordsize_returns_8() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, Atom::str_to_term("wordsize")),
+            Ok(process.integer(8))
+        );
+    });
+}
+
+#[test]
+fn with_process_count_includes_process() {
+    with_process(|process| {
+        let process_count = result(process, Atom::str_to_term("process_count")).unwrap();
+
+        assert!(process.integer(1) <= process_count);
+    });
+}
+
+#[test]
+fn with_otp_release_returns_charlist() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, Atom::str_to_term("otp_release")),
+            Ok(process.charlist_from_str("23"))
+        );
+    });
+}
+
+#[test]
+fn with_unsupported_atom_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, Atom::str_to_term("unsupported")),
+            "item (unsupported) is not a supported atom"
+        );
+    });
+}
@@ -5,8 +5,10 @@ use std::convert::TryInto;
 use std::mem;
 use std::sync::Arc;
 
+use anyhow::*;
 use num_bigint::{BigInt, Sign};
 
+use liblumen_alloc::erts::exception;
 use liblumen_alloc::erts::process::Process;
 use liblumen_alloc::erts::term::closure::{Creator, Definition};
 use liblumen_alloc::erts::term::prelude::*;
@@ -18,10 +20,20 @@ use crate::runtime::distribution::external_term_format::{version, Tag};
 
 use options::*;
 
-pub fn term_to_binary(process: &Process, term: Term, options: Options) -> Term {
-    let byte_vec = term_to_byte_vec(process, &options, term);
+pub fn term_to_binary(process: &Process, term: Term, options: Options) -> exception::Result<Term> {
+    let mut byte_vec: Vec<u8> = Vec::new();
+    append_term(&mut byte_vec, &options, term)?;
+
+    Ok(process.binary_from_bytes(&byte_vec))
+}
+
+/// The exact number of bytes `term_to_binary` would return for `term` with the default options.
+/// `term` goes through the same encoder as `term_to_binary`, but the bytes are only counted.
+pub fn external_size(term: Term) -> anyhow::Result<usize> {
+    let mut byte_count = ByteCount::default();
+    append_term(&mut byte_count, &Default::default(), term)?;
 
-    process.binary_from_bytes(&byte_vec)
+    Ok(byte_count.0)
 }
 
 // Private
@@ -46,8 +58,85 @@ const STRING_EXT_MAX_LEN: usize = std::u16::MAX as usize;
 const SMALL_BIG_EXT_MAX_LEN: usize = std::u8::MAX as usize;
 const SMALL_ATOM_UTF8_EXT_MAX_LEN: usize = std::u8::MAX as usize;
 
-fn append_big_int(byte_vec: &mut Vec<u8>, big_int: &BigInt) {
-    let (sign, mut little_endian_bytes) = big_int.to_bytes_le();
+/// Where the encoder writes.  `term_to_binary` keeps the bytes while `external_size` only counts
+/// them, so the size can never disagree with the encoding.
+trait Sink: Default {
+    fn append(&mut self, other: &mut Self);
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, bytes: I);
+    fn extend_from_slice(&mut self, bytes: &[u8]);
+    fn len(&self) -> usize;
+    fn push(&mut self, byte: u8);
+}
+
+impl Sink for Vec<u8> {
+    fn append(&mut self, other: &mut Self) {
+        Vec::append(self, other)
+    }
+
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, bytes: I) {
+        Extend::extend(self, bytes)
+    }
+
+    fn extend_from_slice(&mut self, bytes: &[u8]) {
+        Vec::extend_from_slice(self, bytes)
+    }
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn push(&mut self, byte: u8) {
+        Vec::push(self, byte)
+    }
+}
+
+#[derive(Default)]
+struct ByteCount(usize);
+
+impl Sink for ByteCount {
+    fn append(&mut self, other: &mut Self) {
+        self.0 += other.0;
+    }
+
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, bytes: I) {
+        self.0 += bytes.into_iter().count();
+    }
+
+    fn extend_from_slice(&mut self, bytes: &[u8]) {
+        self.0 += bytes.len();
+    }
+
+    fn len(&self) -> usize {
+        self.0
+    }
+
+    fn push(&mut self, _byte: u8) {
+        self.0 += 1;
+    }
+}
+
+fn append_atom<S: Sink>(sink: &mut S, atom: Atom) {
+    let bytes = atom.name().as_bytes();
+    let len_usize = bytes.len();
+
+    if bytes.iter().all(|byte| byte.is_ascii()) {
+        push_tag(sink, Tag::Atom);
+        append_usize_as_u16(sink, len_usize);
+    } else if len_usize <= SMALL_ATOM_UTF8_EXT_MAX_LEN {
+        push_tag(sink, Tag::SmallAtomUTF8);
+
+        let len_u8 = len_usize as u8;
+        sink.push(len_u8);
+    } else {
+        push_tag(sink, Tag::AtomUTF8);
+        append_usize_as_u16(sink, len_usize);
+    }
+
+    sink.extend_from_slice(bytes);
+}
+
+fn append_big_int<S: Sink>(sink: &mut S, big_int: &BigInt) {
+    let (sign, little_endian_bytes) = big_int.to_bytes_le();
 
     let sign_byte: u8 = match sign {
         Sign::Minus => 1,
@@ -57,31 +146,31 @@ fn append_big_int(byte_vec: &mut Vec<u8>, big_int: &BigInt) {
     let len_usize = little_endian_bytes.len();
 
     if len_usize <= SMALL_BIG_EXT_MAX_LEN {
-        push_tag(byte_vec, Tag::SmallBig);
-        byte_vec.push(len_usize as u8);
+        push_tag(sink, Tag::SmallBig);
+        sink.push(len_usize as u8);
     } else {
-        push_tag(byte_vec, Tag::LargeBig);
-        append_usize_as_u32(byte_vec, len_usize);
+        push_tag(sink, Tag::LargeBig);
+        append_usize_as_u32(sink, len_usize);
     }
 
-    byte_vec.push(sign_byte);
-    byte_vec.append(&mut little_endian_bytes);
+    sink.push(sign_byte);
+    sink.extend_from_slice(&little_endian_bytes);
 }
 
-fn append_binary_bytes(byte_vec: &mut Vec<u8>, binary_bytes: &[u8]) {
-    byte_vec.extend_from_slice(binary_bytes)
+fn append_binary_bytes<S: Sink>(sink: &mut S, binary_bytes: &[u8]) {
+    sink.extend_from_slice(binary_bytes)
 }
 
-fn append_creator(byte_vec: &mut Vec<u8>, creator: &Creator) {
+fn append_creator<S: Sink>(sink: &mut S, creator: &Creator) {
     match creator {
         Creator::Local(pid) => append_pid(
-            byte_vec,
+            sink,
             node::arc_node(),
             pid.number() as u32,
             pid.serial() as u32,
         ),
         Creator::External(external_pid) => append_pid(
-            byte_vec,
+            sink,
             external_pid.arc_node(),
             external_pid.number() as u32,
             external_pid.serial() as u32,
@@ -89,7 +178,7 @@ fn append_creator(byte_vec: &mut Vec<u8>, creator: &Creator) {
     }
 }
 
-fn append_pid(byte_vec: &mut Vec<u8>, arc_node: Arc<Node>, id: u32, serial: u32) {
+fn append_pid<S: Sink>(sink: &mut S, arc_node: Arc<Node>, id: u32, serial: u32) {
     let creation = arc_node.creation();
 
     let tag = if creation <= (std::u8::MAX as u32) {
@@ -98,98 +187,40 @@ fn append_pid(byte_vec: &mut Vec<u8>, arc_node: Arc<Node>, id: u32, serial: u32)
         Tag::NewPID
     };
 
-    push_tag(byte_vec, tag);
+    push_tag(sink, tag);
 
-    byte_vec.extend_from_slice(&atom_to_byte_vec(arc_node.name()));
-    byte_vec.extend_from_slice(&id.to_be_bytes());
-    byte_vec.extend_from_slice(&serial.to_be_bytes());
+    append_atom(sink, arc_node.name());
+    sink.extend_from_slice(&id.to_be_bytes());
+    sink.extend_from_slice(&serial.to_be_bytes());
 
     if creation <= (std::u8::MAX as u32) {
-        byte_vec.push(creation as u8);
+        sink.push(creation as u8);
     } else {
-        byte_vec.extend_from_slice(&creation.to_be_bytes());
+        sink.extend_from_slice(&creation.to_be_bytes());
     };
 }
 
-fn append_usize_as_u16(byte_vec: &mut Vec<u8>, len_usize: usize) {
-    assert!(len_usize <= (std::u16::MAX as usize));
-    let len_u16 = len_usize as u16;
-    byte_vec.extend_from_slice(&len_u16.to_be_bytes());
-}
-
-fn append_usize_as_u32(byte_vec: &mut Vec<u8>, len_usize: usize) {
-    assert!(len_usize <= (std::u32::MAX as usize));
-    let len_u32 = len_usize as u32;
-    byte_vec.extend_from_slice(&len_u32.to_be_bytes());
-}
-
-fn atom_to_byte_vec(atom: Atom) -> Vec<u8> {
-    let bytes = atom.name().as_bytes();
-    let len_usize = bytes.len();
-    let mut byte_vec: Vec<u8> = Vec::new();
-
-    if bytes.iter().all(|byte| byte.is_ascii()) {
-        push_tag(&mut byte_vec, Tag::Atom);
-        append_usize_as_u16(&mut byte_vec, len_usize);
-    } else if len_usize <= SMALL_ATOM_UTF8_EXT_MAX_LEN {
-        push_tag(&mut byte_vec, Tag::SmallAtomUTF8);
-
-        let len_u8 = len_usize as u8;
-        byte_vec.push(len_u8);
-    } else {
-        push_tag(&mut byte_vec, Tag::AtomUTF8);
-        append_usize_as_u16(&mut byte_vec, len_usize);
-    }
-
-    byte_vec.extend_from_slice(bytes);
-
-    byte_vec
-}
-
-// Tail is the final tail  of the list; it is NIL_EXT for a proper list, but can be any type if the
-// list is improper (for example, [a|b]).
-// -- http://erlang.org/doc/apps/erts/erl_ext_dist.html#list_ext
-fn cons_to_element_vec_tail(cons: &Cons) -> (Vec<Term>, Term) {
-    let mut element_vec: Vec<Term> = Vec::new();
-    let mut tail = Term::NIL;
-
-    for result in cons.into_iter() {
-        match result {
-            Ok(element) => element_vec.push(element),
-            Err(ImproperList {
-                tail: improper_list_tail,
-            }) => tail = improper_list_tail,
-        }
-    }
-
-    (element_vec, tail)
-}
-
-fn push_tag(byte_vec: &mut Vec<u8>, tag: Tag) {
-    byte_vec.push(tag.into());
-}
-
-fn term_to_byte_vec(process: &Process, options: &Options, term: Term) -> Vec<u8> {
+fn append_term<S: Sink>(sink: &mut S, options: &Options, term: Term) -> anyhow::Result<()> {
     let mut stack = VecDeque::new();
     stack.push_front(term);
 
-    let mut byte_vec: Vec<u8> = vec![version::NUMBER];
+    sink.push(version::NUMBER);
 
     while let Some(front_term) = stack.pop_front() {
         match front_term.decode().unwrap() {
             TypedTerm::Atom(atom) => {
-                byte_vec.extend_from_slice(&atom_to_byte_vec(atom));
+                append_atom(sink, atom);
             }
             TypedTerm::List(cons) => {
                 match try_cons_to_string_ext_byte_vec(&cons) {
-                    Ok(mut string_ext_byte_vec) => byte_vec.append(&mut string_ext_byte_vec),
+                    Ok(string_ext_byte_vec) => sink.extend_from_slice(&string_ext_byte_vec),
                     Err(_) => {
-                        push_tag(&mut byte_vec, Tag::List);
+                        push_tag(sink, Tag::List);
 
                         let (element_vec, tail) = cons_to_element_vec_tail(&cons);
 
                         let len_usize = element_vec.len();
-                        append_usize_as_u32(&mut byte_vec, len_usize);
+                        append_usize_as_u32(sink, len_usize);
 
                         stack.push_front(tail);
 
@@ -200,23 +231,15 @@ fn term_to_byte_vec(process: &Process, options: &Options, term: Term) -> Vec<u8>
                 };
             }
             TypedTerm::Nil => {
-                push_tag(&mut byte_vec, Tag::Nil);
+                push_tag(sink, Tag::Nil);
             }
             TypedTerm::Pid(pid) => {
-                append_pid(
-                    &mut byte_vec,
-                    arc_node(),
-                    pid.number() as u32,
-                    pid.serial() as u32,
-                );
+                append_pid(sink, arc_node(), pid.number() as u32, pid.serial() as u32);
             }
             TypedTerm::SmallInteger(small_integer) => {
                 let small_integer_isize: isize = small_integer.into();
 
-                match try_append_isize_as_small_integer_or_integer(
-                    &mut byte_vec,
-                    small_integer_isize,
-                ) {
+                match try_append_isize_as_small_integer_or_integer(sink, small_integer_isize) {
                     Ok(()) => (),
                     Err(_) => {
                         let small_integer_i64 = small_integer_isize as i64;
@@ -224,29 +247,29 @@ fn term_to_byte_vec(process: &Process, options: &Options, term: Term) -> Vec<u8>
                         // jumping to 8 to hold i64.
                         let small_integer_big_int: BigInt = small_integer_i64.into();
 
-                        append_big_int(&mut byte_vec, &small_integer_big_int);
+                        append_big_int(sink, &small_integer_big_int);
                     }
                 }
             }
             TypedTerm::BigInteger(big_integer) => {
                 let big_int: &BigInt = big_integer.as_ref().into();
 
-                append_big_int(&mut byte_vec, big_int);
+                append_big_int(sink, big_int);
             }
             TypedTerm::Float(float) => {
                 let float_f64: f64 = float.into();
 
-                push_tag(&mut byte_vec, Tag::NewFloat);
-                byte_vec.extend_from_slice(&float_f64.to_be_bytes());
+                push_tag(sink, Tag::NewFloat);
+                sink.extend_from_slice(&float_f64.to_be_bytes());
             }
             TypedTerm::Closure(closure) => {
                 match closure.definition() {
                     Definition::Export { function } => {
-                        push_tag(&mut byte_vec, Tag::Export);
-                        byte_vec.append(&mut atom_to_byte_vec(closure.module()));
-                        byte_vec.append(&mut atom_to_byte_vec(*function));
+                        push_tag(sink, Tag::Export);
+                        append_atom(sink, closure.module());
+                        append_atom(sink, *function);
                         try_append_isize_as_small_integer_or_integer(
-                            &mut byte_vec,
+                            sink,
                             closure.arity() as isize,
                         )
                         .unwrap();
@@ -258,22 +281,22 @@ fn term_to_byte_vec(process: &Process, options: &Options, term: Term) -> Vec<u8>
                         //creator,
                     } => {
                         let default_creator = Creator::Local(Pid::default());
-                        let mut sized_byte_vec: Vec<u8> = Vec::new();
+                        let mut sized_sink = S::default();
 
                         let module_function_arity = closure.module_function_arity();
-                        sized_byte_vec.push(module_function_arity.arity);
+                        sized_sink.push(module_function_arity.arity);
 
-                        sized_byte_vec.extend_from_slice(unique);
-                        sized_byte_vec.extend_from_slice(&index.to_be_bytes());
+                        sized_sink.extend_from_slice(unique);
+                        sized_sink.extend_from_slice(&index.to_be_bytes());
 
                         let env_len_u32: u32 = closure.env_len().try_into().unwrap();
-                        sized_byte_vec.extend_from_slice(&env_len_u32.to_be_bytes());
+                        sized_sink.extend_from_slice(&env_len_u32.to_be_bytes());
 
-                        sized_byte_vec.append(&mut atom_to_byte_vec(module_function_arity.module));
+                        append_atom(&mut sized_sink, module_function_arity.module);
 
                         // > [index] encoded using SMALL_INTEGER_EXT or INTEGER_EXT.
                         try_append_isize_as_small_integer_or_integer(
-                            &mut sized_byte_vec,
+                            &mut sized_sink,
                             (*index).try_into().unwrap(),
                         )
                         .unwrap();
@@ -282,39 +305,39 @@ fn term_to_byte_vec(process: &Process, options: &Options, term: Term) -> Vec<u8>
                         // But this means OldUniq can't be the same a Uniq with a different
                         // encoding,
                         try_append_isize_as_small_integer_or_integer(
-                            &mut sized_byte_vec,
+                            &mut sized_sink,
                             (*old_unique).try_into().unwrap(),
                         )
                         .unwrap();
 
-                        append_creator(&mut sized_byte_vec, &default_creator);
+                        append_creator(&mut sized_sink, &default_creator);
 
                         for term in closure.env_slice() {
-                            sized_byte_vec.append(&mut term_to_byte_vec(process, options, *term));
+                            append_term(&mut sized_sink, options, *term)?;
                         }
 
                         const SIZE_BYTE_LEN: usize = mem::size_of::<u32>();
-                        let size = (SIZE_BYTE_LEN + sized_byte_vec.len()) as u32;
+                        let size = (SIZE_BYTE_LEN + sized_sink.len()) as u32;
 
-                        push_tag(&mut byte_vec, Tag::NewFunction);
-                        byte_vec.extend_from_slice(&size.to_be_bytes());
-                        byte_vec.append(&mut sized_byte_vec);
+                        push_tag(sink, Tag::NewFunction);
+                        sink.extend_from_slice(&size.to_be_bytes());
+                        sink.append(&mut sized_sink);
                     }
                 }
             }
             TypedTerm::ExternalPid(external_pid) => {
                 append_pid(
-                    &mut byte_vec,
+                    sink,
                     external_pid.arc_node(),
                     external_pid.number() as u32,
                     external_pid.serial() as u32,
                 );
             }
             TypedTerm::Map(map) => {
-                push_tag(&mut byte_vec, Tag::Map);
+                push_tag(sink, Tag::Map);
 
                 let len_usize = map.len();
-                append_usize_as_u32(&mut byte_vec, len_usize);
+                append_usize_as_u32(sink, len_usize);
 
                 for (key, value) in map.iter() {
                     stack.push_front(*value);
@@ -322,79 +345,84 @@ fn term_to_byte_vec(process: &Process, options: &Options, term: Term) -> Vec<u8>
                 }
             }
             TypedTerm::HeapBinary(heap_bin) => {
-                push_tag(&mut byte_vec, Tag::Binary);
+                push_tag(sink, Tag::Binary);
 
                 let len_usize = heap_bin.full_byte_len();
-                append_usize_as_u32(&mut byte_vec, len_usize);
+                append_usize_as_u32(sink, len_usize);
 
-                byte_vec.extend_from_slice(heap_bin.as_bytes());
+                sink.extend_from_slice(heap_bin.as_bytes());
             }
             TypedTerm::MatchContext(match_context) => {
-                if match_context.is_binary() {
-                    if match_context.is_aligned() {
-                        append_binary_bytes(&mut byte_vec, unsafe {
-                            match_context.as_bytes_unchecked()
-                        });
-                    } else {
-                        unimplemented!()
-                    }
+                if match_context.is_binary() && match_context.is_aligned() {
+                    append_binary_bytes(sink, unsafe { match_context.as_bytes_unchecked() });
                 } else {
-                    unimplemented!()
+                    return Err(anyhow!(
+                        "match context ({}) is not an aligned binary",
+                        front_term
+                    ));
                 }
             }
             TypedTerm::ProcBin(proc_bin) => {
-                push_tag(&mut byte_vec, Tag::Binary);
+                push_tag(sink, Tag::Binary);
 
                 let len_usize = proc_bin.full_byte_len();
-                append_usize_as_u32(&mut byte_vec, len_usize);
+                append_usize_as_u32(sink, len_usize);
+
+                sink.extend_from_slice(proc_bin.as_bytes());
+            }
+            TypedTerm::BinaryLiteral(binary_literal) => {
+                push_tag(sink, Tag::Binary);
+
+                let len_usize = binary_literal.full_byte_len();
+                append_usize_as_u32(sink, len_usize);
 
-                byte_vec.extend_from_slice(proc_bin.as_bytes());
+                sink.extend_from_slice(binary_literal.as_bytes());
             }
             TypedTerm::Reference(reference) => {
                 let scheduler_id_u32: u32 = reference.scheduler_id().into();
                 let number: u64 = reference.number().into();
 
-                push_tag(&mut byte_vec, Tag::NewerReference);
+                push_tag(sink, Tag::NewerReference);
 
                 let u32_byte_len = mem::size_of::<u32>();
                 let len_usize = (mem::size_of::<u32>() + mem::size_of::<u64>()) / u32_byte_len;
                 // > Len - A 16-bit big endian unsigned integer not larger than 3.
                 assert!(len_usize <= NEWER_REFERENCE_EXT_MAX_U32_LEN);
-                append_usize_as_u16(&mut byte_vec, len_usize);
+                append_usize_as_u16(sink, len_usize);
 
-                byte_vec.extend_from_slice(&atom_to_byte_vec(node::atom()));
+                append_atom(sink, node::atom());
 
                 let creation_u32 = CREATION as u32;
-                byte_vec.extend_from_slice(&creation_u32.to_be_bytes());
+                sink.extend_from_slice(&creation_u32.to_be_bytes());
 
-                byte_vec.extend_from_slice(&scheduler_id_u32.to_be_bytes());
-                byte_vec.extend_from_slice(&number.to_be_bytes());
+                sink.extend_from_slice(&scheduler_id_u32.to_be_bytes());
+                sink.extend_from_slice(&number.to_be_bytes());
             }
             TypedTerm::SubBinary(subbinary) => {
                 if subbinary.is_binary() {
-                    push_tag(&mut byte_vec, Tag::Binary);
+                    push_tag(sink, Tag::Binary);
 
                     let len_usize = subbinary.full_byte_len();
-                    append_usize_as_u32(&mut byte_vec, len_usize);
+                    append_usize_as_u32(sink, len_usize);
 
                     if subbinary.is_aligned() {
-                        byte_vec.extend_from_slice(unsafe { subbinary.as_bytes_unchecked() });
+                        sink.extend_from_slice(unsafe { subbinary.as_bytes_unchecked() });
                     } else {
-                        byte_vec.extend(subbinary.full_byte_iter());
+                        sink.extend(subbinary.full_byte_iter());
                     }
                 } else {
-                    push_tag(&mut byte_vec, Tag::BitBinary);
+                    push_tag(sink, Tag::BitBinary);
 
                     let len_usize = subbinary.total_byte_len();
-                    append_usize_as_u32(&mut byte_vec, len_usize);
+                    append_usize_as_u32(sink, len_usize);
 
                     let bits_u8 = subbinary.partial_byte_bit_len();
-                    byte_vec.push(bits_u8);
+                    sink.push(bits_u8);
 
                     if subbinary.is_aligned() {
-                        byte_vec.extend_from_slice(unsafe { subbinary.as_bytes_unchecked() });
+                        sink.extend_from_slice(unsafe { subbinary.as_bytes_unchecked() });
                     } else {
-                        byte_vec.extend(subbinary.full_byte_iter());
+                        sink.extend(subbinary.full_byte_iter());
                     }
 
                     let mut last_byte: u8 = 0;
@@ -403,47 +431,87 @@ fn term_to_byte_vec(process: &Process, options: &Options, term: Term) -> Vec<u8>
                         last_byte |= bit << (7 - index);
                     }
 
-                    byte_vec.push(last_byte);
+                    sink.push(last_byte);
                 }
             }
             TypedTerm::Tuple(tuple) => {
                 let len_usize = tuple.len();
 
                 if len_usize <= SMALL_TUPLE_EXT_MAX_LEN {
-                    push_tag(&mut byte_vec, Tag::SmallTuple);
-                    byte_vec.push(len_usize as u8);
+                    push_tag(sink, Tag::SmallTuple);
+                    sink.push(len_usize as u8);
                 } else {
-                    push_tag(&mut byte_vec, Tag::LargeTuple);
-                    append_usize_as_u32(&mut byte_vec, len_usize);
+                    push_tag(sink, Tag::LargeTuple);
+                    append_usize_as_u32(sink, len_usize);
                 }
 
                 for element in tuple.iter().rev() {
                     stack.push_front(*element);
                 }
             }
-            _ => unimplemented!("term_to_binary({:?})", front_term),
+            _ => {
+                return Err(anyhow!(
+                    "term ({}) cannot be encoded in the external term format",
+                    front_term
+                ))
+            }
         };
     }
 
-    byte_vec
+    Ok(())
+}
+
+fn append_usize_as_u16<S: Sink>(sink: &mut S, len_usize: usize) {
+    assert!(len_usize <= (std::u16::MAX as usize));
+    let len_u16 = len_usize as u16;
+    sink.extend_from_slice(&len_u16.to_be_bytes());
+}
+
+fn append_usize_as_u32<S: Sink>(sink: &mut S, len_usize: usize) {
+    assert!(len_usize <= (std::u32::MAX as usize));
+    let len_u32 = len_usize as u32;
+    sink.extend_from_slice(&len_u32.to_be_bytes());
+}
+
+// Tail is the final tail  of the list; it is NIL_EXT for a proper list, but can be any type if the
+// list is improper (for example, [a|b]).
+// -- http://erlang.org/doc/apps/erts/erl_ext_dist.html#list_ext
+fn cons_to_element_vec_tail(cons: &Cons) -> (Vec<Term>, Term) {
+    let mut element_vec: Vec<Term> = Vec::new();
+    let mut tail = Term::NIL;
+
+    for result in cons.into_iter() {
+        match result {
+            Ok(element) => element_vec.push(element),
+            Err(ImproperList {
+                tail: improper_list_tail,
+            }) => tail = improper_list_tail,
+        }
+    }
+
+    (element_vec, tail)
+}
+
+fn push_tag<S: Sink>(sink: &mut S, tag: Tag) {
+    sink.push(tag.into());
 }
 
-fn try_append_isize_as_small_integer_or_integer(
-    mut byte_vec: &mut Vec<u8>,
+fn try_append_isize_as_small_integer_or_integer<S: Sink>(
+    sink: &mut S,
     integer: isize,
 ) -> Result<(), TypeError> {
     if SMALL_INTEGER_EXT_MIN <= integer && integer <= SMALL_INTEGER_EXT_MAX {
         let integer_u8: u8 = integer as u8;
 
-        push_tag(&mut byte_vec, Tag::SmallInteger);
-        byte_vec.extend_from_slice(&integer_u8.to_be_bytes());
+        push_tag(sink, Tag::SmallInteger);
+        sink.extend_from_slice(&integer_u8.to_be_bytes());
 
         Ok(())
     } else if INTEGER_EXT_MIN <= integer && integer <= INTEGER_EXT_MAX {
         let small_integer_i32: i32 = integer as i32;
 
-        push_tag(&mut byte_vec, Tag::Integer);
-        byte_vec.extend_from_slice(&small_integer_i32.to_be_bytes());
+        push_tag(sink, Tag::Integer);
+        sink.extend_from_slice(&small_integer_i32.to_be_bytes());
 
         Ok(())
     } else {
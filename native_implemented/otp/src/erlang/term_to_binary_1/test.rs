@@ -16,7 +16,7 @@ fn roundtrips_through_binary_to_term() {
     run!(
         |arc_process| (Just(arc_process.clone()), strategy::term(arc_process)),
         |(arc_process, term)| {
-            let binary = result(&arc_process, term);
+            let binary = result(&arc_process, term).unwrap();
 
             prop_assert!(binary.is_binary());
             prop_assert_eq!(binary_to_term_1::result(&arc_process, binary), Ok(term));
@@ -32,7 +32,7 @@ fn with_negative_float_returns_new_float_ext() {
     with_process(|process| {
         assert_eq!(
             result(process, process.float(std::f64::MIN)),
-            process.binary_from_bytes(&[
+            Ok(process.binary_from_bytes(&[
                 VERSION_NUMBER,
                 NEW_FLOAT_EXT,
                 255,
@@ -43,7 +43,7 @@ fn with_negative_float_returns_new_float_ext() {
                 255,
                 255,
                 255
-            ])
+            ]))
         );
     });
 }
@@ -54,7 +54,7 @@ fn with_zero_float_returns_new_float_ext() {
     with_process(|process| {
         assert_eq!(
             result(process, process.float(0.0)),
-            process.binary_from_bytes(&[
+            Ok(process.binary_from_bytes(&[
                 VERSION_NUMBER,
                 NEW_FLOAT_EXT,
                 0b0000_0000,
@@ -65,7 +65,7 @@ fn with_zero_float_returns_new_float_ext() {
                 0b0000_0000,
                 0b0000_0000,
                 0b0000_0000
-            ])
+            ]))
         );
     });
 }
@@ -76,7 +76,7 @@ fn with_positive_float_returns_new_float_ext() {
     with_process(|process| {
         assert_eq!(
             result(process, process.float(std::f64::MAX)),
-            process.binary_from_bytes(&[
+            Ok(process.binary_from_bytes(&[
                 VERSION_NUMBER,
                 NEW_FLOAT_EXT,
                 127,
@@ -87,7 +87,7 @@ fn with_positive_float_returns_new_float_ext() {
                 255,
                 255,
                 255
-            ])
+            ]))
         );
     });
 }
@@ -102,7 +102,7 @@ fn with_subbinary_without_binary_with_aligned_returns_bit_binary_ext() {
         let expected =
             process.binary_from_bytes(&[131, 77, 0, 0, 0, 2, 1, 0b1010_1010, 0b1000_0000]);
 
-        assert_eq!(result(process, subbinary), expected);
+        assert_eq!(result(process, subbinary), Ok(expected));
     });
 }
 
@@ -115,7 +115,7 @@ fn with_subbinary_without_binary_without_aligned_returns_bit_binary_ext() {
 
         assert_eq!(
             result(process, subbinary),
-            process.binary_from_bytes(&[131, 77, 0, 0, 0, 2, 1, 0b10_10101, 0b0000_0000])
+            Ok(process.binary_from_bytes(&[131, 77, 0, 0, 0, 2, 1, 0b10_10101, 0b0000_0000]))
         );
     });
 }
@@ -129,10 +129,10 @@ fn with_reference_returns_new_reference_ext() {
 
         assert_eq!(
             result(process, reference),
-            process.binary_from_bytes(&[
+            Ok(process.binary_from_bytes(&[
                 131, 90, 0, 3, 100, 0, 13, 110, 111, 110, 111, 100, 101, 64, 110, 111, 104, 111,
                 115, 116, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2
-            ])
+            ]))
         );
     });
 }
@@ -145,7 +145,7 @@ fn with_unsigned_byte_small_integer_returns_small_integer_ext() {
 
         assert_eq!(
             result(process, process.integer(small_integer_u8)),
-            process.binary_from_bytes(&[VERSION_NUMBER, SMALL_INTEGER_EXT, small_integer_u8])
+            Ok(process.binary_from_bytes(&[VERSION_NUMBER, SMALL_INTEGER_EXT, small_integer_u8]))
         );
     });
 }
@@ -158,14 +158,14 @@ fn with_negative_i32_small_integer_returns_integer_ext() {
 
         assert_eq!(
             result(process, process.integer(small_integer_i32)),
-            process.binary_from_bytes(&[
+            Ok(process.binary_from_bytes(&[
                 VERSION_NUMBER,
                 INTEGER_EXT,
                 0b1000_0000,
                 0b0000_0000,
                 0b0000_0000,
                 0b0000_0000
-            ])
+            ]))
         );
     });
 }
@@ -178,14 +178,14 @@ fn with_positive_i32_small_integer_returns_integer_ext() {
 
         assert_eq!(
             result(process, process.integer(small_integer_i32)),
-            process.binary_from_bytes(&[
+            Ok(process.binary_from_bytes(&[
                 VERSION_NUMBER,
                 INTEGER_EXT,
                 0b0111_1111,
                 0b1111_1111,
                 0b1111_1111,
                 0b1111_1111
-            ])
+            ]))
         );
     });
 }
@@ -196,7 +196,7 @@ fn with_empty_atom_returns_atom_ext() {
     with_process(|process| {
         assert_eq!(
             result(process, Atom::str_to_term("")),
-            process.binary_from_bytes(&[VERSION_NUMBER, ATOM_EXT, 0, 0])
+            Ok(process.binary_from_bytes(&[VERSION_NUMBER, ATOM_EXT, 0, 0]))
         );
     });
 }
@@ -211,7 +211,7 @@ fn with_non_empty_atom_returns_atom_ext() {
 
         assert_eq!(
             result(process, non_empty_atom_term()),
-            process.binary_from_bytes(&byte_vec)
+            Ok(process.binary_from_bytes(&byte_vec))
         );
     });
 }
@@ -224,7 +224,7 @@ fn with_pid_returns_pid_ext() {
 
         assert_eq!(
             result(process, pid),
-            process.binary_from_bytes(&[
+            Ok(process.binary_from_bytes(&[
                 VERSION_NUMBER,
                 PID_EXT,
                 100,
@@ -252,7 +252,7 @@ fn with_pid_returns_pid_ext() {
                 0,
                 2,
                 0
-            ])
+            ]))
         )
     });
 }
@@ -263,7 +263,7 @@ fn with_empty_tuple_returns_small_tuple_ext() {
     with_process(|process| {
         assert_eq!(
             result(process, process.tuple_from_slice(&[])),
-            process.binary_from_bytes(&[VERSION_NUMBER, SMALL_TUPLE_EXT, 0])
+            Ok(process.binary_from_bytes(&[VERSION_NUMBER, SMALL_TUPLE_EXT, 0]))
         );
     });
 }
@@ -278,7 +278,7 @@ fn with_non_empty_tuple_returns_small_tuple_ext() {
 
         assert_eq!(
             result(process, non_empty_tuple_term(process)),
-            process.binary_from_bytes(&byte_vec)
+            Ok(process.binary_from_bytes(&byte_vec))
         );
     });
 }
@@ -289,7 +289,7 @@ fn with_empty_list_returns_nil_ext() {
     with_process(|process| {
         assert_eq!(
             result(process, Term::NIL),
-            process.binary_from_bytes(&[VERSION_NUMBER, NIL_EXT])
+            Ok(process.binary_from_bytes(&[VERSION_NUMBER, NIL_EXT]))
         );
     });
 }
@@ -300,7 +300,7 @@ fn with_ascii_charlist_returns_string_ext() {
     with_process(|process| {
         assert_eq!(
             result(process, process.charlist_from_str("string")),
-            process.binary_from_bytes(&[
+            Ok(process.binary_from_bytes(&[
                 VERSION_NUMBER,
                 STRING_EXT,
                 0,
@@ -311,7 +311,7 @@ fn with_ascii_charlist_returns_string_ext() {
                 105,
                 110,
                 103
-            ])
+            ]))
         );
     })
 }
@@ -326,9 +326,9 @@ fn with_improper_list_returns_list_ext() {
                 process
                     .improper_list_from_slice(&[Atom::str_to_term("hd")], Atom::str_to_term("tl"))
             ),
-            process.binary_from_bytes(&[
+            Ok(process.binary_from_bytes(&[
                 131, 108, 0, 0, 0, 1, 100, 0, 2, 104, 100, 100, 0, 2, 116, 108
-            ])
+            ]))
         )
     });
 }
@@ -339,7 +339,7 @@ fn with_proper_list_returns_list_ext() {
     with_process(|process| {
         assert_eq!(
             result(process, process.list_from_slice(&[process.integer(256)])),
-            process.binary_from_bytes(&[131, 108, 0, 0, 0, 1, 98, 0, 0, 1, 0, 106])
+            Ok(process.binary_from_bytes(&[131, 108, 0, 0, 0, 1, 98, 0, 0, 1, 0, 106]))
         )
     });
 }
@@ -356,10 +356,10 @@ fn with_nested_list_returns_list_ext() {
                     process.list_from_slice(&[process.integer(2100), process.integer(2200)])
                 ])
             ),
-            process.binary_from_bytes(&[
+            Ok(process.binary_from_bytes(&[
                 131, 108, 0, 0, 0, 2, 108, 0, 0, 0, 2, 98, 0, 0, 4, 76, 98, 0, 0, 4, 176, 106, 108,
                 0, 0, 0, 2, 98, 0, 0, 8, 52, 98, 0, 0, 8, 152, 106, 106
-            ])
+            ]))
         )
     });
 }
@@ -370,7 +370,7 @@ fn with_empty_heap_binary_returns_binary_ext() {
     with_process(|process| {
         assert_eq!(
             result(process, process.binary_from_bytes(&[])),
-            process.binary_from_bytes(&[VERSION_NUMBER, BINARY_EXT, 0, 0, 0, 0])
+            Ok(process.binary_from_bytes(&[VERSION_NUMBER, BINARY_EXT, 0, 0, 0, 0]))
         );
     });
 }
@@ -381,7 +381,7 @@ fn with_non_empty_heap_binary_returns_binary_ext() {
     with_process(|process| {
         assert_eq!(
             result(process, process.binary_from_bytes(&[1, 2, 3])),
-            process.binary_from_bytes(&[VERSION_NUMBER, BINARY_EXT, 0, 0, 0, 3, 1, 2, 3])
+            Ok(process.binary_from_bytes(&[VERSION_NUMBER, BINARY_EXT, 0, 0, 0, 3, 1, 2, 3]))
         )
     })
 }
@@ -400,7 +400,7 @@ fn with_proc_bin_returns_binary_ext() {
                     62, 63, 64
                 ])
             ),
-            process.binary_from_bytes(&[
+            Ok(process.binary_from_bytes(&[
                 VERSION_NUMBER,
                 BINARY_EXT,
                 0,
@@ -472,7 +472,7 @@ fn with_proc_bin_returns_binary_ext() {
                 62,
                 63,
                 64
-            ])
+            ]))
         );
     });
 }
@@ -486,7 +486,7 @@ fn with_subbinary_with_binary_with_aligned_returns_binary_ext() {
 
         assert_eq!(
             result(process, subbinary),
-            process.binary_from_bytes(&[131, 109, 0, 0, 0, 1, 0b1010_1010])
+            Ok(process.binary_from_bytes(&[131, 109, 0, 0, 0, 1, 0b1010_1010]))
         );
     });
 }
@@ -500,7 +500,7 @@ fn with_subbinary_with_binary_without_aligned_returns_binary_ext() {
 
         assert_eq!(
             result(process, subbinary),
-            process.binary_from_bytes(&[131, 109, 0, 0, 0, 1, 0b101_0101])
+            Ok(process.binary_from_bytes(&[131, 109, 0, 0, 0, 1, 0b101_0101]))
         );
     });
 }
@@ -511,7 +511,7 @@ fn with_small_integer_returns_small_big_ext() {
     with_process(|process| {
         assert_eq!(
             result(process, process.integer(9_999_999_999_i64)),
-            process.binary_from_bytes(&[131, 110, 5, 0, 255, 227, 11, 84, 2])
+            Ok(process.binary_from_bytes(&[131, 110, 5, 0, 255, 227, 11, 84, 2]))
         )
     })
 }
@@ -526,7 +526,8 @@ fn with_big_integer_returns_small_big_ext() {
 
         assert_eq!(
             result(process, big_integer),
-            process.binary_from_bytes(&[131, 110, 8, 0, 255, 255, 255, 255, 255, 255, 255, 127])
+            Ok(process
+                .binary_from_bytes(&[131, 110, 8, 0, 255, 255, 255, 255, 255, 255, 255, 127]))
         )
     })
 }
@@ -537,7 +538,7 @@ fn empty_map_returns_map_ext() {
     with_process(|process| {
         assert_eq!(
             result(process, process.map_from_slice(&[])),
-            process.binary_from_bytes(&[131, 116, 0, 0, 0, 0])
+            Ok(process.binary_from_bytes(&[131, 116, 0, 0, 0, 0]))
         )
     })
 }
@@ -554,10 +555,10 @@ fn non_empty_map_returns_map_ext() {
                     process.map_from_slice(&[(Atom::str_to_term("v_k"), Atom::str_to_term("v_v"))])
                 )])
             ),
-            process.binary_from_bytes(&[
+            Ok(process.binary_from_bytes(&[
                 131, 116, 0, 0, 0, 1, 100, 0, 1, 107, 116, 0, 0, 0, 1, 100, 0, 3, 118, 95, 107,
                 100, 0, 3, 118, 95, 118
-            ])
+            ]))
         );
     });
 }
@@ -568,11 +569,23 @@ fn with_small_utf8_atom_returns_small_atom_utf8_ext() {
     with_process(|process| {
         assert_eq!(
             result(process, Atom::str_to_term("😈")),
-            process.binary_from_bytes(&[131, 119, 4, 240, 159, 152, 136])
+            Ok(process.binary_from_bytes(&[131, 119, 4, 240, 159, 152, 136]))
         )
     });
 }
 
+#[test]
+fn with_term_without_external_term_format_errors_badarg() {
+    with_process(|process| {
+        let resource = process.resource(0_usize);
+
+        assert_badarg!(
+            result(process, resource),
+            "cannot be encoded in the external term format"
+        );
+    });
+}
+
 const VERSION_NUMBER: u8 = 131;
 
 const NEW_FLOAT_EXT: u8 = 70;
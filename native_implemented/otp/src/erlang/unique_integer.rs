@@ -30,17 +30,12 @@ pub fn unique_integer(process: &Process, options: Options) -> Term {
 
         // See https://github.com/erlang/otp/blob/769ff22c750d939fdc9cb45fae1e44817ec04307/erts/emulator/beam/erl_bif_unique.c#L669-L697
         if options.positive {
-            process.integer(u)
+            // Positive integers start at 1 as 0 is not positive
+            process.integer(u as u128 + 1)
         } else {
             // When not positive allow for negative and positive even though the counter is unsigned
-            // by subtracting counter value down into signed range.
-            let i = if u < NEGATED_I64_MIN_U64 {
-                (u as i64) + std::i64::MIN
-            } else {
-                (u - NEGATED_I64_MIN_U64) as i64
-            };
-
-            process.integer(i)
+            // by flipping the sign bit, which maps `0` to `i64::MIN` while preserving order.
+            process.integer((u ^ I64_SIGN_BIT) as i64)
         }
     } else {
         // Non-monotonic unique integers are per-scheduler (https://github.com/erlang/otp/blob/769ff22c750d939fdc9cb45fae1e44817ec04307/erts/emulator/beam/erl_bif_unique.c#L572-L584)
@@ -54,23 +49,15 @@ pub fn unique_integer(process: &Process, options: Options) -> Term {
         let u: u128 = (scheduler_id_u128 << 64) | scheduler_unique_integer;
 
         if options.positive {
-            process.integer(u)
+            process.integer(u + 1)
         } else {
-            let i = if u < NEGATED_I128_MIN_U128 {
-                (u as i128) + std::i128::MIN
-            } else {
-                (u - NEGATED_I128_MIN_U128) as i128
-            };
-
-            process.integer(i)
+            process.integer((u ^ I128_SIGN_BIT) as i128)
         }
     }
 }
 
-// have to add and then subtract to prevent overflow
-const NEGATED_I64_MIN_U64: u64 = ((-(std::i64::MIN + 1)) - 1) as u64;
-// have to add and then subtract to prevent overflow
-const NEGATED_I128_MIN_U128: u128 = ((-(std::i128::MIN + 1)) - 1) as u128;
+const I64_SIGN_BIT: u64 = 1 << 63;
+const I128_SIGN_BIT: u128 = 1 << 127;
 
 lazy_static! {
     static ref MONOTONIC: AtomicU64 = Default::default();
@@ -121,7 +121,7 @@ fn with_monotonic_and_positive_returns_monotonic_positiver_integer() {
         let zero = process.integer(0);
 
         assert!(first_unique_integer.is_integer());
-        assert!(zero <= first_unique_integer);
+        assert!(zero < first_unique_integer);
 
         let result_second_unique_integer = result(process, options);
 
@@ -130,7 +130,7 @@ fn with_monotonic_and_positive_returns_monotonic_positiver_integer() {
         let second_unique_integer = result_second_unique_integer.unwrap();
 
         assert!(second_unique_integer.is_integer());
-        assert!(zero <= second_unique_integer);
+        assert!(zero < second_unique_integer);
 
         assert!(first_unique_integer < second_unique_integer);
     });
@@ -149,7 +149,7 @@ fn with_positive_returns_non_monotonic_and_positive_integer() {
         let zero = process.integer(0);
 
         assert!(first_unique_integer.is_integer());
-        assert!(zero <= first_unique_integer);
+        assert!(zero < first_unique_integer);
 
         let result_second_unique_integer = result(process, options);
 
@@ -158,7 +158,7 @@ fn with_positive_returns_non_monotonic_and_positive_integer() {
         let second_unique_integer = result_second_unique_integer.unwrap();
 
         assert!(second_unique_integer.is_integer());
-        assert!(zero <= second_unique_integer);
+        assert!(zero < second_unique_integer);
 
         assert_ne!(first_unique_integer, second_unique_integer);
     });
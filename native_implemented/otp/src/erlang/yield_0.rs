@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::Term;
+
+/// Gives other processes with the same or higher priority a chance to run before returning.  When
+/// there is nothing else to run, the process is rescheduled immediately.
+#[native_implemented::function(erlang:yield/0)]
+pub fn result(process: &Process) -> Term {
+    process.yield_now();
+
+    true.into()
+}
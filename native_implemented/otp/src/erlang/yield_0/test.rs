@@ -0,0 +1,20 @@
+use crate::erlang::yield_0::result;
+use crate::test::with_process;
+
+#[test]
+fn returns_true() {
+    with_process(|process| {
+        assert_eq!(result(process), true.into());
+    });
+}
+
+#[test]
+fn uses_up_reduction_budget() {
+    with_process(|process| {
+        assert!(!process.is_reduced());
+
+        result(process);
+
+        assert!(process.is_reduced());
+    });
+}
@@ -0,0 +1,72 @@
+//! Mirrors [ets](http://erlang.org/doc/man/ets.html) module
+
+pub mod delete_1;
+pub mod delete_2;
+pub mod first_1;
+pub mod insert_2;
+pub mod lookup_2;
+pub mod member_2;
+pub mod new_2;
+pub mod next_2;
+pub mod update_counter_3;
+
+use std::sync::Arc;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_is_not_type;
+use crate::runtime::ets::{name_to_table, reference_to_table, Table};
+
+fn end_of_table() -> Term {
+    Atom::str_to_term("$end_of_table")
+}
+
+fn module() -> Atom {
+    Atom::from_str("ets")
+}
+
+/// Returns the table named or referenced by `table` if `process` can read it.
+fn readable_table(process: &Process, table: Term) -> exception::Result<Arc<Table>> {
+    let arc_table = term_to_table(table)?;
+
+    if arc_table.is_readable_by(process.pid()) {
+        Ok(arc_table)
+    } else {
+        Err(anyhow!("table ({}) is private to another process", table).into())
+    }
+}
+
+/// Returns the table named or referenced by `table` if `process` can write it.
+fn writable_table(process: &Process, table: Term) -> exception::Result<Arc<Table>> {
+    let arc_table = term_to_table(table)?;
+
+    if arc_table.is_writable_by(process.pid()) {
+        Ok(arc_table)
+    } else {
+        Err(anyhow!("table ({}) is only writable by its owner", table).into())
+    }
+}
+
+fn term_to_table(table: Term) -> exception::Result<Arc<Table>> {
+    let option_arc_table = match table.decode()? {
+        TypedTerm::Atom(name) => name_to_table(&name),
+        TypedTerm::Reference(reference) => reference_to_table(reference.as_ref()),
+        _ => {
+            return Err(TypeError)
+                .context(term_is_not_type(
+                    "table",
+                    table,
+                    "a table name (atom) or reference",
+                ))
+                .map_err(From::from)
+        }
+    };
+
+    option_arc_table
+        .with_context(|| format!("table ({}) does not exist", table))
+        .map_err(From::from)
+}
@@ -0,0 +1,17 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::ets;
+
+/// Deletes `table` and all of its objects.
+#[native_implemented::function(ets:delete/1)]
+pub fn result(process: &Process, table: Term) -> exception::Result<Term> {
+    let arc_table = super::writable_table(process, table)?;
+    ets::unregister(&arc_table);
+
+    Ok(true.into())
+}
@@ -0,0 +1,32 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::ets::{delete_1, lookup_2, new_2};
+use crate::test::with_process;
+
+#[test]
+fn with_table_deletes_table() {
+    with_process(|process| {
+        let table = new_2::result(process, Atom::str_to_term("table"), Term::NIL).unwrap();
+
+        assert_eq!(delete_1::result(process, table), Ok(true.into()));
+        assert_badarg!(
+            lookup_2::result(process, table, Atom::str_to_term("key")),
+            format!("table ({}) does not exist", table)
+        );
+    });
+}
+
+#[test]
+fn with_named_table_frees_name() {
+    with_process(|process| {
+        let name = Atom::str_to_term("ets_delete_1_with_named_table_frees_name");
+        let options = process.list_from_slice(&[Atom::str_to_term("named_table")]);
+
+        new_2::result(process, name, options).unwrap();
+
+        assert_eq!(delete_1::result(process, name), Ok(true.into()));
+        assert_eq!(new_2::result(process, name, options), Ok(name));
+
+        delete_1::result(process, name).unwrap();
+    });
+}
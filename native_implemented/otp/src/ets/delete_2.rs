@@ -0,0 +1,15 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Deletes the object with `key` from `table`.  Returns `true` even if there is no such object.
+#[native_implemented::function(ets:delete/2)]
+pub fn result(process: &Process, table: Term, key: Term) -> exception::Result<Term> {
+    let arc_table = super::writable_table(process, table)?;
+    arc_table.delete(key);
+
+    Ok(true.into())
+}
@@ -0,0 +1,29 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::ets::{delete_2, insert_2, member_2, new_2};
+use crate::test::with_process;
+
+#[test]
+fn with_key_deletes_object() {
+    with_process(|process| {
+        let table = new_2::result(process, Atom::str_to_term("table"), Term::NIL).unwrap();
+        let key = Atom::str_to_term("key");
+
+        insert_2::result(process, table, process.tuple_from_slice(&[key])).unwrap();
+
+        assert_eq!(delete_2::result(process, table, key), Ok(true.into()));
+        assert_eq!(member_2::result(process, table, key), Ok(false.into()));
+    });
+}
+
+#[test]
+fn without_key_returns_true() {
+    with_process(|process| {
+        let table = new_2::result(process, Atom::str_to_term("table"), Term::NIL).unwrap();
+
+        assert_eq!(
+            delete_2::result(process, table, Atom::str_to_term("key")),
+            Ok(true.into())
+        );
+    });
+}
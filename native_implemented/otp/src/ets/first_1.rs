@@ -0,0 +1,15 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns the first key of `table`, or `'$end_of_table'` if `table` is empty.  Use
+/// `ets:next/2` to continue the traversal.
+#[native_implemented::function(ets:first/1)]
+pub fn result(process: &Process, table: Term) -> exception::Result<Term> {
+    let arc_table = super::readable_table(process, table)?;
+
+    Ok(arc_table.first(process).unwrap_or_else(super::end_of_table))
+}
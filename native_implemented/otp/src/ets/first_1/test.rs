@@ -0,0 +1,28 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::ets::{first_1, insert_2, new_2};
+use crate::test::with_process;
+
+#[test]
+fn with_empty_table_returns_end_of_table() {
+    with_process(|process| {
+        let table = new_2::result(process, Atom::str_to_term("table"), Term::NIL).unwrap();
+
+        assert_eq!(
+            first_1::result(process, table),
+            Ok(Atom::str_to_term("$end_of_table"))
+        );
+    });
+}
+
+#[test]
+fn with_object_returns_key() {
+    with_process(|process| {
+        let table = new_2::result(process, Atom::str_to_term("table"), Term::NIL).unwrap();
+        let key = Atom::str_to_term("key");
+
+        insert_2::result(process, table, process.tuple_from_slice(&[key])).unwrap();
+
+        assert_eq!(first_1::result(process, table), Ok(key));
+    });
+}
@@ -0,0 +1,56 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Inserts `object_or_objects`, a tuple or list of tuples, into `table`, replacing any objects with
+/// the same keys.  A list of objects is inserted atomically.
+#[native_implemented::function(ets:insert/2)]
+pub fn result(process: &Process, table: Term, object_or_objects: Term) -> exception::Result<Term> {
+    let arc_table = super::writable_table(process, table)?;
+    let objects = match object_or_objects.decode()? {
+        TypedTerm::Tuple(_) => vec![object_or_objects],
+        TypedTerm::Nil => Vec::new(),
+        TypedTerm::List(cons) => cons
+            .into_iter()
+            .collect::<Result<Vec<Term>, _>>()
+            .map_err(|_| ImproperListError)
+            .with_context(|| format!("object_or_objects ({}) is improper", object_or_objects))?,
+        _ => {
+            return Err(TypeError)
+                .with_context(|| {
+                    format!(
+                        "object_or_objects ({}) is not a tuple or list of tuples",
+                        object_or_objects
+                    )
+                })
+                .map_err(From::from)
+        }
+    };
+
+    let key_index: usize = arc_table.key_position.into();
+
+    for object in &objects {
+        let has_key = match object.decode()? {
+            TypedTerm::Tuple(tuple) => tuple.get_element(arc_table.key_position).is_ok(),
+            _ => false,
+        };
+
+        if !has_key {
+            return Err(anyhow!(
+                "object ({}) is not a tuple with at least {} elements",
+                object,
+                key_index + 1
+            )
+            .into());
+        }
+    }
+
+    arc_table.insert(&objects)?;
+
+    Ok(true.into())
+}
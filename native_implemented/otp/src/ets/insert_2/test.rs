@@ -0,0 +1,45 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::ets::{insert_2, new_2};
+use crate::test::with_process;
+
+#[test]
+fn without_table_errors_badarg() {
+    with_process(|process| {
+        let table = Atom::str_to_term("ets_insert_2_without_table");
+
+        assert_badarg!(
+            insert_2::result(process, table, process.tuple_from_slice(&[table])),
+            format!("table ({}) does not exist", table)
+        );
+    });
+}
+
+#[test]
+fn with_object_without_key_errors_badarg() {
+    with_process(|process| {
+        let table = new_2::result(process, Atom::str_to_term("table"), Term::NIL).unwrap();
+        let object = process.tuple_from_slice(&[]);
+
+        assert_badarg!(
+            insert_2::result(process, table, object),
+            format!(
+                "object ({}) is not a tuple with at least 1 elements",
+                object
+            )
+        );
+    });
+}
+
+#[test]
+fn with_list_of_objects_returns_true() {
+    with_process(|process| {
+        let table = new_2::result(process, Atom::str_to_term("table"), Term::NIL).unwrap();
+        let objects = process.list_from_slice(&[
+            process.tuple_from_slice(&[process.integer(1)]),
+            process.tuple_from_slice(&[process.integer(2)]),
+        ]);
+
+        assert_eq!(insert_2::result(process, table, objects), Ok(true.into()));
+    });
+}
@@ -0,0 +1,15 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns the list of objects in `table` with `key`, which for a `set` table is either empty or
+/// has one object.
+#[native_implemented::function(ets:lookup/2)]
+pub fn result(process: &Process, table: Term, key: Term) -> exception::Result<Term> {
+    let arc_table = super::readable_table(process, table)?;
+
+    Ok(arc_table.lookup(process, key))
+}
@@ -0,0 +1,49 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::ets::{insert_2, lookup_2, new_2};
+use crate::test::with_process;
+
+#[test]
+fn without_key_returns_empty_list() {
+    with_process(|process| {
+        let table = new_2::result(process, Atom::str_to_term("table"), Term::NIL).unwrap();
+
+        assert_eq!(
+            lookup_2::result(process, table, Atom::str_to_term("key")),
+            Ok(Term::NIL)
+        );
+    });
+}
+
+#[test]
+fn with_key_returns_latest_object() {
+    with_process(|process| {
+        let table = new_2::result(process, Atom::str_to_term("table"), Term::NIL).unwrap();
+        let key = Atom::str_to_term("key");
+        let first = process.tuple_from_slice(&[key, process.integer(1)]);
+        let second = process.tuple_from_slice(&[key, process.integer(2)]);
+
+        insert_2::result(process, table, first).unwrap();
+        insert_2::result(process, table, second).unwrap();
+
+        assert_eq!(
+            lookup_2::result(process, table, key),
+            Ok(process.list_from_slice(&[second]))
+        );
+    });
+}
+
+#[test]
+fn with_integer_key_does_not_find_float_key() {
+    with_process(|process| {
+        let table = new_2::result(process, Atom::str_to_term("table"), Term::NIL).unwrap();
+        let object = process.tuple_from_slice(&[process.integer(1)]);
+
+        insert_2::result(process, table, object).unwrap();
+
+        assert_eq!(
+            lookup_2::result(process, table, process.float(1.0)),
+            Ok(Term::NIL)
+        );
+    });
+}
@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns whether `table` has an object with `key`.
+#[native_implemented::function(ets:member/2)]
+pub fn result(process: &Process, table: Term, key: Term) -> exception::Result<Term> {
+    let arc_table = super::readable_table(process, table)?;
+
+    Ok(arc_table.is_member(key).into())
+}
@@ -0,0 +1,124 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::{TryFrom, TryInto};
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_try_into_one_based_index;
+use crate::runtime::ets::{self, Access, Table};
+use crate::runtime::scheduler::SchedulerDependentAlloc;
+
+/// Creates a new `set` table owned by `process`.  Returns `name` if the `named_table` option is
+/// given, otherwise a reference identifying the table.
+#[native_implemented::function(ets:new/2)]
+pub fn result(process: &Process, name: Term, options: Term) -> exception::Result<Term> {
+    let name_atom = term_try_into_atom!(name)?;
+    let Options {
+        access,
+        key_position,
+        named_table,
+    } = options.try_into()?;
+
+    let reference_term = process.next_reference();
+    let reference: Boxed<Reference> = reference_term.try_into().unwrap();
+    let table = Table::new(
+        *reference.as_ref(),
+        if named_table { Some(name_atom) } else { None },
+        process.pid(),
+        access,
+        key_position,
+    );
+
+    if ets::register(table) {
+        Ok(if named_table { name } else { reference_term })
+    } else {
+        Err(anyhow!("name ({}) is already used by another named table", name).into())
+    }
+}
+
+// Private
+
+const SUPPORTED_OPTIONS_CONTEXT: &str =
+    "supported options are set, named_table, public, protected, private, and {keypos, Pos}";
+
+#[derive(Default)]
+struct Options {
+    access: Access,
+    key_position: OneBasedIndex,
+    named_table: bool,
+}
+
+impl Options {
+    fn put_option_term(&mut self, option: Term) -> Result<(), anyhow::Error> {
+        match option.decode().unwrap() {
+            TypedTerm::Atom(atom) => match atom.name() {
+                "set" => Ok(()),
+                "named_table" => {
+                    self.named_table = true;
+
+                    Ok(())
+                }
+                "public" => {
+                    self.access = Access::Public;
+
+                    Ok(())
+                }
+                "protected" => {
+                    self.access = Access::Protected;
+
+                    Ok(())
+                }
+                "private" => {
+                    self.access = Access::Private;
+
+                    Ok(())
+                }
+                _ => Err(anyhow!("option ({}) is not supported", option)),
+            },
+            TypedTerm::Tuple(tuple) if tuple.len() == 2 => {
+                let key: Atom = tuple[0]
+                    .try_into()
+                    .with_context(|| format!("option ({}) is not supported", option))?;
+
+                match key.name() {
+                    "keypos" => {
+                        self.key_position = term_try_into_one_based_index(tuple[1])?;
+
+                        Ok(())
+                    }
+                    _ => Err(anyhow!("option ({}) is not supported", option)),
+                }
+            }
+            _ => Err(anyhow!("option ({}) is not supported", option)),
+        }
+    }
+}
+
+impl TryFrom<Term> for Options {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> Result<Self, Self::Error> {
+        let mut options: Options = Default::default();
+        let mut options_term = term;
+
+        loop {
+            match options_term.decode().unwrap() {
+                TypedTerm::Nil => return Ok(options),
+                TypedTerm::List(cons) => {
+                    options
+                        .put_option_term(cons.head)
+                        .context(SUPPORTED_OPTIONS_CONTEXT)?;
+                    options_term = cons.tail;
+
+                    continue;
+                }
+                _ => return Err(ImproperListError).context(SUPPORTED_OPTIONS_CONTEXT),
+            };
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::ets::delete_1;
+use crate::ets::new_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_atom_name_errors_badarg() {
+    with_process(|process| {
+        let name = process.integer(1);
+
+        assert_badarg!(
+            result(process, name, Term::NIL),
+            format!("name ({}) is not an atom", name)
+        );
+    });
+}
+
+#[test]
+fn with_unsupported_option_errors_badarg() {
+    with_process(|process| {
+        let option = Atom::str_to_term("bag");
+
+        assert_badarg!(
+            result(
+                process,
+                Atom::str_to_term("with_unsupported_option"),
+                process.list_from_slice(&[option])
+            ),
+            format!("option ({}) is not supported", option)
+        );
+    });
+}
+
+#[test]
+fn without_named_table_returns_reference() {
+    with_process(|process| {
+        let table = result(process, Atom::str_to_term("without_named_table"), Term::NIL).unwrap();
+
+        assert!(table.is_boxed_local_reference());
+    });
+}
+
+#[test]
+fn with_named_table_returns_name() {
+    with_process(|process| {
+        let name = Atom::str_to_term("ets_new_2_with_named_table_returns_name");
+        let options = process.list_from_slice(&[Atom::str_to_term("named_table")]);
+
+        assert_eq!(result(process, name, options), Ok(name));
+        assert_badarg!(
+            result(process, name, options),
+            format!("name ({}) is already used by another named table", name)
+        );
+
+        delete_1::result(process, name).unwrap();
+    });
+}
@@ -0,0 +1,17 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns the key after `key` in `table`, or `'$end_of_table'` if `key` is the last key.  `key`
+/// must be in `table`.
+#[native_implemented::function(ets:next/2)]
+pub fn result(process: &Process, table: Term, key: Term) -> exception::Result<Term> {
+    let arc_table = super::readable_table(process, table)?;
+
+    Ok(arc_table
+        .next(process, key)?
+        .unwrap_or_else(super::end_of_table))
+}
@@ -0,0 +1,32 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::ets::{insert_2, new_2, next_2};
+use crate::test::with_process;
+
+#[test]
+fn without_key_errors_badarg() {
+    with_process(|process| {
+        let table = new_2::result(process, Atom::str_to_term("table"), Term::NIL).unwrap();
+        let key = Atom::str_to_term("key");
+
+        assert_badarg!(
+            next_2::result(process, table, key),
+            format!("key ({}) is not in table", key)
+        );
+    });
+}
+
+#[test]
+fn with_last_key_returns_end_of_table() {
+    with_process(|process| {
+        let table = new_2::result(process, Atom::str_to_term("table"), Term::NIL).unwrap();
+        let key = Atom::str_to_term("key");
+
+        insert_2::result(process, table, process.tuple_from_slice(&[key])).unwrap();
+
+        assert_eq!(
+            next_2::result(process, table, key),
+            Ok(Atom::str_to_term("$end_of_table"))
+        );
+    });
+}
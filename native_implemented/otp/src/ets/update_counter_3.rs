@@ -0,0 +1,140 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::add_2;
+use crate::runtime::context::*;
+
+/// Atomically adds to the integer counter of the object with `key` in `table`, returning the new
+/// counter value.
+///
+/// `update_op` is one of
+/// * `Incr` - adds `Incr` to the element after the key.
+/// * `{Pos, Incr}` - adds `Incr` to the element at `Pos`.
+/// * `{Pos, Incr, Threshold, SetValue}` - adds `Incr` to the element at `Pos`, but sets it to
+///   `SetValue` instead if the sum goes above `Threshold` (or below it for a negative `Incr`).
+#[native_implemented::function(ets:update_counter/3)]
+pub fn result(
+    process: &Process,
+    table: Term,
+    key: Term,
+    update_op: Term,
+) -> exception::Result<Term> {
+    let arc_table = super::writable_table(process, table)?;
+    let UpdateOp {
+        position,
+        increment,
+        wrap,
+    } = update_op_try_into(update_op, arc_table.key_position)?;
+
+    let option_updated_tuple = arc_table.update(process, key, |tuple| {
+        let counter = tuple
+            .get_element(position)
+            .ok()
+            .filter(|counter| counter.is_integer())
+            .with_context(|| {
+                format!(
+                    "object ({}) does not have an integer counter at position ({})",
+                    tuple.as_ref(),
+                    position_term(process, position)
+                )
+            })?;
+        let sum = add_2::result(process, counter, increment)?;
+        let zero = process.integer(0);
+
+        let updated_counter = match wrap {
+            Some((threshold, set_value))
+                if (increment >= zero && sum > threshold)
+                    || (increment < zero && sum < threshold) =>
+            {
+                set_value
+            }
+            _ => sum,
+        };
+
+        let mut elements = tuple[..].to_vec();
+        let index: usize = position.into();
+        elements[index] = updated_counter;
+
+        Ok(process.tuple_from_slice(&elements))
+    })?;
+
+    match option_updated_tuple {
+        Some(updated_tuple) => {
+            let updated_tuple: Boxed<Tuple> = updated_tuple.try_into().unwrap();
+
+            Ok(updated_tuple.get_element(position).unwrap())
+        }
+        None => Err(anyhow!("key ({}) is not in table ({})", key, table).into()),
+    }
+}
+
+// Private
+
+struct UpdateOp {
+    position: OneBasedIndex,
+    increment: Term,
+    wrap: Option<(Term, Term)>,
+}
+
+fn integer(name: &str, value: Term) -> exception::Result<Term> {
+    if value.is_integer() {
+        Ok(value)
+    } else {
+        Err(TypeError)
+            .context(term_is_not_integer(name, value))
+            .map_err(From::from)
+    }
+}
+
+fn position_term(process: &Process, position: OneBasedIndex) -> Term {
+    let index: usize = position.into();
+
+    process.integer(index + 1)
+}
+
+fn update_op_try_into(update_op: Term, key_position: OneBasedIndex) -> exception::Result<UpdateOp> {
+    let (position, increment, wrap) = match update_op.decode()? {
+        TypedTerm::Tuple(tuple) if tuple.len() == 2 || tuple.len() == 4 => {
+            let position = term_try_into_one_based_index(tuple[0])?;
+            let increment = integer("increment", tuple[1])?;
+            let wrap = if tuple.len() == 4 {
+                Some((
+                    integer("threshold", tuple[2])?,
+                    integer("set_value", tuple[3])?,
+                ))
+            } else {
+                None
+            };
+
+            (position, increment, wrap)
+        }
+        _ if update_op.is_integer() => (key_position + 1, update_op, None),
+        _ => {
+            return Err(TypeError)
+                .context(term_is_not_type(
+                    "update_op",
+                    update_op,
+                    "an integer, {Pos, Incr}, or {Pos, Incr, Threshold, SetValue}",
+                ))
+                .map_err(From::from)
+        }
+    };
+
+    if position == key_position {
+        Err(anyhow!("update_op ({}) would update the key", update_op).into())
+    } else {
+        Ok(UpdateOp {
+            position,
+            increment,
+            wrap,
+        })
+    }
+}
@@ -0,0 +1,130 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::ets::{insert_2, lookup_2, new_2, update_counter_3};
+use crate::test::with_process;
+
+#[test]
+fn without_key_errors_badarg() {
+    with_process(|process| {
+        let table = new_2::result(process, Atom::str_to_term("table"), Term::NIL).unwrap();
+        let key = Atom::str_to_term("key");
+
+        assert_badarg!(
+            update_counter_3::result(process, table, key, process.integer(1)),
+            format!("key ({}) is not in table ({})", key, table)
+        );
+    });
+}
+
+#[test]
+fn with_non_integer_counter_errors_badarg() {
+    with_process(|process| {
+        let table = new_2::result(process, Atom::str_to_term("table"), Term::NIL).unwrap();
+        let key = Atom::str_to_term("key");
+        let object = process.tuple_from_slice(&[key, Atom::str_to_term("counter")]);
+
+        insert_2::result(process, table, object).unwrap();
+
+        assert_badarg!(
+            update_counter_3::result(process, table, key, process.integer(1)),
+            format!(
+                "object ({}) does not have an integer counter at position (2)",
+                object
+            )
+        );
+    });
+}
+
+#[test]
+fn with_key_position_errors_badarg() {
+    with_process(|process| {
+        let table = new_2::result(process, Atom::str_to_term("table"), Term::NIL).unwrap();
+        let update_op = process.tuple_from_slice(&[process.integer(1), process.integer(1)]);
+
+        assert_badarg!(
+            update_counter_3::result(process, table, Atom::str_to_term("key"), update_op),
+            format!("update_op ({}) would update the key", update_op)
+        );
+    });
+}
+
+#[test]
+fn with_integer_increments_element_after_key() {
+    with_process(|process| {
+        let table = new_2::result(process, Atom::str_to_term("table"), Term::NIL).unwrap();
+        let key = Atom::str_to_term("key");
+
+        insert_2::result(
+            process,
+            table,
+            process.tuple_from_slice(&[key, process.integer(1), process.integer(10)]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            update_counter_3::result(process, table, key, process.integer(2)),
+            Ok(process.integer(3))
+        );
+        assert_eq!(
+            lookup_2::result(process, table, key),
+            Ok(process.list_from_slice(&[process.tuple_from_slice(&[
+                key,
+                process.integer(3),
+                process.integer(10)
+            ])]))
+        );
+    });
+}
+
+#[test]
+fn with_threshold_sets_value_when_passed() {
+    with_process(|process| {
+        let table = new_2::result(process, Atom::str_to_term("table"), Term::NIL).unwrap();
+        let key = Atom::str_to_term("key");
+
+        insert_2::result(
+            process,
+            table,
+            process.tuple_from_slice(&[key, process.integer(9)]),
+        )
+        .unwrap();
+
+        let update_op = process.tuple_from_slice(&[
+            process.integer(2),
+            process.integer(1),
+            process.integer(10),
+            process.integer(0),
+        ]);
+
+        assert_eq!(
+            update_counter_3::result(process, table, key, update_op),
+            Ok(process.integer(10))
+        );
+        assert_eq!(
+            update_counter_3::result(process, table, key, update_op),
+            Ok(process.integer(0))
+        );
+    });
+}
+
+#[test]
+fn with_big_integer_counter_returned_counter_outlives_next_update() {
+    with_process(|process| {
+        let table = new_2::result(process, Atom::str_to_term("table"), Term::NIL).unwrap();
+        let key = Atom::str_to_term("key");
+
+        insert_2::result(
+            process,
+            table,
+            process.tuple_from_slice(&[key, process.integer(SmallInteger::MAX_VALUE)]),
+        )
+        .unwrap();
+
+        let counter = update_counter_3::result(process, table, key, process.integer(1)).unwrap();
+
+        // Replaces and frees the object that `counter` was read from
+        update_counter_3::result(process, table, key, process.integer(1)).unwrap();
+
+        assert_eq!(counter, process.integer(SmallInteger::MAX_VALUE + 1));
+    });
+}
@@ -0,0 +1,84 @@
+//! Mirrors [gb_trees](http://erlang.org/doc/man/gb_trees.html) module
+//!
+//! A tree is `{Size, Node}`, where `Node` is either `nil` or `{Key, Value, Smaller, Bigger}`, the
+//! same representation as C-BEAM, so trees can be passed between native and Erlang code.
+
+pub mod empty_0;
+pub mod insert_3;
+pub mod lookup_2;
+pub mod to_list_1;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::from_str("gb_trees")
+}
+
+fn nil() -> Term {
+    Atom::str_to_term("nil")
+}
+
+/// The `{Key, Value, Smaller, Bigger}` of a non-`nil` node.
+#[derive(Clone, Copy)]
+struct Node {
+    key: Term,
+    value: Term,
+    smaller: Term,
+    bigger: Term,
+}
+
+impl Node {
+    fn to_term(&self, process: &Process) -> Term {
+        process.tuple_from_slice(&[self.key, self.value, self.smaller, self.bigger])
+    }
+}
+
+fn term_try_into_node(tree: Term, node: Term) -> exception::Result<Option<Node>> {
+    match node.decode()? {
+        TypedTerm::Atom(atom) if atom.name() == "nil" => Ok(None),
+        TypedTerm::Tuple(tuple) if tuple.len() == 4 => Ok(Some(Node {
+            key: tuple[0],
+            value: tuple[1],
+            smaller: tuple[2],
+            bigger: tuple[3],
+        })),
+        _ => Err(anyhow!("tree ({}) is not a gb_tree", tree).into()),
+    }
+}
+
+/// Splits `tree` into its `Size` and root node.
+fn term_try_into_size_and_root(tree: Term) -> exception::Result<(usize, Term)> {
+    let size_and_root = match tree.decode()? {
+        TypedTerm::Tuple(tuple) if tuple.len() == 2 => {
+            tuple[0].try_into().ok().map(|size: usize| (size, tuple[1]))
+        }
+        _ => None,
+    };
+
+    size_and_root
+        .with_context(|| format!("tree ({}) is not a gb_tree", tree))
+        .map_err(From::from)
+}
+
+/// The `{Key, Value}` pairs of the nodes under `node` in order.
+fn node_to_pairs(tree: Term, node: Term, pairs: &mut Vec<(Term, Term)>) -> exception::Result<()> {
+    if let Some(Node {
+        key,
+        value,
+        smaller,
+        bigger,
+    }) = term_try_into_node(tree, node)?
+    {
+        node_to_pairs(tree, smaller, pairs)?;
+        pairs.push((key, value));
+        node_to_pairs(tree, bigger, pairs)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,8 @@
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns a tree without any keys.
+#[native_implemented::function(gb_trees:empty/0)]
+pub fn result(process: &Process) -> Term {
+    process.tuple_from_slice(&[process.integer(0), super::nil()])
+}
@@ -0,0 +1,196 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::cmp::Ordering;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception::{self, error};
+use liblumen_alloc::erts::process::trace::Trace;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::Node;
+
+/// Returns `tree` with `key` mapped to `value`.  Errors with `{key_exists, Key}` if `tree` already
+/// has a key that compares equal to `key`.
+///
+/// Like C-BEAM, the tree is not rebalanced on every insert: it is only rebalanced once the path to
+/// the new key is longer than `2 * log2(Size)`, so that trees built by native and Erlang code have
+/// the same shape.
+#[native_implemented::function(gb_trees:insert/3)]
+pub fn result(process: &Process, key: Term, value: Term, tree: Term) -> exception::Result<Term> {
+    let (size, root) = super::term_try_into_size_and_root(tree)?;
+    let inserted_size = size + 1;
+    let inserted = insert(
+        process,
+        tree,
+        key,
+        value,
+        root,
+        inserted_size.saturating_mul(inserted_size),
+    )?;
+
+    Ok(process.tuple_from_slice(&[process.integer(inserted_size), inserted.node()]))
+}
+
+// Private
+
+enum Inserted {
+    /// The `height`, which doubles at each level, and `size` of `node` are tracked while the path
+    /// to the new key may be too long
+    Tracked {
+        node: Term,
+        height: usize,
+        size: usize,
+    },
+    /// `node` is short enough, or has been rebalanced, so its ancestors don't need rebalancing
+    Balanced(Term),
+}
+
+impl Inserted {
+    fn node(&self) -> Term {
+        match self {
+            Inserted::Tracked { node, .. } => *node,
+            Inserted::Balanced(node) => *node,
+        }
+    }
+}
+
+/// `max_height` is halved at each level, so that it is `0` once the path is too long
+fn insert(
+    process: &Process,
+    tree: Term,
+    key: Term,
+    value: Term,
+    node: Term,
+    max_height: usize,
+) -> exception::Result<Inserted> {
+    match super::term_try_into_node(tree, node)? {
+        None => {
+            let leaf = Node {
+                key,
+                value,
+                smaller: super::nil(),
+                bigger: super::nil(),
+            }
+            .to_term(process);
+
+            if max_height == 0 {
+                Ok(Inserted::Tracked {
+                    node: leaf,
+                    height: 1,
+                    size: 1,
+                })
+            } else {
+                Ok(Inserted::Balanced(leaf))
+            }
+        }
+        Some(parent) => {
+            let is_smaller = match key.cmp(&parent.key) {
+                Ordering::Less => true,
+                Ordering::Greater => false,
+                Ordering::Equal => {
+                    return Err(error(
+                        process.tuple_from_slice(&[Atom::str_to_term("key_exists"), key]),
+                        None,
+                        Trace::capture(),
+                        Some(anyhow!("tree ({}) already has key ({})", tree, key).into()),
+                    )
+                    .into())
+                }
+            };
+            let (child, sibling) = if is_smaller {
+                (parent.smaller, parent.bigger)
+            } else {
+                (parent.bigger, parent.smaller)
+            };
+            let inserted_child = insert(process, tree, key, value, child, max_height >> 1)?;
+            let inserted_parent = if is_smaller {
+                Node {
+                    smaller: inserted_child.node(),
+                    ..parent
+                }
+            } else {
+                Node {
+                    bigger: inserted_child.node(),
+                    ..parent
+                }
+            }
+            .to_term(process);
+
+            match inserted_child {
+                Inserted::Tracked {
+                    height: child_height,
+                    size: child_size,
+                    ..
+                } => {
+                    let (sibling_height, sibling_size) = count(tree, sibling)?;
+                    let height = child_height.max(sibling_height) << 1;
+                    let size = child_size + sibling_size + 1;
+
+                    if height > size.saturating_mul(size) {
+                        balance(process, tree, inserted_parent, size).map(Inserted::Balanced)
+                    } else {
+                        Ok(Inserted::Tracked {
+                            node: inserted_parent,
+                            height,
+                            size,
+                        })
+                    }
+                }
+                Inserted::Balanced(_) => Ok(Inserted::Balanced(inserted_parent)),
+            }
+        }
+    }
+}
+
+/// The height, which doubles at each level, and size of `node`
+fn count(tree: Term, node: Term) -> exception::Result<(usize, usize)> {
+    match super::term_try_into_node(tree, node)? {
+        None => Ok((1, 0)),
+        Some(Node {
+            smaller, bigger, ..
+        }) => {
+            let (smaller_height, smaller_size) = count(tree, smaller)?;
+            let (bigger_height, bigger_size) = count(tree, bigger)?;
+
+            if smaller_size == 0 && bigger_size == 0 {
+                Ok((1, 1))
+            } else {
+                Ok((
+                    smaller_height.max(bigger_height) << 1,
+                    smaller_size + bigger_size + 1,
+                ))
+            }
+        }
+    }
+}
+
+fn balance(process: &Process, tree: Term, node: Term, size: usize) -> exception::Result<Term> {
+    let mut pairs = Vec::with_capacity(size);
+    super::node_to_pairs(tree, node, &mut pairs)?;
+
+    Ok(balanced_node(process, &pairs))
+}
+
+/// Builds the node for `pairs` with the median as the root.  When `pairs` can't be split evenly,
+/// the smaller half gets the extra pair, as in C-BEAM.
+fn balanced_node(process: &Process, pairs: &[(Term, Term)]) -> Term {
+    match pairs.len() {
+        0 => super::nil(),
+        len => {
+            let without_root = len - 1;
+            let smaller_len = without_root - without_root / 2;
+            let (key, value) = pairs[smaller_len];
+
+            Node {
+                key,
+                value,
+                smaller: balanced_node(process, &pairs[..smaller_len]),
+                bigger: balanced_node(process, &pairs[smaller_len + 1..]),
+            }
+            .to_term(process)
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::gb_trees::{empty_0, insert_3, to_list_1};
+use crate::test::with_process;
+
+#[test]
+fn without_tree_errors_badarg() {
+    with_process(|process| {
+        let tree = Atom::str_to_term("tree");
+
+        assert_badarg!(
+            insert_3::result(process, process.integer(1), process.integer(2), tree),
+            format!("tree ({}) is not a gb_tree", tree)
+        );
+    });
+}
+
+#[test]
+fn with_out_of_order_keys_to_list_is_in_order() {
+    with_process(|process| {
+        let keys = [5, 3, 8, 1, 4, 7, 9, 2, 6, 10];
+        let mut tree = empty_0::result(process);
+
+        for key in keys.iter() {
+            tree = insert_3::result(
+                process,
+                process.integer(*key),
+                process.integer(key * 10),
+                tree,
+            )
+            .unwrap();
+        }
+
+        let pairs: Vec<Term> = (1..=10)
+            .map(|key| process.tuple_from_slice(&[process.integer(key), process.integer(key * 10)]))
+            .collect();
+
+        assert_eq!(
+            to_list_1::result(process, tree),
+            Ok(process.list_from_slice(&pairs))
+        );
+    });
+}
+
+#[test]
+fn with_ascending_keys_rebalances_once_path_is_too_long() {
+    with_process(|process| {
+        let nil = Atom::str_to_term("nil");
+        let node = |key, smaller, bigger| {
+            process.tuple_from_slice(&[process.integer(key), Term::NIL, smaller, bigger])
+        };
+        let mut tree = empty_0::result(process);
+
+        for key in 1..=6 {
+            tree = insert_3::result(process, process.integer(key), Term::NIL, tree).unwrap();
+        }
+
+        // With 6 keys, a path of 6 nodes is not too long
+        assert_eq!(
+            tree,
+            process.tuple_from_slice(&[
+                process.integer(6),
+                node(
+                    1,
+                    nil,
+                    node(
+                        2,
+                        nil,
+                        node(3, nil, node(4, nil, node(5, nil, node(6, nil, nil))))
+                    )
+                )
+            ])
+        );
+
+        tree = insert_3::result(process, process.integer(7), Term::NIL, tree).unwrap();
+
+        assert_eq!(
+            tree,
+            process.tuple_from_slice(&[
+                process.integer(7),
+                node(
+                    4,
+                    node(2, node(1, nil, nil), node(3, nil, nil)),
+                    node(6, node(5, nil, nil), node(7, nil, nil))
+                )
+            ])
+        );
+    });
+}
+
+#[test]
+fn with_existing_key_errors_key_exists() {
+    with_process(|process| {
+        let key = process.integer(1);
+        let tree =
+            insert_3::result(process, key, process.integer(2), empty_0::result(process)).unwrap();
+
+        assert_error!(
+            insert_3::result(process, key, process.integer(3), tree),
+            process.tuple_from_slice(&[Atom::str_to_term("key_exists"), key])
+        );
+    });
+}
@@ -0,0 +1,33 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::cmp::Ordering;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `{value, Value}` for the key in `tree` that compares equal to `key`, or `none` if there
+/// is no such key.
+#[native_implemented::function(gb_trees:lookup/2)]
+pub fn result(process: &Process, key: Term, tree: Term) -> exception::Result<Term> {
+    let (_, mut node) = super::term_try_into_size_and_root(tree)?;
+
+    while let Some(super::Node {
+        key: node_key,
+        value,
+        smaller,
+        bigger,
+    }) = super::term_try_into_node(tree, node)?
+    {
+        node = match key.cmp(&node_key) {
+            Ordering::Less => smaller,
+            Ordering::Greater => bigger,
+            Ordering::Equal => {
+                return Ok(process.tuple_from_slice(&[Atom::str_to_term("value"), value]))
+            }
+        };
+    }
+
+    Ok(Atom::str_to_term("none"))
+}
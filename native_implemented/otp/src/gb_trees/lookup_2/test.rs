@@ -0,0 +1,36 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::gb_trees::{empty_0, insert_3, lookup_2};
+use crate::test::with_process;
+
+#[test]
+fn with_key_returns_value() {
+    with_process(|process| {
+        let mut tree = empty_0::result(process);
+
+        for key in [2, 1, 3].iter() {
+            tree = insert_3::result(
+                process,
+                process.integer(*key),
+                process.integer(key * 10),
+                tree,
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            lookup_2::result(process, process.integer(3), tree),
+            Ok(process.tuple_from_slice(&[Atom::str_to_term("value"), process.integer(30)]))
+        );
+    });
+}
+
+#[test]
+fn without_key_returns_none() {
+    with_process(|process| {
+        assert_eq!(
+            lookup_2::result(process, process.integer(1), empty_0::result(process)),
+            Ok(Atom::str_to_term("none"))
+        );
+    });
+}
@@ -0,0 +1,21 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns the `{Key, Value}` pairs of `tree` in key order.
+#[native_implemented::function(gb_trees:to_list/1)]
+pub fn result(process: &Process, tree: Term) -> exception::Result<Term> {
+    let (_, root) = super::term_try_into_size_and_root(tree)?;
+    let mut pairs = Vec::new();
+    super::node_to_pairs(tree, root, &mut pairs)?;
+
+    let pair_terms: Vec<Term> = pairs
+        .into_iter()
+        .map(|(key, value)| process.tuple_from_slice(&[key, value]))
+        .collect();
+
+    Ok(process.list_from_slice(&pair_terms))
+}
@@ -0,0 +1,26 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::gb_trees::{empty_0, to_list_1};
+use crate::test::with_process;
+
+#[test]
+fn without_tree_errors_badarg() {
+    with_process(|process| {
+        let tree = process.tuple_from_slice(&[process.integer(1), Atom::str_to_term("node")]);
+
+        assert_badarg!(
+            to_list_1::result(process, tree),
+            format!("tree ({}) is not a gb_tree", tree)
+        );
+    });
+}
+
+#[test]
+fn with_empty_tree_returns_empty_list() {
+    with_process(|process| {
+        assert_eq!(
+            to_list_1::result(process, empty_0::result(process)),
+            Ok(Term::NIL)
+        );
+    });
+}
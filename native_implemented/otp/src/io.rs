@@ -0,0 +1,8 @@
+pub mod format_1;
+pub mod format_2;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::from_str("io")
+}
@@ -0,0 +1,13 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Prints `format` to the group leader of `process`, which may only use the control sequences
+/// that take no arguments: `~n` and `~~`.
+#[native_implemented::function(io:format/1)]
+pub fn result(process: &Process, format: Term) -> exception::Result<Term> {
+    super::format_2::result(process, format, Term::NIL)
+}
@@ -0,0 +1,29 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::io::format_1::result;
+use crate::test::with_process;
+
+#[test]
+fn with_control_sequence_that_takes_argument_errors_badarg() {
+    with_process(|process| {
+        let format = process.charlist_from_str("~p~n");
+
+        assert_badarg!(
+            result(process, format),
+            format!(
+                "format ({}) has more control sequences than arguments ([])",
+                format
+            )
+        );
+    });
+}
+
+#[test]
+fn with_control_sequences_without_arguments_returns_ok() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.charlist_from_str("~~~n")),
+            Ok(Atom::str_to_term("ok"))
+        );
+    });
+}
@@ -0,0 +1,71 @@
+//! ```elixir
+//! def format(format, arguments) do
+//!   chars = :io_lib.format(format, arguments)
+//!   group_leader = group_leader()
+//!   reply_as = monitor(:process, group_leader)
+//!   send(group_leader, {:io_request, self(), reply_as, {:put_chars, :unicode, chars}})
+//!   wait_for_io_reply(reply_as)
+//! end
+//! ```
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::io_lib::format;
+use crate::runtime::process::monitor;
+use crate::runtime::registry::pid_to_process;
+use crate::runtime::scheduler::SchedulerDependentAlloc;
+use crate::runtime::sys::io::print;
+
+/// Prints `arguments` formatted according to the control sequences in `format` to the group
+/// leader of `process`.
+///
+/// `init` is its own group leader and stands in for the standard io server, so its output goes
+/// straight to standard output.  Any other group leader is sent an io protocol `put_chars`
+/// request, and `ok` is only returned once its `io_reply` arrives.
+#[native_implemented::function(io:format/2)]
+pub fn result(process: &Process, format: Term, arguments: Term) -> exception::Result<Term> {
+    let string = format::format(format, arguments)?;
+    let group_leader_pid = process.get_group_leader_pid();
+
+    match pid_to_process(&group_leader_pid) {
+        Some(group_leader_arc_process)
+            if group_leader_arc_process.get_group_leader_pid() != group_leader_pid =>
+        {
+            let request = process.tuple_from_slice(&[
+                atom!("put_chars"),
+                atom!("unicode"),
+                process.binary_from_str(&string),
+            ]);
+            let reply_as = monitor(process, &group_leader_arc_process);
+            let message = process.tuple_from_slice(&[
+                atom!("io_request"),
+                process.pid_term(),
+                reply_as,
+                request,
+            ]);
+
+            group_leader_arc_process.send_from_other(message);
+            group_leader_arc_process
+                .scheduler()
+                .unwrap()
+                .stop_waiting(&group_leader_arc_process);
+
+            process.queue_frame_with_arguments(label_1::frame().with_arguments(false, &[reply_as]));
+
+            Ok(Term::NONE)
+        }
+        _ => {
+            print(&string);
+
+            Ok(atom!("ok"))
+        }
+    }
+}
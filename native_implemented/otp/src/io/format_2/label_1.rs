@@ -0,0 +1,139 @@
+//! ```elixir
+//! # label 1
+//! # pushed to stack: (reply_as)
+//! # returned from call: N/A
+//! # full stack: (reply_as)
+//! # returns: :ok
+//! receive do
+//!   {:io_reply, ^reply_as, :ok} ->
+//!     demonitor(reply_as, [:flush])
+//!     :ok
+//!   {:io_reply, ^reply_as, {:error, reason}} ->
+//!     demonitor(reply_as, [:flush])
+//!     :erlang.error(conv_reason(reason))
+//!   {:DOWN, ^reply_as, _, _, _} ->
+//!     :erlang.error(:terminated)
+//! end
+//! ```
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception::{self, error};
+use liblumen_alloc::erts::process::trace::Trace;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::erts::Message;
+
+use crate::erlang::demonitor_2::demonitor;
+use crate::erlang::demonitor_2::options::Options;
+use crate::runtime::process::monitor::is_down;
+
+// Private
+
+#[native_implemented::label]
+fn result(process: &Process, reply_as: Term) -> exception::Result<Term> {
+    let reply_as_reference: Boxed<Reference> = reply_as.try_into().unwrap();
+
+    let reply = {
+        let mailbox_guard = process.mailbox.lock();
+        let mut mailbox = mailbox_guard.borrow_mut();
+        let found = mailbox.iter().find_map(|message| {
+            received(message, &reply_as_reference).map(|reply| (message as *const Message, reply))
+        });
+
+        found.map(|(message, reply)| {
+            mailbox.remove(message);
+
+            reply
+        })
+    };
+
+    match reply {
+        Some(Reply::Down) => Err(io_error("terminated")),
+        Some(reply) => {
+            demonitor(
+                process,
+                &reply_as_reference,
+                Options {
+                    flush: true,
+                    info: false,
+                },
+            )?;
+
+            match reply {
+                Reply::Error(reason) => Err(io_error(reason)),
+                _ => Ok(atom!("ok")),
+            }
+        }
+        None => {
+            process.wait();
+            process.queue_frame_with_arguments(frame().with_arguments(false, &[reply_as]));
+
+            Ok(Term::NONE)
+        }
+    }
+}
+
+enum Reply {
+    Ok,
+    Error(&'static str),
+    Down,
+}
+
+/// Matches the `io_reply` or monitor `DOWN` message for `reply_as`.  Only the reply's atoms are
+/// kept, so nothing points into the message once it is removed from the mailbox.
+fn received(message: &Message, reply_as: &Reference) -> Option<Reply> {
+    if is_down(message, reply_as) {
+        return Some(Reply::Down);
+    }
+
+    let tuple: Boxed<Tuple> = message.data().try_into().ok()?;
+
+    if tuple.len() != 3 || tuple[0] != atom!("io_reply") {
+        return None;
+    }
+
+    let message_reply_as: Boxed<Reference> = tuple[1].try_into().ok()?;
+
+    if message_reply_as.as_ref() != reply_as {
+        return None;
+    }
+
+    let reply = tuple[2];
+
+    if reply == atom!("ok") {
+        Some(Reply::Ok)
+    } else {
+        let result_reply_tuple: Result<Boxed<Tuple>, _> = reply.try_into();
+        let reason = match result_reply_tuple {
+            Ok(reply_tuple) if reply_tuple.len() == 2 && reply_tuple[0] == atom!("error") => {
+                Some(reply_tuple[1])
+            }
+            _ => None,
+        };
+
+        Some(Reply::Error(conv_reason(reason)))
+    }
+}
+
+/// Maps the io server's error reason to the reason `io:format/2` raises, as OTP's `io` does.
+fn conv_reason(reason: Option<Term>) -> &'static str {
+    match reason {
+        Some(reason) if reason == atom!("terminated") => "terminated",
+        Some(reason) if reason == atom!("no_translation") => "no_translation",
+        _ => "badarg",
+    }
+}
+
+fn io_error(reason: &str) -> exception::Exception {
+    error(
+        Atom::str_to_term(reason),
+        None,
+        Trace::capture(),
+        Some(anyhow!("group leader replied to io request with error ({})", reason).into()),
+    )
+    .into()
+}
@@ -0,0 +1,79 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::io::format_2::result;
+use crate::test::{self, receive_message, with_process};
+
+#[test]
+fn without_list_arguments_errors_badarg() {
+    with_process(|process| {
+        let arguments = Atom::str_to_term("a");
+
+        assert_badarg!(
+            result(process, process.charlist_from_str("~w"), arguments),
+            format!("arguments ({}) is not a list", arguments)
+        );
+    });
+}
+
+#[test]
+fn with_unsupported_control_sequence_errors_badarg() {
+    with_process(|process| {
+        let format_term = process.charlist_from_str("~z");
+
+        assert_badarg!(
+            result(
+                process,
+                format_term,
+                process.list_from_slice(&[process.integer(1)])
+            ),
+            format!(
+                "format ({}) control sequence (~z) is not supported",
+                format_term
+            )
+        );
+    });
+}
+
+#[test]
+fn with_more_arguments_than_control_sequences_errors_badarg() {
+    with_process(|process| {
+        assert!(result(
+            process,
+            process.charlist_from_str("~n"),
+            process.list_from_slice(&[process.integer(1)])
+        )
+        .is_err());
+    });
+}
+
+#[test]
+fn with_other_group_leader_sends_put_chars_io_request() {
+    with_process(|process| {
+        let group_leader_arc_process = test::process::child(process);
+        process.set_group_leader_pid(group_leader_arc_process.pid());
+
+        assert_eq!(
+            result(
+                process,
+                process.charlist_from_str("~w~n"),
+                process.list_from_slice(&[process.integer(1)])
+            ),
+            Ok(Term::NONE)
+        );
+
+        let message = receive_message(&group_leader_arc_process).unwrap();
+        let message_tuple: Boxed<Tuple> = message.try_into().unwrap();
+
+        assert_eq!(message_tuple.len(), 4);
+        assert_eq!(message_tuple[0], Atom::str_to_term("io_request"));
+        assert_eq!(message_tuple[1], process.pid_term());
+        assert_eq!(
+            message_tuple[3],
+            process.tuple_from_slice(&[
+                Atom::str_to_term("put_chars"),
+                Atom::str_to_term("unicode"),
+                process.binary_from_str("1\n"),
+            ])
+        );
+    });
+}
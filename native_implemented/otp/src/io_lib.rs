@@ -0,0 +1,9 @@
+pub(crate) mod format;
+
+pub mod format_2;
+
+use liblumen_alloc::erts::term::prelude::Atom;
+
+fn module() -> Atom {
+    Atom::from_str("io_lib")
+}
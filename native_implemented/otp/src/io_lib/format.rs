@@ -0,0 +1,396 @@
+//! The control sequence engine shared by `io:format` and `io_lib:format`.
+//!
+//! Only the control sequences without field width, precision, or padding are supported:
+//!
+//! * `~p` - pretty-prints the next argument, printing printable lists as strings and breaking
+//!   terms that do not fit on the line over multiple lines.
+//! * `~w` - writes the next argument in standard syntax on a single line.
+//! * `~s` - prints the next argument, which must be a string, binary, or atom, without quotes.
+//! * `~b` - prints the next argument, which must be an integer, in base 10.
+//! * `~n` - writes a newline.
+//! * `~~` - writes a literal `~`.
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::list_to_string::list_to_string;
+use crate::runtime::binary_to_string::binary_to_string;
+use crate::runtime::context::term_is_not_type;
+use crate::runtime::distribution::nodes::node;
+
+/// Formats `arguments` according to the control sequences in `format`.
+pub(crate) fn format(format: Term, arguments: Term) -> exception::Result<String> {
+    let format_string = format_to_string(format)?;
+    let mut argument_iter = arguments_to_vec(arguments)?.into_iter();
+    let mut string = String::new();
+    let mut chars = format_string.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '~' {
+            string.push(c);
+
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => string.push('\n'),
+            Some('~') => string.push('~'),
+            Some(control) => {
+                let argument = argument_iter.next().with_context(|| {
+                    format!(
+                        "format ({}) has more control sequences than arguments ({})",
+                        format, arguments
+                    )
+                })?;
+
+                match control {
+                    'b' => write_integer(argument, &mut string)?,
+                    'p' => {
+                        let column = column(&string);
+
+                        pretty(argument, column, &mut string)?
+                    }
+                    's' => write_chars(argument, &mut string)?,
+                    'w' => write(argument, false, &mut string)?,
+                    _ => {
+                        return Err(anyhow!(
+                            "format ({}) control sequence (~{}) is not supported",
+                            format,
+                            control
+                        )
+                        .into())
+                    }
+                }
+            }
+            None => {
+                return Err(
+                    anyhow!("format ({}) ends in an incomplete control sequence", format).into(),
+                )
+            }
+        }
+    }
+
+    if argument_iter.next().is_some() {
+        Err(anyhow!(
+            "format ({}) has fewer control sequences than arguments ({})",
+            format,
+            arguments
+        )
+        .into())
+    } else {
+        Ok(string)
+    }
+}
+
+// Private
+
+const LINE_LENGTH: usize = 80;
+
+const RESERVED_WORDS: &[&str] = &[
+    "after", "and", "andalso", "band", "begin", "bnot", "bor", "bsl", "bsr", "bxor", "case",
+    "catch", "cond", "div", "end", "fun", "if", "let", "not", "of", "or", "orelse", "receive",
+    "rem", "try", "when", "xor",
+];
+
+fn arguments_to_vec(arguments: Term) -> exception::Result<Vec<Term>> {
+    match arguments.decode()? {
+        TypedTerm::Nil => Ok(Vec::new()),
+        TypedTerm::List(cons) => cons
+            .into_iter()
+            .map(|result| {
+                result
+                    .map_err(|_| ImproperListError)
+                    .with_context(|| format!("arguments ({}) is improper", arguments))
+                    .map_err(From::from)
+            })
+            .collect(),
+        _ => Err(TypeError)
+            .context(term_is_not_type("arguments", arguments, "a list"))
+            .map_err(From::from),
+    }
+}
+
+fn column(string: &str) -> usize {
+    match string.rfind('\n') {
+        Some(index) => string[index + 1..].chars().count(),
+        None => string.chars().count(),
+    }
+}
+
+fn format_to_string(format: Term) -> exception::Result<String> {
+    match format.decode()? {
+        TypedTerm::Atom(atom) => Ok(atom.name().to_owned()),
+        TypedTerm::Nil | TypedTerm::List(_) => list_to_string(format),
+        _ if format.is_binary() => binary_to_string(format),
+        _ => Err(TypeError)
+            .context(term_is_not_type(
+                "format",
+                format,
+                "a string, binary, or atom",
+            ))
+            .map_err(From::from),
+    }
+}
+
+fn is_printable_char(c: char) -> bool {
+    matches!(c, ' '..='~' | '\u{A0}'..='\u{FF}' | '\n' | '\r' | '\t' | '\u{B}' | '\u{8}' | '\u{C}' | '\u{1B}')
+}
+
+/// Returns the characters of `cons` if it is a proper list of only printable characters.
+fn printable_string(cons: &Cons) -> Option<String> {
+    cons.into_iter()
+        .map(|result| {
+            result
+                .ok()
+                .and_then(|element| element.try_into().ok())
+                .filter(|c: &char| is_printable_char(*c))
+        })
+        .collect()
+}
+
+fn pretty(term: Term, column: usize, string: &mut String) -> exception::Result<()> {
+    let mut flat = String::new();
+    write(term, true, &mut flat)?;
+
+    if column + flat.chars().count() <= LINE_LENGTH {
+        string.push_str(&flat);
+
+        return Ok(());
+    }
+
+    match term.decode()? {
+        TypedTerm::Tuple(tuple) => {
+            string.push('{');
+            pretty_elements(tuple.iter().copied(), column + 1, string)?;
+            string.push('}');
+        }
+        TypedTerm::List(cons) if printable_string(&cons).is_none() => {
+            let mut elements = Vec::new();
+            let mut tail = Term::NIL;
+
+            for result in cons.into_iter() {
+                match result {
+                    Ok(element) => elements.push(element),
+                    Err(ImproperList {
+                        tail: improper_tail,
+                    }) => tail = improper_tail,
+                }
+            }
+
+            string.push('[');
+            pretty_elements(elements.into_iter(), column + 1, string)?;
+
+            if !tail.is_nil() {
+                string.push('|');
+                let column = self::column(string);
+                pretty(tail, column, string)?;
+            }
+
+            string.push(']');
+        }
+        TypedTerm::Map(map) => {
+            string.push_str("#{");
+
+            for (index, (key, value)) in sorted_entries(&map).into_iter().enumerate() {
+                if 0 < index {
+                    string.push_str(",\n");
+                    string.extend(std::iter::repeat(' ').take(column + 2));
+                }
+
+                write(key, true, string)?;
+                string.push_str(" => ");
+                let column = self::column(string);
+                pretty(value, column, string)?;
+            }
+
+            string.push('}');
+        }
+        _ => string.push_str(&flat),
+    }
+
+    Ok(())
+}
+
+/// Pretty-prints each element on its own line, aligned to `column`.
+fn pretty_elements(
+    elements: impl Iterator<Item = Term>,
+    column: usize,
+    string: &mut String,
+) -> exception::Result<()> {
+    for (index, element) in elements.enumerate() {
+        if 0 < index {
+            string.push_str(",\n");
+            string.extend(std::iter::repeat(' ').take(column));
+        }
+
+        pretty(element, column, string)?;
+    }
+
+    Ok(())
+}
+
+fn sorted_entries(map: &Map) -> Vec<(Term, Term)> {
+    let mut entries: Vec<(Term, Term)> = map.iter().map(|(key, value)| (*key, *value)).collect();
+    entries.sort_unstable_by(|(key1, _), (key2, _)| key1.cmp(key2));
+
+    entries
+}
+
+/// Writes `term` in standard syntax on a single line.  When `printable_lists` is set, as it is for
+/// `~p`, lists of printable characters are written as strings.
+fn write(term: Term, printable_lists: bool, string: &mut String) -> exception::Result<()> {
+    match term.decode()? {
+        TypedTerm::Atom(atom) => write_atom(atom, string),
+        TypedTerm::Nil => string.push_str("[]"),
+        TypedTerm::List(cons) => match printable_string(&cons).filter(|_| printable_lists) {
+            Some(chars) => write_quoted(&chars, string),
+            None => {
+                string.push('[');
+
+                for (index, result) in cons.into_iter().enumerate() {
+                    match result {
+                        Ok(element) => {
+                            if 0 < index {
+                                string.push(',');
+                            }
+
+                            write(element, printable_lists, string)?;
+                        }
+                        Err(ImproperList { tail }) => {
+                            string.push('|');
+                            write(tail, printable_lists, string)?;
+                        }
+                    }
+                }
+
+                string.push(']');
+            }
+        },
+        TypedTerm::Tuple(tuple) => {
+            string.push('{');
+
+            for (index, element) in tuple.iter().enumerate() {
+                if 0 < index {
+                    string.push(',');
+                }
+
+                write(*element, printable_lists, string)?;
+            }
+
+            string.push('}');
+        }
+        TypedTerm::Map(map) => {
+            let separator = if printable_lists { " => " } else { "=>" };
+            string.push_str("#{");
+
+            for (index, (key, value)) in sorted_entries(&map).into_iter().enumerate() {
+                if 0 < index {
+                    string.push(',');
+                }
+
+                write(key, printable_lists, string)?;
+                string.push_str(separator);
+                write(value, printable_lists, string)?;
+            }
+
+            string.push('}');
+        }
+        TypedTerm::Pid(pid) => string.push_str(&format!(
+            "<{}.{}.{}>",
+            node::id(),
+            pid.number(),
+            pid.serial()
+        )),
+        TypedTerm::ExternalPid(external_pid) => string.push_str(&format!(
+            "<{}.{}.{}>",
+            external_pid.arc_node().id(),
+            external_pid.number(),
+            external_pid.serial()
+        )),
+        TypedTerm::Closure(closure) => {
+            let module_function_arity = closure.module_function_arity();
+
+            string.push_str(&format!(
+                "#Fun<{}.{}.{}>",
+                module_function_arity.module,
+                module_function_arity.function,
+                module_function_arity.arity
+            ))
+        }
+        typed_term => string.push_str(&typed_term.to_string()),
+    }
+
+    Ok(())
+}
+
+/// Writes `atom` as `io_lib:write_atom/1` does: quoted when it does not start with a lowercase
+/// letter, contains characters other than alphanumerics and `_`, or is a reserved word.
+fn write_atom(atom: Atom, string: &mut String) {
+    let name = atom.name();
+
+    if RESERVED_WORDS.contains(&name) {
+        string.push('\'');
+        string.push_str(name);
+        string.push('\'');
+    } else {
+        string.push_str(&atom.to_string());
+    }
+}
+
+fn write_chars(chars: Term, string: &mut String) -> exception::Result<()> {
+    match chars.decode()? {
+        TypedTerm::Atom(atom) => string.push_str(atom.name()),
+        TypedTerm::Nil | TypedTerm::List(_) => string.push_str(&list_to_string(chars)?),
+        _ if chars.is_binary() => string.push_str(&binary_to_string(chars)?),
+        _ => {
+            return Err(TypeError)
+                .context(term_is_not_type(
+                    "~s argument",
+                    chars,
+                    "a string, binary, or atom",
+                ))
+                .map_err(From::from)
+        }
+    }
+
+    Ok(())
+}
+
+fn write_integer(integer: Term, string: &mut String) -> exception::Result<()> {
+    match integer.decode()? {
+        TypedTerm::SmallInteger(small_integer) => string.push_str(&small_integer.to_string()),
+        TypedTerm::BigInteger(big_integer) => string.push_str(&big_integer.to_string()),
+        _ => {
+            return Err(TypeError)
+                .context(term_is_not_type("~b argument", integer, "an integer"))
+                .map_err(From::from)
+        }
+    }
+
+    Ok(())
+}
+
+fn write_quoted(chars: &str, string: &mut String) {
+    string.push('"');
+
+    for c in chars.chars() {
+        match c {
+            '"' => string.push_str("\\\""),
+            '\\' => string.push_str("\\\\"),
+            '\n' => string.push_str("\\n"),
+            '\r' => string.push_str("\\r"),
+            '\t' => string.push_str("\\t"),
+            '\u{B}' => string.push_str("\\v"),
+            '\u{8}' => string.push_str("\\b"),
+            '\u{C}' => string.push_str("\\f"),
+            '\u{1B}' => string.push_str("\\e"),
+            _ => string.push(c),
+        }
+    }
+
+    string.push('"');
+}
@@ -0,0 +1,17 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::io_lib::format;
+
+/// Returns `arguments` formatted according to the control sequences in `format` as a charlist,
+/// using the same control sequences as `io:format/2`.
+#[native_implemented::function(io_lib:format/2)]
+pub fn result(process: &Process, format: Term, arguments: Term) -> exception::Result<Term> {
+    let string = format::format(format, arguments)?;
+
+    Ok(process.charlist_from_str(&string))
+}
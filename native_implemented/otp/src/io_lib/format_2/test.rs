@@ -0,0 +1,147 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::io_lib::format_2::result;
+use crate::test::with_process;
+
+#[test]
+fn with_w_writes_printable_list_as_list() {
+    with_process(|process| {
+        let arguments = process.list_from_slice(&[process.charlist_from_str("ab")]);
+
+        assert_eq!(
+            result(process, process.charlist_from_str("~w"), arguments),
+            Ok(process.charlist_from_str("[97,98]"))
+        );
+    });
+}
+
+#[test]
+fn with_p_writes_printable_list_as_string() {
+    with_process(|process| {
+        let arguments = process.list_from_slice(&[process.charlist_from_str("a\"b")]);
+
+        assert_eq!(
+            result(process, process.charlist_from_str("~p"), arguments),
+            Ok(process.charlist_from_str("\"a\\\"b\""))
+        );
+    });
+}
+
+#[test]
+fn with_p_writes_nested_tuple_without_spaces() {
+    with_process(|process| {
+        let tuple = process.tuple_from_slice(&[
+            Atom::str_to_term("a"),
+            process.list_from_slice(&[process.integer(1), process.integer(2)]),
+        ]);
+
+        assert_eq!(
+            result(
+                process,
+                process.charlist_from_str("~p~n"),
+                process.list_from_slice(&[tuple])
+            ),
+            Ok(process.charlist_from_str("{a,[1,2]}\n"))
+        );
+    });
+}
+
+#[test]
+fn with_p_breaks_terms_longer_than_line() {
+    with_process(|process| {
+        let element = process.charlist_from_str(&"x".repeat(50));
+        let tuple = process.tuple_from_slice(&[element, element]);
+
+        let line = format!("\"{}\"", "x".repeat(50));
+
+        assert_eq!(
+            result(
+                process,
+                process.charlist_from_str("~p"),
+                process.list_from_slice(&[tuple])
+            ),
+            Ok(process.charlist_from_str(&format!("{{{},\n {}}}", line, line)))
+        );
+    });
+}
+
+#[test]
+fn with_s_writes_chars_without_quotes() {
+    with_process(|process| {
+        let arguments = process.list_from_slice(&[
+            process.charlist_from_str("list"),
+            process.binary_from_str("binary"),
+            Atom::str_to_term("atom"),
+        ]);
+
+        assert_eq!(
+            result(process, process.charlist_from_str("~s ~s ~s"), arguments),
+            Ok(process.charlist_from_str("list binary atom"))
+        );
+    });
+}
+
+#[test]
+fn with_b_writes_integer_in_base_10() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("~b~~"),
+                process.list_from_slice(&[process.integer(-255)])
+            ),
+            Ok(process.charlist_from_str("-255~"))
+        );
+    });
+}
+
+#[test]
+fn with_b_without_integer_errors_badarg() {
+    with_process(|process| {
+        let float = process.float(1.0);
+
+        assert_badarg!(
+            result(
+                process,
+                process.charlist_from_str("~b"),
+                process.list_from_slice(&[float])
+            ),
+            format!("~b argument ({}) is not an integer", float)
+        );
+    });
+}
+
+#[test]
+fn with_b_returns_charlist() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.charlist_from_str("~b"),
+                process.list_from_slice(&[process.integer(255)])
+            ),
+            Ok(process.charlist_from_str("255"))
+        );
+    });
+}
+
+#[test]
+fn with_w_quotes_atoms_that_need_quotes() {
+    with_process(|process| {
+        let atoms = process.list_from_slice(&[
+            Atom::str_to_term("ok"),
+            Atom::str_to_term("Foo"),
+            Atom::str_to_term("hello world"),
+            Atom::str_to_term("case"),
+        ]);
+
+        assert_eq!(
+            result(
+                process,
+                process.charlist_from_str("~w"),
+                process.list_from_slice(&[atoms])
+            ),
+            Ok(process.charlist_from_str("[ok,'Foo','hello world','case']"))
+        );
+    });
+}
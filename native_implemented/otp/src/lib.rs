@@ -8,15 +8,25 @@ mod macros;
 
 pub mod binary;
 pub mod erlang;
+pub mod ets;
+pub mod gb_trees;
+pub mod io;
+pub mod io_lib;
 pub mod lists;
 pub mod lumen;
 pub mod maps;
+pub mod math;
 pub mod number;
+pub mod orddict;
+pub mod proplists;
+pub mod sets;
 #[cfg(not(test))]
 use lumen_rt_core as runtime;
 #[cfg(test)]
 use lumen_rt_full as runtime;
+pub mod string;
 pub mod timer;
+pub mod unicode;
 
 #[cfg(test)]
 mod test;
@@ -1,13 +1,188 @@
 //! Mirrors [lists](http://erlang.org/doc/man/lists.html) module
 
+pub mod all_2;
+pub mod any_2;
+pub mod concat_1;
+pub mod dropwhile_2;
+pub mod enumerate_1;
+pub mod enumerate_2;
+pub mod filter_2;
+pub mod flatmap_2;
+pub mod foldl_3;
+pub mod foldr_3;
+pub mod foreach_2;
+pub mod join_2;
+pub mod keydelete_3;
 pub mod keyfind_3;
+pub mod keymerge_3;
 pub mod keymember_3;
+pub mod keyreplace_4;
+pub mod keysort_2;
+pub mod keystore_4;
+pub mod keytake_3;
+pub mod map_2;
+pub mod max_1;
 pub mod member_2;
+pub mod merge_2;
+pub mod min_1;
+pub mod nthtail_2;
+pub mod partition_2;
 pub mod reverse_1;
 pub mod reverse_2;
+pub mod search_2;
+pub mod sort_1;
+pub mod sort_2;
+pub mod splitwith_2;
+pub mod sublist_2;
+pub mod sublist_3;
+pub mod sum_1;
+pub mod takewhile_2;
+pub mod uniq_1;
+pub mod uniq_2;
+pub mod unzip_1;
+pub mod usort_1;
+pub mod zip3_3;
+pub mod zip_2;
+pub mod zipwith_3;
 
-use liblumen_alloc::erts::term::prelude::Atom;
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_is_not_type;
 
 fn module() -> Atom {
     Atom::from_str("lists")
 }
+
+/// Checks that `function` is a function of `arity`.  The higher-order functions check this
+/// before calling `function`, so that a bad `function` is reported even for an empty list.
+fn function_with_arity(name: &str, function: Term, arity: u8) -> exception::Result<Term> {
+    match function.decode()? {
+        TypedTerm::Closure(closure) if closure.arity() == arity => Ok(function),
+        _ => Err(TypeError)
+            .context(term_is_not_type(
+                name,
+                function,
+                &format!("a function with arity {}", arity),
+            ))
+            .map_err(From::from),
+    }
+}
+
+/// Whether any of `terms` is exactly equal (`=:=`) to `element`, so that `1` and `1.0` are
+/// different.
+fn contains_exactly(terms: &[Term], element: Term) -> exception::Result<bool> {
+    let element_typed_term = element.decode()?;
+
+    Ok(terms
+        .iter()
+        .any(|term| term.decode().unwrap().exact_eq(&element_typed_term)))
+}
+
+/// Returns the position in `vec` of the first tuple whose `n`th element compares equal to `key`.
+/// Elements that are not tuples, or are too short to have an `n`th element, are skipped.
+fn key_position(vec: &[Term], n: OneBasedIndex, key: Term) -> Option<usize> {
+    vec.iter().position(|element| match element.decode() {
+        Ok(TypedTerm::Tuple(tuple)) => tuple
+            .get_element(n)
+            .map(|candidate| candidate == key)
+            .unwrap_or(false),
+        _ => false,
+    })
+}
+
+/// Merges the already sorted `left` and `right` by the key paired with each element.  The merge is
+/// stable, so when keys compare equal, the element from `left` comes first.
+fn merge_keyed_vecs(left: Vec<(Term, Term)>, right: Vec<(Term, Term)>) -> Vec<Term> {
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let mut left_iter = left.into_iter().peekable();
+    let mut right_iter = right.into_iter().peekable();
+
+    loop {
+        let take_left = match (left_iter.peek(), right_iter.peek()) {
+            (Some((left_key, _)), Some((right_key, _))) => left_key <= right_key,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => break,
+        };
+        let (_, element) = if take_left {
+            left_iter.next()
+        } else {
+            right_iter.next()
+        }
+        .unwrap();
+
+        merged.push(element);
+    }
+
+    merged
+}
+
+/// Pairs each tuple in `tuple_list` with its `index`th element, so it can be sorted or merged by
+/// that key.
+fn tuple_list_to_keyed_vec(
+    name: &str,
+    tuple_list: Term,
+    n: Term,
+    index: OneBasedIndex,
+) -> exception::Result<Vec<(Term, Term)>> {
+    let mut keyed_vec = Vec::new();
+
+    for element in list_to_vec(name, tuple_list)? {
+        let key = match element.decode()? {
+            TypedTerm::Tuple(tuple) => tuple.get_element(index).ok(),
+            _ => None,
+        }
+        .with_context(|| {
+            format!(
+                "{} ({}) element ({}) is not a tuple with at least {} elements",
+                name, tuple_list, element, n
+            )
+        })?;
+
+        keyed_vec.push((key, element));
+    }
+
+    Ok(keyed_vec)
+}
+
+/// Checks that `lists` all have the same length, as the zipping functions require.
+fn lists_to_equal_length_vecs(names: &[&str], lists: &[Term]) -> exception::Result<Vec<Vec<Term>>> {
+    let vecs = names
+        .iter()
+        .zip(lists)
+        .map(|(name, list)| list_to_vec(name, *list))
+        .collect::<exception::Result<Vec<Vec<Term>>>>()?;
+
+    if vecs.windows(2).all(|pair| pair[0].len() == pair[1].len()) {
+        Ok(vecs)
+    } else {
+        let lists_string = lists
+            .iter()
+            .map(|list| list.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        Err(anyhow!("lists ({}) do not have the same length", lists_string).into())
+    }
+}
+
+fn list_to_vec(name: &str, list: Term) -> exception::Result<Vec<Term>> {
+    match list.decode()? {
+        TypedTerm::Nil => Ok(Vec::new()),
+        TypedTerm::List(cons) => cons
+            .into_iter()
+            .map(|result| {
+                result
+                    .map_err(|_| ImproperListError)
+                    .with_context(|| format!("{} ({}) is improper", name, list))
+                    .map_err(From::from)
+            })
+            .collect(),
+        _ => Err(TypeError)
+            .context(format!("{} ({}) is not a list", name, list))
+            .map_err(From::from),
+    }
+}
@@ -0,0 +1,50 @@
+//! ```erlang
+//! all(_Pred, []) -> true;
+//! all(Pred, [Head | Tail]) ->
+//!   % label 1
+//!   case Pred(Head) of
+//!     true -> all(Pred, Tail);
+//!     false -> false
+//!   end.
+//! ```
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+
+/// Returns `true` if `pred(Element)` returns `true` for every element of `list`.  `pred` is not
+/// called on the elements after the first for which it returns `false`.  `pred` must return a
+/// boolean.
+#[native_implemented::function(lists:all/2)]
+pub fn result(process: &Process, pred: Term, list: Term) -> exception::Result<Term> {
+    let pred = super::function_with_arity("pred", pred, 1)?;
+    super::list_to_vec("list", list)?;
+
+    all(process, pred, list)
+}
+
+// Private
+
+fn all(process: &Process, pred: Term, list: Term) -> exception::Result<Term> {
+    match list.decode()? {
+        TypedTerm::List(cons) => {
+            process.queue_frame_with_arguments(apply_2::frame_with_arguments(
+                pred,
+                process.list_from_slice(&[cons.head]),
+            ));
+            process.queue_frame_with_arguments(
+                label_1::frame().with_arguments(true, &[pred, cons.tail]),
+            );
+
+            Ok(Term::NONE)
+        }
+        _ => Ok(true.into()),
+    }
+}
@@ -0,0 +1,50 @@
+//! ```erlang
+//! any(_Pred, []) -> false;
+//! any(Pred, [Head | Tail]) ->
+//!   % label 1
+//!   case Pred(Head) of
+//!     true -> true;
+//!     false -> any(Pred, Tail)
+//!   end.
+//! ```
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+
+/// Returns `true` if `pred(Element)` returns `true` for at least one element of `list`.  `pred` is
+/// not called on the elements after the first for which it returns `true`.  `pred` must return a
+/// boolean.
+#[native_implemented::function(lists:any/2)]
+pub fn result(process: &Process, pred: Term, list: Term) -> exception::Result<Term> {
+    let pred = super::function_with_arity("pred", pred, 1)?;
+    super::list_to_vec("list", list)?;
+
+    any(process, pred, list)
+}
+
+// Private
+
+fn any(process: &Process, pred: Term, list: Term) -> exception::Result<Term> {
+    match list.decode()? {
+        TypedTerm::List(cons) => {
+            process.queue_frame_with_arguments(apply_2::frame_with_arguments(
+                pred,
+                process.list_from_slice(&[cons.head]),
+            ));
+            process.queue_frame_with_arguments(
+                label_1::frame().with_arguments(true, &[pred, cons.tail]),
+            );
+
+            Ok(Term::NONE)
+        }
+        _ => Ok(false.into()),
+    }
+}
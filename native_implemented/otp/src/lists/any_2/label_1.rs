@@ -0,0 +1,28 @@
+//! ```erlang
+//! % label 1
+//! % pushed to stack: (Pred, Tail)
+//! % returned from call: Satisfied
+//! % full stack: (Satisfied, Pred, Tail)
+//! % returns: Any
+//! case Satisfied of
+//!   true -> true;
+//!   false -> any(Pred, Tail)
+//! end
+//! ```
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_try_into_bool;
+
+// Private
+
+#[native_implemented::label]
+fn result(process: &Process, satisfied: Term, pred: Term, tail: Term) -> exception::Result<Term> {
+    if term_try_into_bool("pred result", satisfied)? {
+        Ok(true.into())
+    } else {
+        super::any(process, pred, tail)
+    }
+}
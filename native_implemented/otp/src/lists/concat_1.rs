@@ -0,0 +1,42 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::float_to_string::float_to_string;
+use crate::erlang::list_to_string::list_to_string;
+
+/// Concatenates the text representation of each element of `things` into a single charlist.
+///
+/// Atoms are written as their name, integers in decimal, floats as `float_to_list/1` writes them,
+/// and strings as-is.
+#[native_implemented::function(lists:concat/1)]
+pub fn result(process: &Process, things: Term) -> exception::Result<Term> {
+    let mut string = String::new();
+
+    for thing in super::list_to_vec("things", things)? {
+        match thing.decode()? {
+            TypedTerm::Atom(atom) => string.push_str(atom.name()),
+            TypedTerm::SmallInteger(small_integer) => string.push_str(&small_integer.to_string()),
+            TypedTerm::BigInteger(big_integer) => string.push_str(&big_integer.to_string()),
+            TypedTerm::Float(_) => string.push_str(&float_to_string(thing, Default::default())?),
+            TypedTerm::Nil | TypedTerm::List(_) => string.push_str(&list_to_string(thing)?),
+            _ => {
+                return Err(TypeError)
+                    .with_context(|| {
+                        format!(
+                            "things ({}) element ({}) is not an atom, integer, float, or string",
+                            things, thing
+                        )
+                    })
+                    .map_err(From::from)
+            }
+        }
+    }
+
+    Ok(process.charlist_from_str(&string))
+}
@@ -0,0 +1,60 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::concat_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_list_errors_badarg() {
+    with_process(|process| {
+        let things = Atom::str_to_term("things");
+
+        assert_badarg!(
+            result(process, things),
+            format!("things ({}) is not a list", things)
+        );
+    });
+}
+
+#[test]
+fn with_tuple_element_errors_badarg() {
+    with_process(|process| {
+        let thing = process.tuple_from_slice(&[]);
+        let things = process.list_from_slice(&[thing]);
+
+        assert_badarg!(
+            result(process, things),
+            format!(
+                "things ({}) element ({}) is not an atom, integer, float, or string",
+                things, thing
+            )
+        );
+    });
+}
+
+#[test]
+fn with_atom_integer_and_string_returns_concatenated_charlist() {
+    with_process(|process| {
+        let things = process.list_from_slice(&[
+            Atom::str_to_term("foo"),
+            process.integer(1),
+            process.charlist_from_str("bar"),
+        ]);
+
+        assert_eq!(
+            result(process, things),
+            Ok(process.charlist_from_str("foo1bar"))
+        );
+    });
+}
+
+#[test]
+fn with_float_matches_float_to_list() {
+    with_process(|process| {
+        let things = process.list_from_slice(&[process.float(2.5), process.float(1.0e21)]);
+
+        assert_eq!(
+            result(process, things),
+            Ok(process.charlist_from_str("2.50000000000000000000e+001.00000000000000000000e+21"))
+        );
+    });
+}
@@ -0,0 +1,48 @@
+//! ```erlang
+//! dropwhile(_Pred, []) -> [];
+//! dropwhile(Pred, [Head | Tail] = List) ->
+//!   % label 1
+//!   case Pred(Head) of
+//!     true -> dropwhile(Pred, Tail);
+//!     false -> List
+//!   end.
+//! ```
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+
+/// Drops elements from the head of `list` while `pred(Element)` returns `true`, returning the
+/// remaining list.  `pred` must return a boolean.
+#[native_implemented::function(lists:dropwhile/2)]
+pub fn result(process: &Process, pred: Term, list: Term) -> exception::Result<Term> {
+    let pred = super::function_with_arity("pred", pred, 1)?;
+    super::list_to_vec("list", list)?;
+
+    dropwhile(process, pred, list)
+}
+
+// Private
+
+fn dropwhile(process: &Process, pred: Term, list: Term) -> exception::Result<Term> {
+    match list.decode()? {
+        TypedTerm::List(cons) => {
+            process.queue_frame_with_arguments(apply_2::frame_with_arguments(
+                pred,
+                process.list_from_slice(&[cons.head]),
+            ));
+            process
+                .queue_frame_with_arguments(label_1::frame().with_arguments(true, &[pred, list]));
+
+            Ok(Term::NONE)
+        }
+        _ => Ok(Term::NIL),
+    }
+}
@@ -0,0 +1,32 @@
+//! ```erlang
+//! % label 1
+//! % pushed to stack: (Pred, List)
+//! % returned from call: Satisfied
+//! % full stack: (Satisfied, Pred, List)
+//! % returns: Rest
+//! case Satisfied of
+//!   true -> dropwhile(Pred, tl(List));
+//!   false -> List
+//! end
+//! ```
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_try_into_bool;
+
+// Private
+
+#[native_implemented::label]
+fn result(process: &Process, satisfied: Term, pred: Term, list: Term) -> exception::Result<Term> {
+    if term_try_into_bool("pred result", satisfied)? {
+        let cons: Boxed<Cons> = list.try_into().unwrap();
+
+        super::dropwhile(process, pred, cons.tail)
+    } else {
+        Ok(list)
+    }
+}
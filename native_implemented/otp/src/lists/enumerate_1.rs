@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::enumerate_2;
+
+/// Returns `list` with each element paired with its 1-based index as `{Index, Element}`.
+#[native_implemented::function(lists:enumerate/1)]
+pub fn result(process: &Process, list: Term) -> exception::Result<Term> {
+    enumerate_2::result(process, process.integer(1), list)
+}
@@ -0,0 +1,27 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::enumerate_1::result;
+use crate::test::with_process;
+
+#[test]
+fn with_empty_list_returns_empty_list() {
+    with_process(|process| {
+        assert_eq!(result(process, Term::NIL), Ok(Term::NIL));
+    });
+}
+
+#[test]
+fn with_list_starts_at_1() {
+    with_process(|process| {
+        let a = Atom::str_to_term("a");
+        let b = Atom::str_to_term("b");
+
+        assert_eq!(
+            result(process, process.list_from_slice(&[a, b])),
+            Ok(process.list_from_slice(&[
+                process.tuple_from_slice(&[process.integer(1), a]),
+                process.tuple_from_slice(&[process.integer(2), b]),
+            ]))
+        );
+    });
+}
@@ -0,0 +1,34 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+use num_bigint::BigInt;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_is_not_integer;
+
+/// Returns `list` with each element paired with its index as `{Index, Element}`, counting up from
+/// `index`.
+#[native_implemented::function(lists:enumerate/2)]
+pub fn result(process: &Process, index: Term, list: Term) -> exception::Result<Term> {
+    let index_big_int: BigInt = index
+        .try_into()
+        .with_context(|| term_is_not_integer("index", index))?;
+    let vec = super::list_to_vec("list", list)?;
+    let enumerated: Vec<Term> = vec
+        .into_iter()
+        .enumerate()
+        .map(|(offset, element)| {
+            let element_index = process.integer(index_big_int.clone() + offset);
+
+            process.tuple_from_slice(&[element_index, element])
+        })
+        .collect();
+
+    Ok(process.list_from_slice(&enumerated))
+}
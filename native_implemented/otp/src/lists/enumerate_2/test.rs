@@ -0,0 +1,68 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::enumerate_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_integer_index_errors_badarg() {
+    with_process(|process| {
+        let index = Atom::str_to_term("index");
+
+        assert_badarg!(
+            result(process, index, Term::NIL),
+            format!("index ({}) is not an integer", index)
+        );
+    });
+}
+
+#[test]
+fn without_list_errors_badarg() {
+    with_process(|process| {
+        let list = Atom::str_to_term("list");
+
+        assert_badarg!(
+            result(process, process.integer(0), list),
+            format!("list ({}) is not a list", list)
+        );
+    });
+}
+
+#[test]
+fn with_index_0_starts_at_0() {
+    with_process(|process| {
+        let a = Atom::str_to_term("a");
+        let b = Atom::str_to_term("b");
+
+        assert_eq!(
+            result(
+                process,
+                process.integer(0),
+                process.list_from_slice(&[a, b])
+            ),
+            Ok(process.list_from_slice(&[
+                process.tuple_from_slice(&[process.integer(0), a]),
+                process.tuple_from_slice(&[process.integer(1), b]),
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_negative_index_counts_up() {
+    with_process(|process| {
+        let a = Atom::str_to_term("a");
+        let b = Atom::str_to_term("b");
+
+        assert_eq!(
+            result(
+                process,
+                process.integer(-1),
+                process.list_from_slice(&[a, b])
+            ),
+            Ok(process.list_from_slice(&[
+                process.tuple_from_slice(&[process.integer(-1), a]),
+                process.tuple_from_slice(&[process.integer(0), b]),
+            ]))
+        );
+    });
+}
@@ -0,0 +1,52 @@
+//! ```erlang
+//! filter(Pred, List) -> filter(Pred, List, []).
+//!
+//! filter(_Pred, [], Acc) -> lists:reverse(Acc);
+//! filter(Pred, [Head | Tail], Acc) ->
+//!   % label 1
+//!   case Pred(Head) of
+//!     true -> filter(Pred, Tail, [Head | Acc]);
+//!     false -> filter(Pred, Tail, Acc)
+//!   end.
+//! ```
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+use crate::lists::reverse_2;
+
+/// Returns the elements of `list` for which `pred(Element)` returns `true`.  `pred` must return
+/// a boolean.
+#[native_implemented::function(lists:filter/2)]
+pub fn result(process: &Process, pred: Term, list: Term) -> exception::Result<Term> {
+    let pred = super::function_with_arity("pred", pred, 1)?;
+    super::list_to_vec("list", list)?;
+
+    filter(process, pred, list, Term::NIL)
+}
+
+// Private
+
+fn filter(process: &Process, pred: Term, list: Term, acc: Term) -> exception::Result<Term> {
+    match list.decode()? {
+        TypedTerm::List(cons) => {
+            process.queue_frame_with_arguments(apply_2::frame_with_arguments(
+                pred,
+                process.list_from_slice(&[cons.head]),
+            ));
+            process.queue_frame_with_arguments(
+                label_1::frame().with_arguments(true, &[pred, cons.head, cons.tail, acc]),
+            );
+
+            Ok(Term::NONE)
+        }
+        _ => reverse_2::result(process, acc, Term::NIL),
+    }
+}
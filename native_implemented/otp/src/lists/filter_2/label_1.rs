@@ -0,0 +1,37 @@
+//! ```erlang
+//! % label 1
+//! % pushed to stack: (Pred, Head, Tail, Acc)
+//! % returned from call: Satisfied
+//! % full stack: (Satisfied, Pred, Head, Tail, Acc)
+//! % returns: Filtered
+//! case Satisfied of
+//!   true -> filter(Pred, Tail, [Head | Acc]);
+//!   false -> filter(Pred, Tail, Acc)
+//! end
+//! ```
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_try_into_bool;
+
+// Private
+
+#[native_implemented::label]
+fn result(
+    process: &Process,
+    satisfied: Term,
+    pred: Term,
+    head: Term,
+    tail: Term,
+    acc: Term,
+) -> exception::Result<Term> {
+    let acc = if term_try_into_bool("pred result", satisfied)? {
+        process.cons(head, acc)
+    } else {
+        acc
+    };
+
+    super::filter(process, pred, tail, acc)
+}
@@ -0,0 +1,50 @@
+//! ```erlang
+//! flatmap(Fun, List) -> flatmap(Fun, List, []).
+//!
+//! flatmap(_Fun, [], Acc) -> lists:reverse(Acc);
+//! flatmap(Fun, [Head | Tail], Acc) ->
+//!   % label 1
+//!   Mapped = Fun(Head),
+//!   flatmap(Fun, Tail, lists:reverse(Mapped, Acc)).
+//! ```
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+use crate::lists::reverse_2;
+
+/// Calls `fun(Element)` for each element of `list`, in order from the head, and appends the
+/// returned lists together.
+#[native_implemented::function(lists:flatmap/2)]
+pub fn result(process: &Process, fun: Term, list: Term) -> exception::Result<Term> {
+    let fun = super::function_with_arity("fun", fun, 1)?;
+    super::list_to_vec("list", list)?;
+
+    flatmap(process, fun, list, Term::NIL)
+}
+
+// Private
+
+fn flatmap(process: &Process, fun: Term, list: Term, acc: Term) -> exception::Result<Term> {
+    match list.decode()? {
+        TypedTerm::List(cons) => {
+            process.queue_frame_with_arguments(apply_2::frame_with_arguments(
+                fun,
+                process.list_from_slice(&[cons.head]),
+            ));
+            process.queue_frame_with_arguments(
+                label_1::frame().with_arguments(true, &[fun, cons.tail, acc]),
+            );
+
+            Ok(Term::NONE)
+        }
+        _ => reverse_2::result(process, acc, Term::NIL),
+    }
+}
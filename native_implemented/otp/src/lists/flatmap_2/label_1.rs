@@ -0,0 +1,30 @@
+//! ```erlang
+//! % label 1
+//! % pushed to stack: (Fun, Tail, Acc)
+//! % returned from call: Mapped
+//! % full stack: (Mapped, Fun, Tail, Acc)
+//! % returns: FlatMappedList
+//! flatmap(Fun, Tail, lists:reverse(Mapped, Acc))
+//! ```
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::reverse_2;
+
+// Private
+
+#[native_implemented::label]
+fn result(
+    process: &Process,
+    mapped: Term,
+    fun: Term,
+    tail: Term,
+    acc: Term,
+) -> exception::Result<Term> {
+    super::super::list_to_vec("fun result", mapped)?;
+    let acc = reverse_2::result(process, mapped, acc)?;
+
+    super::flatmap(process, fun, tail, acc)
+}
@@ -0,0 +1,38 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::flatmap_2::result;
+use crate::test::{anonymous_0, anonymous_1, with_process};
+
+#[test]
+fn without_function_with_arity_1_errors_badarg() {
+    with_process(|process| {
+        let fun = anonymous_0::anonymous_closure(process);
+
+        assert_badarg!(
+            result(process, fun, Term::NIL),
+            format!("fun ({}) is not a function with arity 1", fun)
+        );
+    });
+}
+
+#[test]
+fn without_list_errors_badarg() {
+    with_process(|process| {
+        let list = Atom::str_to_term("list");
+
+        assert_badarg!(
+            result(process, anonymous_1::anonymous_closure(process), list),
+            format!("list ({}) is not a list", list)
+        );
+    });
+}
+
+#[test]
+fn with_empty_list_returns_empty_list() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, anonymous_1::anonymous_closure(process), Term::NIL),
+            Ok(Term::NIL)
+        );
+    });
+}
@@ -0,0 +1,52 @@
+//! ```erlang
+//! foldl(_Fun, Acc, []) -> Acc;
+//! foldl(Fun, Acc, [Head | Tail]) ->
+//!   % label 1
+//!   NextAcc = Fun(Head, Acc),
+//!   foldl(Fun, NextAcc, Tail).
+//! ```
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+
+/// Calls `fun(Element, AccIn)` on each element of `list`, starting with `AccIn` being `acc0` and
+/// continuing from the head of `list` with the `AccOut` of the previous call.  Returns the final
+/// `AccOut`.
+#[native_implemented::function(lists:foldl/3)]
+pub fn result(process: &Process, fun: Term, acc0: Term, list: Term) -> exception::Result<Term> {
+    let fun = super::function_with_arity("fun", fun, 2)?;
+    super::list_to_vec("list", list)?;
+
+    fold(process, fun, acc0, list)
+}
+
+/// Folds the proper `list` from its head.  `foldr/3` uses this on the reversed list.
+pub(in crate::lists) fn fold(
+    process: &Process,
+    fun: Term,
+    acc: Term,
+    list: Term,
+) -> exception::Result<Term> {
+    match list.decode()? {
+        TypedTerm::List(cons) => {
+            process.queue_frame_with_arguments(apply_2::frame_with_arguments(
+                fun,
+                process.list_from_slice(&[cons.head, acc]),
+            ));
+            process.queue_frame_with_arguments(
+                label_1::frame().with_arguments(true, &[fun, cons.tail]),
+            );
+
+            Ok(Term::NONE)
+        }
+        _ => Ok(acc),
+    }
+}
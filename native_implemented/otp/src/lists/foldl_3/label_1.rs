@@ -0,0 +1,19 @@
+//! ```erlang
+//! % label 1
+//! % pushed to stack: (Fun, Tail)
+//! % returned from call: NextAcc
+//! % full stack: (NextAcc, Fun, Tail)
+//! % returns: Acc
+//! foldl(Fun, NextAcc, Tail)
+//! ```
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+// Private
+
+#[native_implemented::label]
+fn result(process: &Process, next_acc: Term, fun: Term, tail: Term) -> exception::Result<Term> {
+    super::fold(process, fun, next_acc, tail)
+}
@@ -0,0 +1,22 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::foldl_3::fold;
+use crate::lists::reverse_2;
+
+/// Like `foldl/3`, but calls `fun` starting from the last element of `list`.
+///
+/// `list` is reversed up front and then folded from its head, so that neither the native stack
+/// nor the process stack grows with the length of `list`.
+#[native_implemented::function(lists:foldr/3)]
+pub fn result(process: &Process, fun: Term, acc0: Term, list: Term) -> exception::Result<Term> {
+    let fun = super::function_with_arity("fun", fun, 2)?;
+    super::list_to_vec("list", list)?;
+    let reversed = reverse_2::result(process, list, Term::NIL)?;
+
+    fold(process, fun, acc0, reversed)
+}
@@ -0,0 +1,16 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::foldr_3::result;
+use crate::test::{anonymous_1, with_process};
+
+#[test]
+fn without_function_with_arity_2_errors_badarg() {
+    with_process(|process| {
+        let fun = anonymous_1::anonymous_closure(process);
+
+        assert_badarg!(
+            result(process, fun, process.integer(0), Term::NIL),
+            format!("fun ({}) is not a function with arity 2", fun)
+        );
+    });
+}
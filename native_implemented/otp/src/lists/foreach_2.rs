@@ -0,0 +1,47 @@
+//! ```erlang
+//! foreach(_Fun, []) -> ok;
+//! foreach(Fun, [Head | Tail]) ->
+//!   % label 1
+//!   Fun(Head),
+//!   foreach(Fun, Tail).
+//! ```
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+
+/// Calls `fun(Element)` on each element of `list` for its side effects, in order from the head of
+/// `list`, and returns `ok`.
+#[native_implemented::function(lists:foreach/2)]
+pub fn result(process: &Process, fun: Term, list: Term) -> exception::Result<Term> {
+    let fun = super::function_with_arity("fun", fun, 1)?;
+    super::list_to_vec("list", list)?;
+
+    Ok(foreach(process, fun, list))
+}
+
+// Private
+
+fn foreach(process: &Process, fun: Term, list: Term) -> Term {
+    match list.decode().unwrap() {
+        TypedTerm::List(cons) => {
+            process.queue_frame_with_arguments(apply_2::frame_with_arguments(
+                fun,
+                process.list_from_slice(&[cons.head]),
+            ));
+            process.queue_frame_with_arguments(
+                label_1::frame().with_arguments(true, &[fun, cons.tail]),
+            );
+
+            Term::NONE
+        }
+        _ => Atom::str_to_term("ok"),
+    }
+}
@@ -0,0 +1,18 @@
+//! ```erlang
+//! % label 1
+//! % pushed to stack: (Fun, Tail)
+//! % returned from call: _
+//! % full stack: (_, Fun, Tail)
+//! % returns: ok
+//! foreach(Fun, Tail)
+//! ```
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+// Private
+
+#[native_implemented::label]
+fn result(process: &Process, _returned: Term, fun: Term, tail: Term) -> Term {
+    super::foreach(process, fun, tail)
+}
@@ -0,0 +1,29 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Inserts `sep` between each element of `list`.  Lists with fewer than 2 elements are returned
+/// unchanged.
+#[native_implemented::function(lists:join/2)]
+pub fn result(process: &Process, sep: Term, list: Term) -> exception::Result<Term> {
+    let vec = super::list_to_vec("list", list)?;
+
+    if vec.len() < 2 {
+        Ok(list)
+    } else {
+        let mut joined = Vec::with_capacity(2 * vec.len() - 1);
+
+        for (index, element) in vec.into_iter().enumerate() {
+            if 0 < index {
+                joined.push(sep);
+            }
+
+            joined.push(element);
+        }
+
+        Ok(process.list_from_slice(&joined))
+    }
+}
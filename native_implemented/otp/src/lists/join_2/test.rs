@@ -0,0 +1,50 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::join_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_list_errors_badarg() {
+    with_process(|process| {
+        let list = Atom::str_to_term("list");
+
+        assert_badarg!(
+            result(process, Atom::str_to_term("sep"), list),
+            format!("list ({}) is not a list", list)
+        );
+    });
+}
+
+#[test]
+fn with_empty_list_returns_empty_list() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, Atom::str_to_term("sep"), Term::NIL),
+            Ok(Term::NIL)
+        );
+    });
+}
+
+#[test]
+fn with_one_element_returns_list() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[process.integer(1)]);
+
+        assert_eq!(result(process, Atom::str_to_term("sep"), list), Ok(list));
+    });
+}
+
+#[test]
+fn with_three_elements_returns_five_elements() {
+    with_process(|process| {
+        let sep = Atom::str_to_term("sep");
+        let one = process.integer(1);
+        let two = process.integer(2);
+        let three = process.integer(3);
+
+        assert_eq!(
+            result(process, sep, process.list_from_slice(&[one, two, three])),
+            Ok(process.list_from_slice(&[one, sep, two, sep, three]))
+        );
+    });
+}
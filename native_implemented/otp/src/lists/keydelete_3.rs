@@ -0,0 +1,24 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_try_into_one_based_index;
+
+/// Returns `tuple_list` without the first tuple whose `n`th element compares equal to `key`.
+#[native_implemented::function(lists:keydelete/3)]
+pub fn result(process: &Process, key: Term, n: Term, tuple_list: Term) -> exception::Result<Term> {
+    let index = term_try_into_one_based_index(n)?;
+    let mut vec = super::list_to_vec("tuple_list", tuple_list)?;
+
+    match super::key_position(&vec, index, key) {
+        Some(position) => {
+            vec.remove(position);
+
+            Ok(process.list_from_slice(&vec))
+        }
+        None => Ok(tuple_list),
+    }
+}
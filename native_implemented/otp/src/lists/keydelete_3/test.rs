@@ -0,0 +1,55 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::keydelete_3::result;
+use crate::test::with_process;
+
+#[test]
+fn without_list_errors_badarg() {
+    with_process(|process| {
+        let tuple_list = Atom::str_to_term("tuple_list");
+
+        assert_badarg!(
+            result(
+                process,
+                Atom::str_to_term("key"),
+                process.integer(1),
+                tuple_list
+            ),
+            format!("tuple_list ({}) is not a list", tuple_list)
+        );
+    });
+}
+
+#[test]
+fn with_key_deletes_first_matching_tuple_and_skips_non_tuples() {
+    with_process(|process| {
+        let key = Atom::str_to_term("key");
+        let non_tuple = Atom::str_to_term("non_tuple");
+        let first = process.tuple_from_slice(&[key, process.integer(1)]);
+        let second = process.tuple_from_slice(&[key, process.integer(2)]);
+        let tuple_list = process.list_from_slice(&[non_tuple, first, second]);
+
+        assert_eq!(
+            result(process, key, process.integer(1), tuple_list),
+            Ok(process.list_from_slice(&[non_tuple, second]))
+        );
+    });
+}
+
+#[test]
+fn without_key_returns_tuple_list() {
+    with_process(|process| {
+        let tuple = process.tuple_from_slice(&[Atom::str_to_term("other")]);
+        let tuple_list = process.list_from_slice(&[tuple]);
+
+        assert_eq!(
+            result(
+                process,
+                Atom::str_to_term("key"),
+                process.integer(1),
+                tuple_list
+            ),
+            Ok(tuple_list)
+        );
+    });
+}
@@ -0,0 +1,26 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_try_into_one_based_index;
+
+/// Merges `tuple_list1` and `tuple_list2`, which must already be sorted by the `n`th element of
+/// each tuple.  The merge is stable, so tuples with equal keys from `tuple_list1` come before
+/// those from `tuple_list2`.
+#[native_implemented::function(lists:keymerge/3)]
+pub fn result(
+    process: &Process,
+    n: Term,
+    tuple_list1: Term,
+    tuple_list2: Term,
+) -> exception::Result<Term> {
+    let index = term_try_into_one_based_index(n)?;
+    let keyed_vec1 = super::tuple_list_to_keyed_vec("tuple_list1", tuple_list1, n, index)?;
+    let keyed_vec2 = super::tuple_list_to_keyed_vec("tuple_list2", tuple_list2, n, index)?;
+    let merged = super::merge_keyed_vecs(keyed_vec1, keyed_vec2);
+
+    Ok(process.list_from_slice(&merged))
+}
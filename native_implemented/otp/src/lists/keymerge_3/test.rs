@@ -0,0 +1,52 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::keymerge_3::result;
+use crate::test::with_process;
+
+#[test]
+fn without_positive_index_errors_badarg() {
+    with_process(|process| {
+        let n = process.integer(0);
+
+        assert_badarg!(
+            result(process, n, Term::NIL, Term::NIL),
+            format!("index ({}) is not a 1-based integer", n)
+        );
+    });
+}
+
+#[test]
+fn with_non_tuple_element_errors_badarg() {
+    with_process(|process| {
+        let element = Atom::str_to_term("a");
+        let tuple_list2 = process.list_from_slice(&[element]);
+
+        assert_badarg!(
+            result(process, process.integer(1), Term::NIL, tuple_list2),
+            format!(
+                "tuple_list2 ({}) element ({}) is not a tuple with at least 1 elements",
+                tuple_list2, element
+            )
+        );
+    });
+}
+
+#[test]
+fn with_sorted_tuple_lists_returns_list_sorted_by_key_with_tuple_list1_first_for_equal_keys() {
+    with_process(|process| {
+        let a1 = process.tuple_from_slice(&[Atom::str_to_term("a"), process.integer(1)]);
+        let b3 = process.tuple_from_slice(&[Atom::str_to_term("b"), process.integer(3)]);
+        let c2 = process.tuple_from_slice(&[Atom::str_to_term("c"), process.integer(2)]);
+        let d3 = process.tuple_from_slice(&[Atom::str_to_term("d"), process.integer(3)]);
+
+        assert_eq!(
+            result(
+                process,
+                process.integer(2),
+                process.list_from_slice(&[a1, b3]),
+                process.list_from_slice(&[c2, d3])
+            ),
+            Ok(process.list_from_slice(&[a1, c2, b3, d3]))
+        );
+    });
+}
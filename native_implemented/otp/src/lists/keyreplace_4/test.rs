@@ -0,0 +1,38 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::keyreplace_4::result;
+use crate::test::with_process;
+
+#[test]
+fn without_tuple_new_tuple_errors_badarg() {
+    with_process(|process| {
+        let new_tuple = Atom::str_to_term("new_tuple");
+
+        assert_badarg!(
+            result(
+                process,
+                Atom::str_to_term("key"),
+                process.integer(1),
+                Term::NIL,
+                new_tuple
+            ),
+            format!("new_tuple ({}) is not a tuple", new_tuple)
+        );
+    });
+}
+
+#[test]
+fn with_key_replaces_first_matching_tuple() {
+    with_process(|process| {
+        let key = Atom::str_to_term("key");
+        let first = process.tuple_from_slice(&[key, process.integer(1)]);
+        let second = process.tuple_from_slice(&[key, process.integer(2)]);
+        let tuple_list = process.list_from_slice(&[first, second]);
+        let new_tuple = process.tuple_from_slice(&[key, process.integer(3)]);
+
+        assert_eq!(
+            result(process, key, process.integer(1), tuple_list, new_tuple),
+            Ok(process.list_from_slice(&[new_tuple, second]))
+        );
+    });
+}
@@ -0,0 +1,22 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_try_into_one_based_index;
+
+/// Sorts `tuple_list` by the `n`th element of each tuple in standard term order.  The sort is
+/// stable, so tuples with equal keys keep their order from `tuple_list`.
+#[native_implemented::function(lists:keysort/2)]
+pub fn result(process: &Process, n: Term, tuple_list: Term) -> exception::Result<Term> {
+    let index = term_try_into_one_based_index(n)?;
+    let mut keyed_vec = super::tuple_list_to_keyed_vec("tuple_list", tuple_list, n, index)?;
+
+    keyed_vec.sort_by(|(key1, _), (key2, _)| key1.cmp(key2));
+
+    let sorted: Vec<Term> = keyed_vec.into_iter().map(|(_, element)| element).collect();
+
+    Ok(process.list_from_slice(&sorted))
+}
@@ -0,0 +1,67 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::keysort_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_positive_index_errors_badarg() {
+    with_process(|process| {
+        let n = process.integer(0);
+
+        assert_badarg!(
+            result(process, n, Term::NIL),
+            format!("index ({}) is not a 1-based integer", n)
+        );
+    });
+}
+
+#[test]
+fn with_non_tuple_element_errors_badarg() {
+    with_process(|process| {
+        let element = Atom::str_to_term("a");
+        let tuple_list = process.list_from_slice(&[element]);
+
+        assert_badarg!(
+            result(process, process.integer(1), tuple_list),
+            format!(
+                "tuple_list ({}) element ({}) is not a tuple with at least 1 elements",
+                tuple_list, element
+            )
+        );
+    });
+}
+
+#[test]
+fn with_tuple_too_small_errors_badarg() {
+    with_process(|process| {
+        let element = process.tuple_from_slice(&[process.integer(1)]);
+        let tuple_list = process.list_from_slice(&[element]);
+
+        assert_badarg!(
+            result(process, process.integer(2), tuple_list),
+            format!(
+                "tuple_list ({}) element ({}) is not a tuple with at least 2 elements",
+                tuple_list, element
+            )
+        );
+    });
+}
+
+#[test]
+fn with_equal_keys_is_stable() {
+    with_process(|process| {
+        let b2 = process.tuple_from_slice(&[Atom::str_to_term("b"), process.integer(2)]);
+        let a1 = process.tuple_from_slice(&[Atom::str_to_term("a"), process.integer(1)]);
+        let c2 = process.tuple_from_slice(&[Atom::str_to_term("c"), process.integer(2)]);
+        let d1 = process.tuple_from_slice(&[Atom::str_to_term("d"), process.integer(1)]);
+
+        assert_eq!(
+            result(
+                process,
+                process.integer(2),
+                process.list_from_slice(&[b2, a1, c2, d1])
+            ),
+            Ok(process.list_from_slice(&[a1, d1, b2, c2]))
+        );
+    });
+}
@@ -0,0 +1,30 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::*;
+
+/// Returns `tuple_list` with the first tuple whose `n`th element compares equal to `key`
+/// replaced by `new_tuple`, or with `new_tuple` appended if there is no such tuple.
+#[native_implemented::function(lists:keystore/4)]
+pub fn result(
+    process: &Process,
+    key: Term,
+    n: Term,
+    tuple_list: Term,
+    new_tuple: Term,
+) -> exception::Result<Term> {
+    let index = term_try_into_one_based_index(n)?;
+    let mut vec = super::list_to_vec("tuple_list", tuple_list)?;
+    term_try_into_tuple("new_tuple", new_tuple)?;
+
+    match super::key_position(&vec, index, key) {
+        Some(position) => vec[position] = new_tuple,
+        None => vec.push(new_tuple),
+    }
+
+    Ok(process.list_from_slice(&vec))
+}
@@ -0,0 +1,34 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::keystore_4::result;
+use crate::test::with_process;
+
+#[test]
+fn with_key_replaces_first_matching_tuple() {
+    with_process(|process| {
+        let key = Atom::str_to_term("key");
+        let tuple = process.tuple_from_slice(&[key, process.integer(1)]);
+        let tuple_list = process.list_from_slice(&[tuple]);
+        let new_tuple = process.tuple_from_slice(&[key, process.integer(2)]);
+
+        assert_eq!(
+            result(process, key, process.integer(1), tuple_list, new_tuple),
+            Ok(process.list_from_slice(&[new_tuple]))
+        );
+    });
+}
+
+#[test]
+fn without_key_appends_new_tuple() {
+    with_process(|process| {
+        let tuple = process.tuple_from_slice(&[Atom::str_to_term("other")]);
+        let tuple_list = process.list_from_slice(&[tuple]);
+        let key = Atom::str_to_term("key");
+        let new_tuple = process.tuple_from_slice(&[key]);
+
+        assert_eq!(
+            result(process, key, process.integer(1), tuple_list, new_tuple),
+            Ok(process.list_from_slice(&[tuple, new_tuple]))
+        );
+    });
+}
@@ -0,0 +1,27 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_try_into_one_based_index;
+
+/// Finds the first tuple in `tuple_list` whose `n`th element compares equal to `key`, returning
+/// `{value, Tuple, Rest}`, where `Rest` is `tuple_list` without `Tuple`, or `false` if there is
+/// no such tuple.
+#[native_implemented::function(lists:keytake/3)]
+pub fn result(process: &Process, key: Term, n: Term, tuple_list: Term) -> exception::Result<Term> {
+    let index = term_try_into_one_based_index(n)?;
+    let mut vec = super::list_to_vec("tuple_list", tuple_list)?;
+
+    match super::key_position(&vec, index, key) {
+        Some(position) => {
+            let tuple = vec.remove(position);
+            let rest = process.list_from_slice(&vec);
+
+            Ok(process.tuple_from_slice(&[Atom::str_to_term("value"), tuple, rest]))
+        }
+        None => Ok(false.into()),
+    }
+}
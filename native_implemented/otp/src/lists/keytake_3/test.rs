@@ -0,0 +1,53 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::keytake_3::result;
+use crate::test::with_process;
+
+#[test]
+fn without_one_based_index_errors_badarg() {
+    with_process(|process| {
+        let n = process.integer(0);
+
+        assert_badarg!(
+            result(process, Atom::str_to_term("key"), n, Term::NIL),
+            format!("index ({}) is not a 1-based integer", n)
+        );
+    });
+}
+
+#[test]
+fn with_key_returns_value_tuple_and_rest() {
+    with_process(|process| {
+        let key = Atom::str_to_term("key");
+        let other = process.tuple_from_slice(&[Atom::str_to_term("other")]);
+        let tuple = process.tuple_from_slice(&[key]);
+        let tuple_list = process.list_from_slice(&[other, tuple]);
+
+        assert_eq!(
+            result(process, key, process.integer(1), tuple_list),
+            Ok(process.tuple_from_slice(&[
+                Atom::str_to_term("value"),
+                tuple,
+                process.list_from_slice(&[other])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn without_key_returns_false() {
+    with_process(|process| {
+        let tuple = process.tuple_from_slice(&[Atom::str_to_term("other")]);
+        let tuple_list = process.list_from_slice(&[tuple]);
+
+        assert_eq!(
+            result(
+                process,
+                Atom::str_to_term("key"),
+                process.integer(1),
+                tuple_list
+            ),
+            Ok(false.into())
+        );
+    });
+}
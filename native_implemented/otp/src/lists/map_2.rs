@@ -0,0 +1,50 @@
+//! ```erlang
+//! map(Fun, List) -> map(Fun, List, []).
+//!
+//! map(_Fun, [], Acc) -> lists:reverse(Acc);
+//! map(Fun, [Head | Tail], Acc) ->
+//!   % label 1
+//!   Mapped = Fun(Head),
+//!   map(Fun, Tail, [Mapped | Acc]).
+//! ```
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+use crate::lists::reverse_2;
+
+/// Returns the list of `fun(Element)` for each element of `list`, calling `fun` in order from the
+/// head of `list`.
+#[native_implemented::function(lists:map/2)]
+pub fn result(process: &Process, fun: Term, list: Term) -> exception::Result<Term> {
+    let fun = super::function_with_arity("fun", fun, 1)?;
+    super::list_to_vec("list", list)?;
+
+    map(process, fun, list, Term::NIL)
+}
+
+// Private
+
+fn map(process: &Process, fun: Term, list: Term, acc: Term) -> exception::Result<Term> {
+    match list.decode()? {
+        TypedTerm::List(cons) => {
+            process.queue_frame_with_arguments(apply_2::frame_with_arguments(
+                fun,
+                process.list_from_slice(&[cons.head]),
+            ));
+            process.queue_frame_with_arguments(
+                label_1::frame().with_arguments(true, &[fun, cons.tail, acc]),
+            );
+
+            Ok(Term::NONE)
+        }
+        _ => reverse_2::result(process, acc, Term::NIL),
+    }
+}
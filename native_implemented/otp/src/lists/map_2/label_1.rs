@@ -0,0 +1,25 @@
+//! ```erlang
+//! % label 1
+//! % pushed to stack: (Fun, Tail, Acc)
+//! % returned from call: Mapped
+//! % full stack: (Mapped, Fun, Tail, Acc)
+//! % returns: MappedList
+//! map(Fun, Tail, [Mapped | Acc])
+//! ```
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+// Private
+
+#[native_implemented::label]
+fn result(
+    process: &Process,
+    mapped: Term,
+    fun: Term,
+    tail: Term,
+    acc: Term,
+) -> exception::Result<Term> {
+    super::map(process, fun, tail, process.cons(mapped, acc))
+}
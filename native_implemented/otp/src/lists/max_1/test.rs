@@ -0,0 +1,32 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::max_1::result;
+use crate::test::with_process;
+
+#[test]
+fn with_empty_list_errors_badarg() {
+    assert_badarg!(result(Term::NIL), "list ([]) is empty");
+}
+
+#[test]
+fn with_mixed_types_uses_term_order() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[
+            process.integer(2),
+            Atom::str_to_term("a"),
+            process.tuple_from_slice(&[]),
+            Term::NIL,
+        ]);
+
+        assert_eq!(result(list), Ok(Term::NIL));
+    });
+}
+
+#[test]
+fn with_equal_elements_returns_first() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[process.integer(1), process.float(1.0)]);
+
+        assert!(result(list).unwrap().is_smallint());
+    });
+}
@@ -0,0 +1,23 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Merges `list1` and `list2`, which must already be sorted in standard term order.  The merge is
+/// stable, so elements that compare equal, such as `1` and `1.0`, from `list1` come before those
+/// from `list2`.
+#[native_implemented::function(lists:merge/2)]
+pub fn result(process: &Process, list1: Term, list2: Term) -> exception::Result<Term> {
+    let keyed_vec1 = self_keyed_vec("list1", list1)?;
+    let keyed_vec2 = self_keyed_vec("list2", list2)?;
+    let merged = super::merge_keyed_vecs(keyed_vec1, keyed_vec2);
+
+    Ok(process.list_from_slice(&merged))
+}
+
+fn self_keyed_vec(name: &str, list: Term) -> exception::Result<Vec<(Term, Term)>> {
+    super::list_to_vec(name, list)
+        .map(|vec| vec.into_iter().map(|element| (element, element)).collect())
+}
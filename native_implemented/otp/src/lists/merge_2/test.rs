@@ -0,0 +1,60 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::merge_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_list_errors_badarg() {
+    with_process(|process| {
+        let list1 = Atom::str_to_term("a");
+
+        assert_badarg!(
+            result(process, list1, Term::NIL),
+            format!("list1 ({}) is not a list", list1)
+        );
+    });
+}
+
+#[test]
+fn with_sorted_lists_returns_sorted_list() {
+    with_process(|process| {
+        let list1 =
+            process.list_from_slice(&[process.integer(1), process.integer(4), process.integer(5)]);
+        let list2 =
+            process.list_from_slice(&[process.integer(2), process.integer(3), process.integer(6)]);
+
+        assert_eq!(
+            result(process, list1, list2),
+            Ok(process.list_from_slice(&[
+                process.integer(1),
+                process.integer(2),
+                process.integer(3),
+                process.integer(4),
+                process.integer(5),
+                process.integer(6),
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_equal_elements_puts_list1_first() {
+    with_process(|process| {
+        let integer = process.integer(1);
+        let float = process.float(1.0);
+        let merged = result(
+            process,
+            process.list_from_slice(&[float]),
+            process.list_from_slice(&[integer]),
+        )
+        .unwrap();
+
+        let merged_cons: Boxed<Cons> = merged.try_into().unwrap();
+        let merged_vec: Vec<Term> = merged_cons.into_iter().map(Result::unwrap).collect();
+
+        assert!(merged_vec[0].is_float());
+        assert!(merged_vec[1].is_smallint());
+    });
+}
@@ -0,0 +1,21 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns the smallest element of `list` in standard term order.  If several elements compare
+/// equal, the first is returned.
+#[native_implemented::function(lists:min/1)]
+pub fn result(list: Term) -> exception::Result<Term> {
+    super::list_to_vec("list", list)?
+        .into_iter()
+        .fold(None, |min, element| match min {
+            Some(min) if !(element < min) => Some(min),
+            _ => Some(element),
+        })
+        .with_context(|| format!("list ({}) is empty", list))
+        .map_err(From::from)
+}
@@ -0,0 +1,34 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::*;
+
+/// Returns the tail of `list` after dropping its first `n` elements.  Unlike `lists:sublist/2`,
+/// `list` must have at least `n` elements.
+#[native_implemented::function(lists:nthtail/2)]
+pub fn result(n: Term, list: Term) -> exception::Result<Term> {
+    let n_usize: usize = n
+        .try_into()
+        .with_context(|| term_is_not_non_negative_integer("n", n))?;
+    let mut tail = list;
+
+    for _ in 0..n_usize {
+        match tail.decode()? {
+            TypedTerm::List(cons) => tail = cons.tail,
+            _ => {
+                return Err(
+                    anyhow!("n ({}) is greater than the length of list ({})", n, list).into(),
+                )
+            }
+        }
+    }
+
+    Ok(tail)
+}
@@ -0,0 +1,47 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::nthtail_2::result;
+use crate::test::with_process;
+
+#[test]
+fn with_negative_n_errors_badarg() {
+    with_process(|process| {
+        let n = process.integer(-1);
+
+        assert_badarg!(
+            result(n, Term::NIL),
+            format!("n ({}) is not a non-negative integer", n)
+        );
+    });
+}
+
+#[test]
+fn with_n_greater_than_length_errors_badarg() {
+    with_process(|process| {
+        let n = process.integer(2);
+        let list = process.list_from_slice(&[process.integer(1)]);
+
+        assert_badarg!(
+            result(n, list),
+            format!("n ({}) is greater than the length of list ({})", n, list)
+        );
+    });
+}
+
+#[test]
+fn with_zero_returns_list() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[process.integer(1)]);
+
+        assert_eq!(result(process.integer(0), list), Ok(list));
+    });
+}
+
+#[test]
+fn with_n_equal_to_length_returns_empty_list() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[process.integer(1), process.integer(2)]);
+
+        assert_eq!(result(process.integer(2), list), Ok(Term::NIL));
+    });
+}
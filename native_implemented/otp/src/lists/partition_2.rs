@@ -0,0 +1,70 @@
+//! ```erlang
+//! partition(Pred, List) -> partition(Pred, List, [], []).
+//!
+//! partition(_Pred, [], Satisfying, NotSatisfying) ->
+//!   {lists:reverse(Satisfying), lists:reverse(NotSatisfying)};
+//! partition(Pred, [Head | Tail], Satisfying, NotSatisfying) ->
+//!   % label 1
+//!   case Pred(Head) of
+//!     true -> partition(Pred, Tail, [Head | Satisfying], NotSatisfying);
+//!     false -> partition(Pred, Tail, Satisfying, [Head | NotSatisfying])
+//!   end.
+//! ```
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+use crate::lists::reverse_2;
+
+/// Returns `{Satisfying, NotSatisfying}`, where `Satisfying` are the elements of `list` for which
+/// `pred(Element)` returns `true` and `NotSatisfying` are those for which it returns `false`.
+#[native_implemented::function(lists:partition/2)]
+pub fn result(process: &Process, pred: Term, list: Term) -> exception::Result<Term> {
+    let pred = super::function_with_arity("pred", pred, 1)?;
+    super::list_to_vec("list", list)?;
+
+    partition(process, pred, list, Term::NIL, Term::NIL)
+}
+
+// Private
+
+fn partition(
+    process: &Process,
+    pred: Term,
+    list: Term,
+    satisfying: Term,
+    not_satisfying: Term,
+) -> exception::Result<Term> {
+    match list.decode()? {
+        TypedTerm::List(cons) => {
+            process.queue_frame_with_arguments(apply_2::frame_with_arguments(
+                pred,
+                process.list_from_slice(&[cons.head]),
+            ));
+            process.queue_frame_with_arguments(label_1::frame().with_arguments(
+                true,
+                &[
+                    pred,
+                    cons.head,
+                    cons.tail,
+                    process.tuple_from_slice(&[satisfying, not_satisfying]),
+                ],
+            ));
+
+            Ok(Term::NONE)
+        }
+        _ => {
+            let satisfying = reverse_2::result(process, satisfying, Term::NIL)?;
+            let not_satisfying = reverse_2::result(process, not_satisfying, Term::NIL)?;
+
+            Ok(process.tuple_from_slice(&[satisfying, not_satisfying]))
+        }
+    }
+}
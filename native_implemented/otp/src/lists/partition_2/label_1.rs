@@ -0,0 +1,53 @@
+//! ```erlang
+//! % label 1
+//! % pushed to stack: (Pred, Head, Tail, {Satisfying, NotSatisfying})
+//! % returned from call: Satisfied
+//! % full stack: (Satisfied, Pred, Head, Tail, {Satisfying, NotSatisfying})
+//! % returns: {Satisfying, NotSatisfying}
+//! case Satisfied of
+//!   true -> partition(Pred, Tail, [Head | Satisfying], NotSatisfying);
+//!   false -> partition(Pred, Tail, Satisfying, [Head | NotSatisfying])
+//! end
+//! ```
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_try_into_bool;
+
+// Private
+
+#[native_implemented::label]
+fn result(
+    process: &Process,
+    satisfied: Term,
+    pred: Term,
+    head: Term,
+    tail: Term,
+    accs: Term,
+) -> exception::Result<Term> {
+    let accs_tuple: Boxed<Tuple> = accs.try_into().unwrap();
+    let satisfying = accs_tuple[0];
+    let not_satisfying = accs_tuple[1];
+
+    if term_try_into_bool("pred result", satisfied)? {
+        super::partition(
+            process,
+            pred,
+            tail,
+            process.cons(head, satisfying),
+            not_satisfying,
+        )
+    } else {
+        super::partition(
+            process,
+            pred,
+            tail,
+            satisfying,
+            process.cons(head, not_satisfying),
+        )
+    }
+}
@@ -0,0 +1,50 @@
+//! ```erlang
+//! search(_Pred, []) -> false;
+//! search(Pred, [Head | Tail]) ->
+//!   % label 1
+//!   case Pred(Head) of
+//!     true -> {value, Head};
+//!     false -> search(Pred, Tail)
+//!   end.
+//! ```
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+
+/// Returns `{value, Element}` for the first element of `list` for which `pred(Element)` returns
+/// `true`, or `false` if there is none.  `pred` is not called on the elements after that first
+/// element.  `pred` must return a boolean.
+#[native_implemented::function(lists:search/2)]
+pub fn result(process: &Process, pred: Term, list: Term) -> exception::Result<Term> {
+    let pred = super::function_with_arity("pred", pred, 1)?;
+    super::list_to_vec("list", list)?;
+
+    search(process, pred, list)
+}
+
+// Private
+
+fn search(process: &Process, pred: Term, list: Term) -> exception::Result<Term> {
+    match list.decode()? {
+        TypedTerm::List(cons) => {
+            process.queue_frame_with_arguments(apply_2::frame_with_arguments(
+                pred,
+                process.list_from_slice(&[cons.head]),
+            ));
+            process.queue_frame_with_arguments(
+                label_1::frame().with_arguments(true, &[pred, cons.head, cons.tail]),
+            );
+
+            Ok(Term::NONE)
+        }
+        _ => Ok(false.into()),
+    }
+}
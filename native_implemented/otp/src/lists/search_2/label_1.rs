@@ -0,0 +1,34 @@
+//! ```erlang
+//! % label 1
+//! % pushed to stack: (Pred, Head, Tail)
+//! % returned from call: Satisfied
+//! % full stack: (Satisfied, Pred, Head, Tail)
+//! % returns: {value, Head} | false
+//! case Satisfied of
+//!   true -> {value, Head};
+//!   false -> search(Pred, Tail)
+//! end
+//! ```
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_try_into_bool;
+
+// Private
+
+#[native_implemented::label]
+fn result(
+    process: &Process,
+    satisfied: Term,
+    pred: Term,
+    head: Term,
+    tail: Term,
+) -> exception::Result<Term> {
+    if term_try_into_bool("pred result", satisfied)? {
+        Ok(process.tuple_from_slice(&[Atom::str_to_term("value"), head]))
+    } else {
+        super::search(process, pred, tail)
+    }
+}
@@ -0,0 +1,67 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::sort_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_list_errors_badarg() {
+    with_process(|process| {
+        let list = Atom::str_to_term("list");
+
+        assert_badarg!(
+            result(process, list),
+            format!("list ({}) is not a list", list)
+        );
+    });
+}
+
+#[test]
+fn with_improper_list_errors_badarg() {
+    with_process(|process| {
+        let list = process.improper_list_from_slice(&[process.integer(1)], process.integer(2));
+
+        assert_badarg!(
+            result(process, list),
+            format!("list ({}) is improper", list)
+        );
+    });
+}
+
+#[test]
+fn with_mixed_types_sorts_in_term_order() {
+    with_process(|process| {
+        let number = process.integer(2);
+        let atom = Atom::str_to_term("a");
+        let tuple = process.tuple_from_slice(&[number]);
+        let nil = Term::NIL;
+        let list = process.list_from_slice(&[atom]);
+        let binary = process.binary_from_str("b");
+
+        assert_eq!(
+            result(
+                process,
+                process.list_from_slice(&[binary, list, nil, tuple, atom, number])
+            ),
+            Ok(process.list_from_slice(&[number, atom, tuple, nil, list, binary]))
+        );
+    });
+}
+
+#[test]
+fn with_equal_elements_is_stable() {
+    with_process(|process| {
+        let float = process.float(1.0);
+        let integer = process.integer(1);
+        let zero = process.integer(0);
+
+        let sorted = result(process, process.list_from_slice(&[float, integer, zero])).unwrap();
+        let sorted_cons: Boxed<Cons> = sorted.try_into().unwrap();
+        let sorted_vec: Vec<Term> = sorted_cons.into_iter().map(Result::unwrap).collect();
+
+        assert_eq!(sorted_vec[0], zero);
+        assert!(sorted_vec[1].is_float());
+        assert!(sorted_vec[2].is_smallint());
+    });
+}
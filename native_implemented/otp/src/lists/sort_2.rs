@@ -0,0 +1,128 @@
+//! ```erlang
+//! sort(Fun, List) ->
+//!   merge_runs(Fun, [[Element] || Element <- List], []).
+//!
+//! merge_runs(_Fun, [], []) -> [];
+//! merge_runs(_Fun, [], [Run]) -> Run;
+//! merge_runs(Fun, [], Merged) -> merge_runs(Fun, lists:reverse(Merged), []);
+//! merge_runs(Fun, [Run], Merged) -> merge_runs(Fun, [], [Run | Merged]);
+//! merge_runs(Fun, [Left, Right | Pending], Merged) ->
+//!   merge(Fun, Left, Right, [], Pending, Merged).
+//!
+//! merge(Fun, [], Right, Acc, Pending, Merged) ->
+//!   merge_runs(Fun, Pending, [lists:reverse(Acc, Right) | Merged]);
+//! merge(Fun, Left, [], Acc, Pending, Merged) ->
+//!   merge_runs(Fun, Pending, [lists:reverse(Acc, Left) | Merged]);
+//! merge(Fun, [L | LT] = Left, [R | RT] = Right, Acc, Pending, Merged) ->
+//!   % label 1
+//!   case Fun(L, R) of
+//!     true -> merge(Fun, LT, Right, [L | Acc], Pending, Merged);
+//!     false -> merge(Fun, Left, RT, [R | Acc], Pending, Merged)
+//!   end.
+//! ```
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+use crate::lists::reverse_2;
+
+/// Sorts `list` using `fun(A, B)`, which returns `true` if `A` compares less than or equal to
+/// `B`.  The sort is a stable merge sort, so `fun` is called `O(n log n)` times.
+#[native_implemented::function(lists:sort/2)]
+pub fn result(process: &Process, fun: Term, list: Term) -> exception::Result<Term> {
+    let fun = super::function_with_arity("fun", fun, 2)?;
+    let runs: Vec<Term> = super::list_to_vec("list", list)?
+        .into_iter()
+        .map(|element| process.list_from_slice(&[element]))
+        .collect();
+
+    merge_runs(process, fun, process.list_from_slice(&runs), Term::NIL)
+}
+
+// Private
+
+/// Merges the next pair of `pending` runs.  Once there are no more pairs, the next pass starts
+/// over the `merged` runs, until only one run, the sorted list, is left.
+fn merge_runs(
+    process: &Process,
+    fun: Term,
+    mut pending: Term,
+    mut merged: Term,
+) -> exception::Result<Term> {
+    loop {
+        match pending.decode()? {
+            TypedTerm::Nil => match merged.decode()? {
+                TypedTerm::Nil => return Ok(Term::NIL),
+                TypedTerm::List(merged_cons) if merged_cons.tail.is_nil() => {
+                    return Ok(merged_cons.head)
+                }
+                _ => {
+                    pending = reverse_2::result(process, merged, Term::NIL)?;
+                    merged = Term::NIL;
+                }
+            },
+            TypedTerm::List(pending_cons) => match pending_cons.tail.decode()? {
+                TypedTerm::List(rest_cons) => {
+                    return merge(
+                        process,
+                        fun,
+                        pending_cons.head,
+                        rest_cons.head,
+                        Term::NIL,
+                        rest_cons.tail,
+                        merged,
+                    )
+                }
+                _ => {
+                    merged = process.cons(pending_cons.head, merged);
+                    pending = Term::NIL;
+                }
+            },
+            _ => unreachable!("pending ({}) is not a list", pending),
+        }
+    }
+}
+
+/// Merges the `left` and `right` runs onto the reversed `acc`umulator, calling `fun` to compare
+/// the heads of the runs.
+fn merge(
+    process: &Process,
+    fun: Term,
+    left: Term,
+    right: Term,
+    acc: Term,
+    pending: Term,
+    merged: Term,
+) -> exception::Result<Term> {
+    match (left.decode()?, right.decode()?) {
+        (TypedTerm::List(left_cons), TypedTerm::List(right_cons)) => {
+            process.queue_frame_with_arguments(apply_2::frame_with_arguments(
+                fun,
+                process.list_from_slice(&[left_cons.head, right_cons.head]),
+            ));
+            process.queue_frame_with_arguments(label_1::frame().with_arguments(
+                true,
+                &[process.tuple_from_slice(&[fun, left, right, acc, pending, merged])],
+            ));
+
+            Ok(Term::NONE)
+        }
+        (TypedTerm::Nil, _) => {
+            let run = reverse_2::result(process, acc, right)?;
+
+            merge_runs(process, fun, pending, process.cons(run, merged))
+        }
+        _ => {
+            let run = reverse_2::result(process, acc, left)?;
+
+            merge_runs(process, fun, pending, process.cons(run, merged))
+        }
+    }
+}
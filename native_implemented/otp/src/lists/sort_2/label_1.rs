@@ -0,0 +1,59 @@
+//! ```erlang
+//! % label 1
+//! % pushed to stack: ({Fun, Left, Right, Acc, Pending, Merged})
+//! % returned from call: Ordered
+//! % full stack: (Ordered, {Fun, Left, Right, Acc, Pending, Merged})
+//! % returns: Sorted
+//! case Ordered of
+//!   true -> merge(Fun, LT, Right, [L | Acc], Pending, Merged);
+//!   false -> merge(Fun, Left, RT, [R | Acc], Pending, Merged)
+//! end
+//! ```
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_try_into_bool;
+
+// Private
+
+#[native_implemented::label]
+fn result(process: &Process, ordered: Term, state: Term) -> exception::Result<Term> {
+    let ordered = term_try_into_bool("fun result", ordered)?;
+    let state_tuple: Boxed<Tuple> = state.try_into().unwrap();
+    let fun = state_tuple[0];
+    let left = state_tuple[1];
+    let right = state_tuple[2];
+    let acc = state_tuple[3];
+    let pending = state_tuple[4];
+    let merged = state_tuple[5];
+
+    if ordered {
+        let left_cons: Boxed<Cons> = left.try_into().unwrap();
+
+        super::merge(
+            process,
+            fun,
+            left_cons.tail,
+            right,
+            process.cons(left_cons.head, acc),
+            pending,
+            merged,
+        )
+    } else {
+        let right_cons: Boxed<Cons> = right.try_into().unwrap();
+
+        super::merge(
+            process,
+            fun,
+            left,
+            right_cons.tail,
+            process.cons(right_cons.head, acc),
+            pending,
+            merged,
+        )
+    }
+}
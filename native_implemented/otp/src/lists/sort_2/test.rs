@@ -0,0 +1,28 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::sort_2::result;
+use crate::test::{anonymous_1, with_process};
+
+#[test]
+fn without_function_errors_badarg() {
+    with_process(|process| {
+        let fun = Atom::str_to_term("fun");
+
+        assert_badarg!(
+            result(process, fun, Term::NIL),
+            format!("fun ({}) is not a function with arity 2", fun)
+        );
+    });
+}
+
+#[test]
+fn with_function_with_wrong_arity_errors_badarg() {
+    with_process(|process| {
+        let fun = anonymous_1::anonymous_closure(process);
+
+        assert_badarg!(
+            result(process, fun, Term::NIL),
+            format!("fun ({}) is not a function with arity 2", fun)
+        );
+    });
+}
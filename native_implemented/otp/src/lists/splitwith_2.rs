@@ -0,0 +1,59 @@
+//! ```erlang
+//! splitwith(Pred, List) -> splitwith(Pred, List, []).
+//!
+//! splitwith(_Pred, [], Acc) -> {lists:reverse(Acc), []};
+//! splitwith(Pred, [Head | Tail] = List, Acc) ->
+//!   % label 1
+//!   case Pred(Head) of
+//!     true -> splitwith(Pred, Tail, [Head | Acc]);
+//!     false -> {lists:reverse(Acc), List}
+//!   end.
+//! ```
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+use crate::lists::reverse_2;
+
+/// Splits `list` into `{Taken, Rest}`, where `Taken` is the longest prefix for which
+/// `pred(Element)` returns `true` and `Rest` starts at the first element for which it returns
+/// `false`.  `pred` must return a boolean.
+#[native_implemented::function(lists:splitwith/2)]
+pub fn result(process: &Process, pred: Term, list: Term) -> exception::Result<Term> {
+    let pred = super::function_with_arity("pred", pred, 1)?;
+    super::list_to_vec("list", list)?;
+
+    splitwith(process, pred, list, Term::NIL)
+}
+
+// Private
+
+fn splitwith(process: &Process, pred: Term, list: Term, acc: Term) -> exception::Result<Term> {
+    match list.decode()? {
+        TypedTerm::List(cons) => {
+            process.queue_frame_with_arguments(apply_2::frame_with_arguments(
+                pred,
+                process.list_from_slice(&[cons.head]),
+            ));
+            process.queue_frame_with_arguments(
+                label_1::frame().with_arguments(true, &[pred, list, acc]),
+            );
+
+            Ok(Term::NONE)
+        }
+        _ => split(process, acc, Term::NIL),
+    }
+}
+
+fn split(process: &Process, acc: Term, rest: Term) -> exception::Result<Term> {
+    let taken = reverse_2::result(process, acc, Term::NIL)?;
+
+    Ok(process.tuple_from_slice(&[taken, rest]))
+}
@@ -0,0 +1,38 @@
+//! ```erlang
+//! % label 1
+//! % pushed to stack: (Pred, List, Acc)
+//! % returned from call: Satisfied
+//! % full stack: (Satisfied, Pred, List, Acc)
+//! % returns: {Taken, Rest}
+//! case Satisfied of
+//!   true -> splitwith(Pred, tl(List), [hd(List) | Acc]);
+//!   false -> {lists:reverse(Acc), List}
+//! end
+//! ```
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_try_into_bool;
+
+// Private
+
+#[native_implemented::label]
+fn result(
+    process: &Process,
+    satisfied: Term,
+    pred: Term,
+    list: Term,
+    acc: Term,
+) -> exception::Result<Term> {
+    if term_try_into_bool("pred result", satisfied)? {
+        let cons: Boxed<Cons> = list.try_into().unwrap();
+
+        super::splitwith(process, pred, cons.tail, process.cons(cons.head, acc))
+    } else {
+        super::split(process, acc, list)
+    }
+}
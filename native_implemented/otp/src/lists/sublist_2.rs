@@ -0,0 +1,15 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::sublist_3;
+
+/// Returns the first `len` elements of `list`, or all of `list` if it has fewer than `len`
+/// elements.
+#[native_implemented::function(lists:sublist/2)]
+pub fn result(process: &Process, list: Term, len: Term) -> exception::Result<Term> {
+    sublist_3::result(process, list, process.integer(1), len)
+}
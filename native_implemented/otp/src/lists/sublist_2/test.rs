@@ -0,0 +1,37 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::sublist_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_list_errors_badarg() {
+    with_process(|process| {
+        let list = Atom::str_to_term("list");
+
+        assert_badarg!(
+            result(process, list, process.integer(1)),
+            format!("list ({}) is not a list", list)
+        );
+    });
+}
+
+#[test]
+fn with_len_less_than_length_returns_prefix() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[process.integer(1), process.integer(2)]);
+
+        assert_eq!(
+            result(process, list, process.integer(1)),
+            Ok(process.list_from_slice(&[process.integer(1)]))
+        );
+    });
+}
+
+#[test]
+fn with_len_greater_than_length_returns_list() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[process.integer(1), process.integer(2)]);
+
+        assert_eq!(result(process, list, process.integer(3)), Ok(list));
+    });
+}
@@ -0,0 +1,38 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::*;
+
+/// Returns up to `len` elements of `list`, starting at the 1-based position `start`.  `start`
+/// may be at most one past the end of `list`, but `len` may exceed the elements available.
+#[native_implemented::function(lists:sublist/3)]
+pub fn result(process: &Process, list: Term, start: Term, len: Term) -> exception::Result<Term> {
+    let start_index = term_try_into_one_based_index(start)?;
+    let len_usize: usize = len
+        .try_into()
+        .with_context(|| term_is_not_non_negative_integer("len", len))?;
+    let vec = super::list_to_vec("list", list)?;
+
+    let skip: usize = start_index.into();
+
+    if skip <= vec.len() {
+        let sublist: Vec<Term> = vec.into_iter().skip(skip).take(len_usize).collect();
+
+        Ok(process.list_from_slice(&sublist))
+    } else {
+        Err(anyhow!(
+            "start ({}) is more than one past the end of list ({})",
+            start,
+            list
+        )
+        .into())
+    }
+}
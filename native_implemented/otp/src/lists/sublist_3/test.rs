@@ -0,0 +1,69 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::sublist_3::result;
+use crate::test::with_process;
+
+#[test]
+fn without_one_based_start_errors_badarg() {
+    with_process(|process| {
+        let start = process.integer(0);
+
+        assert_badarg!(
+            result(process, Term::NIL, start, process.integer(1)),
+            format!("index ({}) is not a 1-based integer", start)
+        );
+    });
+}
+
+#[test]
+fn with_negative_len_errors_badarg() {
+    with_process(|process| {
+        let len = process.integer(-1);
+
+        assert_badarg!(
+            result(process, Term::NIL, process.integer(1), len),
+            format!("len ({}) is not a non-negative integer", len)
+        );
+    });
+}
+
+#[test]
+fn with_start_past_end_errors_badarg() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[process.integer(1)]);
+        let start = process.integer(3);
+
+        assert_badarg!(
+            result(process, list, start, process.integer(1)),
+            format!(
+                "start ({}) is more than one past the end of list ({})",
+                start, list
+            )
+        );
+    });
+}
+
+#[test]
+fn with_start_one_past_end_returns_empty_list() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[process.integer(1)]);
+
+        assert_eq!(
+            result(process, list, process.integer(2), process.integer(1)),
+            Ok(Term::NIL)
+        );
+    });
+}
+
+#[test]
+fn with_len_past_end_returns_available_elements() {
+    with_process(|process| {
+        let list =
+            process.list_from_slice(&[process.integer(1), process.integer(2), process.integer(3)]);
+
+        assert_eq!(
+            result(process, list, process.integer(2), process.integer(5)),
+            Ok(process.list_from_slice(&[process.integer(2), process.integer(3)]))
+        );
+    });
+}
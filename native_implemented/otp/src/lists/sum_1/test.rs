@@ -0,0 +1,30 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::sum_1::result;
+use crate::test::with_process;
+
+#[test]
+fn with_empty_list_returns_zero() {
+    with_process(|process| {
+        assert_eq!(result(process, Term::NIL), Ok(process.integer(0)));
+    });
+}
+
+#[test]
+fn with_integers_and_float_returns_float() {
+    with_process(|process| {
+        let list =
+            process.list_from_slice(&[process.integer(1), process.float(1.5), process.integer(2)]);
+
+        assert_eq!(result(process, list), Ok(process.float(4.5)));
+    });
+}
+
+#[test]
+fn with_non_number_errors_badarith() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[process.integer(1), Atom::str_to_term("a")]);
+
+        assert_badarith!(result(process, list));
+    });
+}
@@ -0,0 +1,36 @@
+//! ```erlang
+//! % label 1
+//! % pushed to stack: (Pred, Head, Tail, Acc)
+//! % returned from call: Satisfied
+//! % full stack: (Satisfied, Pred, Head, Tail, Acc)
+//! % returns: Taken
+//! case Satisfied of
+//!   true -> takewhile(Pred, Tail, [Head | Acc]);
+//!   false -> lists:reverse(Acc)
+//! end
+//! ```
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::reverse_2;
+use crate::runtime::context::term_try_into_bool;
+
+// Private
+
+#[native_implemented::label]
+fn result(
+    process: &Process,
+    satisfied: Term,
+    pred: Term,
+    head: Term,
+    tail: Term,
+    acc: Term,
+) -> exception::Result<Term> {
+    if term_try_into_bool("pred result", satisfied)? {
+        super::takewhile(process, pred, tail, process.cons(head, acc))
+    } else {
+        reverse_2::result(process, acc, Term::NIL)
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Removes elements that are exactly equal (`=:=`) to an earlier element of `list`.  Unlike
+/// `lists:usort/1`, `list` is not sorted, so the first occurrence of each element is kept in its
+/// original order.
+#[native_implemented::function(lists:uniq/1)]
+pub fn result(process: &Process, list: Term) -> exception::Result<Term> {
+    let mut seen: HashSet<ExactTerm> = HashSet::new();
+    let mut unique: Vec<Term> = Vec::new();
+
+    for element in super::list_to_vec("list", list)? {
+        if seen.insert(ExactTerm::new(element)?) {
+            unique.push(element);
+        }
+    }
+
+    Ok(process.list_from_slice(&unique))
+}
+
+// Private
+
+/// A `Term` that compares with `=:=` instead of `==`, so that `1` and `1.0` are both kept.
+struct ExactTerm(TypedTerm);
+
+impl ExactTerm {
+    fn new(term: Term) -> exception::Result<Self> {
+        Ok(Self(term.decode()?))
+    }
+}
+
+impl Eq for ExactTerm {}
+
+impl Hash for ExactTerm {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl PartialEq for ExactTerm {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.exact_eq(&other.0)
+    }
+}
@@ -0,0 +1,56 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::uniq_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_list_errors_badarg() {
+    with_process(|process| {
+        let list = Atom::str_to_term("list");
+
+        assert_badarg!(
+            result(process, list),
+            format!("list ({}) is not a list", list)
+        );
+    });
+}
+
+#[test]
+fn with_duplicates_keeps_first_occurrence_in_order() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[
+            process.integer(3),
+            process.integer(1),
+            process.integer(3),
+            process.integer(2),
+            process.integer(1),
+        ]);
+
+        assert_eq!(
+            result(process, list),
+            Ok(process.list_from_slice(&[
+                process.integer(3),
+                process.integer(1),
+                process.integer(2)
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_compare_equal_elements_keeps_both() {
+    with_process(|process| {
+        let integer = process.integer(1);
+        let float = process.float(1.0);
+
+        let unique = result(process, process.list_from_slice(&[integer, float])).unwrap();
+        let unique_cons: Boxed<Cons> = unique.try_into().unwrap();
+        let unique_vec: Vec<Term> = unique_cons.into_iter().map(Result::unwrap).collect();
+
+        assert_eq!(unique_vec.len(), 2);
+        assert!(unique_vec[0].is_smallint());
+        assert!(unique_vec[1].is_float());
+    });
+}
@@ -0,0 +1,60 @@
+//! ```erlang
+//! uniq(Fun, List) -> uniq(Fun, List, [], []).
+//!
+//! uniq(_Fun, [], _Keys, Acc) -> lists:reverse(Acc);
+//! uniq(Fun, [Head | Tail], Keys, Acc) ->
+//!   % label 1
+//!   Key = Fun(Head),
+//!   case lists:member(Key, Keys) of
+//!     true -> uniq(Fun, Tail, Keys, Acc);
+//!     false -> uniq(Fun, Tail, [Key | Keys], [Head | Acc])
+//!   end.
+//! ```
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+use crate::lists::reverse_2;
+
+/// Removes elements of `list` whose `fun(Element)` is exactly equal (`=:=`) to that of an earlier
+/// element.  Unlike `lists:usort/1`, `list` is not sorted, so the first element with each key is
+/// kept in its original order.
+#[native_implemented::function(lists:uniq/2)]
+pub fn result(process: &Process, fun: Term, list: Term) -> exception::Result<Term> {
+    let fun = super::function_with_arity("fun", fun, 1)?;
+    super::list_to_vec("list", list)?;
+
+    uniq(process, fun, list, Term::NIL, Term::NIL)
+}
+
+// Private
+
+fn uniq(
+    process: &Process,
+    fun: Term,
+    list: Term,
+    keys: Term,
+    acc: Term,
+) -> exception::Result<Term> {
+    match list.decode()? {
+        TypedTerm::List(cons) => {
+            process.queue_frame_with_arguments(apply_2::frame_with_arguments(
+                fun,
+                process.list_from_slice(&[cons.head]),
+            ));
+            process.queue_frame_with_arguments(
+                label_1::frame().with_arguments(true, &[fun, cons.head, cons.tail, keys, acc]),
+            );
+
+            Ok(Term::NONE)
+        }
+        _ => reverse_2::result(process, acc, Term::NIL),
+    }
+}
@@ -0,0 +1,42 @@
+//! ```erlang
+//! % label 1
+//! % pushed to stack: (Fun, Head, Tail, Keys, Acc)
+//! % returned from call: Key
+//! % full stack: (Key, Fun, Head, Tail, Keys, Acc)
+//! % returns: Unique
+//! case lists:member(Key, Keys) of
+//!   true -> uniq(Fun, Tail, Keys, Acc);
+//!   false -> uniq(Fun, Tail, [Key | Keys], [Head | Acc])
+//! end
+//! ```
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+// Private
+
+#[native_implemented::label]
+fn result(
+    process: &Process,
+    key: Term,
+    fun: Term,
+    head: Term,
+    tail: Term,
+    keys: Term,
+    acc: Term,
+) -> exception::Result<Term> {
+    let keys_vec = super::super::list_to_vec("keys", keys)?;
+
+    if super::super::contains_exactly(&keys_vec, key)? {
+        super::uniq(process, fun, tail, keys, acc)
+    } else {
+        super::uniq(
+            process,
+            fun,
+            tail,
+            process.cons(key, keys),
+            process.cons(head, acc),
+        )
+    }
+}
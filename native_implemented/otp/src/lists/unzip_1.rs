@@ -0,0 +1,37 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Splits `list1` of `{Element1, Element2}` tuples into `{List1, List2}`.
+#[native_implemented::function(lists:unzip/1)]
+pub fn result(process: &Process, list1: Term) -> exception::Result<Term> {
+    let mut vec1: Vec<Term> = Vec::new();
+    let mut vec2: Vec<Term> = Vec::new();
+
+    for element in super::list_to_vec("list1", list1)? {
+        match element.decode()? {
+            TypedTerm::Tuple(tuple) if tuple.len() == 2 => {
+                vec1.push(tuple[0]);
+                vec2.push(tuple[1]);
+            }
+            _ => {
+                return Err(TypeError)
+                    .context(format!(
+                        "list1 ({}) element ({}) is not a 2-tuple",
+                        list1, element
+                    ))
+                    .map_err(From::from)
+            }
+        }
+    }
+
+    Ok(process.tuple_from_slice(&[
+        process.list_from_slice(&vec1),
+        process.list_from_slice(&vec2),
+    ]))
+}
@@ -0,0 +1,41 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::unzip_1::result;
+use crate::test::with_process;
+
+#[test]
+fn with_list_of_2_tuples_returns_tuple_of_lists() {
+    with_process(|process| {
+        let a = Atom::str_to_term("a");
+        let b = Atom::str_to_term("b");
+        let one = process.integer(1);
+        let two = process.integer(2);
+
+        assert_eq!(
+            result(
+                process,
+                process.list_from_slice(&[
+                    process.tuple_from_slice(&[a, one]),
+                    process.tuple_from_slice(&[b, two])
+                ])
+            ),
+            Ok(process.tuple_from_slice(&[
+                process.list_from_slice(&[a, b]),
+                process.list_from_slice(&[one, two])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn without_2_tuple_element_errors_badarg() {
+    with_process(|process| {
+        let element = process.tuple_from_slice(&[process.integer(1)]);
+        let list1 = process.list_from_slice(&[element]);
+
+        assert_badarg!(
+            result(process, list1),
+            format!("list1 ({}) element ({}) is not a 2-tuple", list1, element)
+        );
+    });
+}
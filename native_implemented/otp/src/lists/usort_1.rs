@@ -0,0 +1,41 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::cmp::Ordering;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Sorts `list` in standard term order, removing elements that are exactly equal (`=:=`) to an
+/// earlier element.  Elements that only compare equal (`==`), such as `1` and `1.0`, are not
+/// duplicates, so both are kept in their order from `list`.
+#[native_implemented::function(lists:usort/1)]
+pub fn result(process: &Process, list: Term) -> exception::Result<Term> {
+    let mut vec = super::list_to_vec("list", list)?;
+    vec.sort();
+
+    let mut unique: Vec<Term> = Vec::with_capacity(vec.len());
+    // The start of the elements in `unique` that compare equal to the current element
+    let mut equal_start = 0;
+
+    for element in vec {
+        let element_typed_term = element.decode()?;
+
+        if let Some(last) = unique.last() {
+            if last.cmp(&element) != Ordering::Equal {
+                equal_start = unique.len();
+            }
+        }
+
+        let is_duplicate = unique[equal_start..]
+            .iter()
+            .any(|kept| kept.decode().unwrap().exact_eq(&element_typed_term));
+
+        if !is_duplicate {
+            unique.push(element);
+        }
+    }
+
+    Ok(process.list_from_slice(&unique))
+}
@@ -0,0 +1,47 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::usort_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_list_errors_badarg() {
+    with_process(|process| {
+        let list = process.integer(1);
+
+        assert_badarg!(
+            result(process, list),
+            format!("list ({}) is not a list", list)
+        );
+    });
+}
+
+#[test]
+fn with_exact_duplicates_removes_duplicates() {
+    with_process(|process| {
+        let a = Atom::str_to_term("a");
+        let b = Atom::str_to_term("b");
+
+        assert_eq!(
+            result(process, process.list_from_slice(&[b, a, b, a])),
+            Ok(process.list_from_slice(&[a, b]))
+        );
+    });
+}
+
+#[test]
+fn with_integer_and_equal_float_keeps_both() {
+    with_process(|process| {
+        let integer = process.integer(1);
+        let float = process.float(1.0);
+
+        let unique = result(process, process.list_from_slice(&[integer, float, integer])).unwrap();
+        let unique_cons: Boxed<Cons> = unique.try_into().unwrap();
+        let unique_vec: Vec<Term> = unique_cons.into_iter().map(Result::unwrap).collect();
+
+        assert_eq!(unique_vec.len(), 2);
+        assert!(unique_vec[0].is_smallint());
+        assert!(unique_vec[1].is_float());
+    });
+}
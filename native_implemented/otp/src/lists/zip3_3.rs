@@ -0,0 +1,19 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Combines the elements of `list1`, `list2`, and `list3`, which must be the same length, into a
+/// list of `{Element1, Element2, Element3}` tuples.
+#[native_implemented::function(lists:zip3/3)]
+pub fn result(process: &Process, list1: Term, list2: Term, list3: Term) -> exception::Result<Term> {
+    let vecs =
+        super::lists_to_equal_length_vecs(&["list1", "list2", "list3"], &[list1, list2, list3])?;
+    let tuples: Vec<Term> = (0..vecs[0].len())
+        .map(|index| process.tuple_from_slice(&[vecs[0][index], vecs[1][index], vecs[2][index]]))
+        .collect();
+
+    Ok(process.list_from_slice(&tuples))
+}
@@ -0,0 +1,39 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::zip3_3::result;
+use crate::test::with_process;
+
+#[test]
+fn with_same_length_lists_returns_list_of_tuples() {
+    with_process(|process| {
+        let a = Atom::str_to_term("a");
+        let one = process.integer(1);
+        let binary = process.binary_from_str("x");
+
+        assert_eq!(
+            result(
+                process,
+                process.list_from_slice(&[a]),
+                process.list_from_slice(&[one]),
+                process.list_from_slice(&[binary])
+            ),
+            Ok(process.list_from_slice(&[process.tuple_from_slice(&[a, one, binary])]))
+        );
+    });
+}
+
+#[test]
+fn with_different_length_lists_errors_badarg() {
+    with_process(|process| {
+        let list1 = process.list_from_slice(&[process.integer(1)]);
+        let list3 = process.list_from_slice(&[process.integer(3), process.integer(4)]);
+
+        assert_badarg!(
+            result(process, list1, list1, list3),
+            format!(
+                "lists ({}, {}, {}) do not have the same length",
+                list1, list1, list3
+            )
+        );
+    });
+}
@@ -0,0 +1,20 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Combines the elements of `list1` and `list2`, which must be the same length, into a list of
+/// `{Element1, Element2}` tuples.
+#[native_implemented::function(lists:zip/2)]
+pub fn result(process: &Process, list1: Term, list2: Term) -> exception::Result<Term> {
+    let vecs = super::lists_to_equal_length_vecs(&["list1", "list2"], &[list1, list2])?;
+    let tuples: Vec<Term> = vecs[0]
+        .iter()
+        .zip(vecs[1].iter())
+        .map(|(element1, element2)| process.tuple_from_slice(&[*element1, *element2]))
+        .collect();
+
+    Ok(process.list_from_slice(&tuples))
+}
@@ -0,0 +1,51 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::zip_2::result;
+use crate::test::with_process;
+
+#[test]
+fn with_same_length_lists_returns_list_of_tuples() {
+    with_process(|process| {
+        let a = Atom::str_to_term("a");
+        let b = Atom::str_to_term("b");
+        let one = process.integer(1);
+        let two = process.integer(2);
+
+        assert_eq!(
+            result(
+                process,
+                process.list_from_slice(&[a, b]),
+                process.list_from_slice(&[one, two])
+            ),
+            Ok(process.list_from_slice(&[
+                process.tuple_from_slice(&[a, one]),
+                process.tuple_from_slice(&[b, two])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_different_length_lists_errors_badarg() {
+    with_process(|process| {
+        let list1 = process.list_from_slice(&[process.integer(1)]);
+        let list2 = process.list_from_slice(&[process.integer(1), process.integer(2)]);
+
+        assert_badarg!(
+            result(process, list1, list2),
+            format!("lists ({}, {}) do not have the same length", list1, list2)
+        );
+    });
+}
+
+#[test]
+fn without_list_errors_badarg() {
+    with_process(|process| {
+        let list2 = Atom::str_to_term("list");
+
+        assert_badarg!(
+            result(process, Term::NIL, list2),
+            format!("list2 ({}) is not a list", list2)
+        );
+    });
+}
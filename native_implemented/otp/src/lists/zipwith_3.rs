@@ -0,0 +1,61 @@
+//! ```erlang
+//! zipwith(Combine, List1, List2) -> zipwith(Combine, List1, List2, []).
+//!
+//! zipwith(_Combine, [], [], Acc) -> lists:reverse(Acc);
+//! zipwith(Combine, [Head1 | Tail1], [Head2 | Tail2], Acc) ->
+//!   % label 1
+//!   Combined = Combine(Head1, Head2),
+//!   zipwith(Combine, Tail1, Tail2, [Combined | Acc]).
+//! ```
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+use crate::lists::reverse_2;
+
+/// Combines the elements of `list1` and `list2`, which must be the same length, into a list of
+/// `combine(Element1, Element2)`.
+#[native_implemented::function(lists:zipwith/3)]
+pub fn result(
+    process: &Process,
+    combine: Term,
+    list1: Term,
+    list2: Term,
+) -> exception::Result<Term> {
+    let combine = super::function_with_arity("combine", combine, 2)?;
+    super::lists_to_equal_length_vecs(&["list1", "list2"], &[list1, list2])?;
+
+    zipwith(process, combine, list1, list2, Term::NIL)
+}
+
+// Private
+
+fn zipwith(
+    process: &Process,
+    combine: Term,
+    list1: Term,
+    list2: Term,
+    acc: Term,
+) -> exception::Result<Term> {
+    match (list1.decode()?, list2.decode()?) {
+        (TypedTerm::List(cons1), TypedTerm::List(cons2)) => {
+            process.queue_frame_with_arguments(apply_2::frame_with_arguments(
+                combine,
+                process.list_from_slice(&[cons1.head, cons2.head]),
+            ));
+            process.queue_frame_with_arguments(
+                label_1::frame().with_arguments(true, &[combine, cons1.tail, cons2.tail, acc]),
+            );
+
+            Ok(Term::NONE)
+        }
+        _ => reverse_2::result(process, acc, Term::NIL),
+    }
+}
@@ -0,0 +1,26 @@
+//! ```erlang
+//! % label 1
+//! % pushed to stack: (Combine, Tail1, Tail2, Acc)
+//! % returned from call: Combined
+//! % full stack: (Combined, Combine, Tail1, Tail2, Acc)
+//! % returns: CombinedList
+//! zipwith(Combine, Tail1, Tail2, [Combined | Acc])
+//! ```
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+// Private
+
+#[native_implemented::label]
+fn result(
+    process: &Process,
+    combined: Term,
+    combine: Term,
+    tail1: Term,
+    tail2: Term,
+    acc: Term,
+) -> exception::Result<Term> {
+    super::zipwith(process, combine, tail1, tail2, process.cons(combined, acc))
+}
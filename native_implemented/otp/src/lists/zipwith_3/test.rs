@@ -0,0 +1,16 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::zipwith_3::result;
+use crate::test::{anonymous_1, with_process};
+
+#[test]
+fn without_function_with_arity_2_errors_badarg() {
+    with_process(|process| {
+        let combine = anonymous_1::anonymous_closure(process);
+
+        assert_badarg!(
+            result(process, combine, Term::NIL, Term::NIL),
+            format!("combine ({}) is not a function with arity 2", combine)
+        );
+    });
+}
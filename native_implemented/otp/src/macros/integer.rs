@@ -89,37 +89,17 @@ macro_rules! bitshift_infix_operator {
                 let shift_isize: isize = term_try_into_isize!($shift).map_err(ArcError::new).map_err(|source| badarith(Trace::capture(), Some(source)))?;
 
                 // Rust doesn't support negative shift, so negative left shifts need to be right shifts
-                if 0 <= shift_isize {
+                let shifted_term = if 0 <= shift_isize {
                     let shift_usize = shift_isize as usize;
 
-                    if shift_usize <= MAX_SHIFT {
-                        let shifted = integer_isize $positive shift_usize;
-                        let shifted_term = $process.integer(shifted);
-
-                        Some(shifted_term)
-                    } else {
-                        let big_int: BigInt = integer_isize.into();
-                        let shifted = big_int $positive shift_usize;
-                        let shifted_term = $process.integer(shifted);
-
-                        Some(shifted_term)
-                    }
+                    small_integer_shift!(integer_isize, shift_usize, $process, $positive, $negative)
                 } else {
                     let shift_usize = (-shift_isize) as usize;
 
-                    if shift_usize <= MAX_SHIFT {
-                        let shifted = integer_isize $negative shift_usize;
-                        let shifted_term = $process.integer(shifted);
-
-                        Some(shifted_term)
-                    } else {
-                        let big_int: BigInt = integer_isize.into();
-                        let shifted = big_int $negative shift_usize;
-                        let shifted_term = $process.integer(shifted);
+                    small_integer_shift!(integer_isize, shift_usize, $process, $negative, $positive)
+                };
 
-                        Some(shifted_term)
-                    }
-                }
+                Some(shifted_term)
             }
             TypedTerm::BigInteger(integer_big_integer) => {
                 let big_int = integer_big_integer.as_ref();
@@ -216,3 +196,24 @@ macro_rules! integer_infix_operator {
         }.map_err(|error| error.into())
     }};
 }
+
+/// Shifts `isize` by `shift` with `operator`, falling back to `BigInt` when `shift` or the result
+/// would not fit in an `isize`.  The `isize` result only fits if shifting it back with `inverse`
+/// restores `isize`, as Rust silently discards bits shifted out to the left.
+macro_rules! small_integer_shift {
+    // A right shift can never overflow, so there is no need to check that it round-trips.  Shifting
+    // by `MAX_SHIFT` already leaves only the sign.
+    ($isize:ident, $shift:ident, $process:ident, >>, <<) => {{
+        $process.integer($isize >> $shift.min(MAX_SHIFT))
+    }};
+    ($isize:ident, $shift:ident, $process:ident, $operator:tt, $inverse:tt) => {{
+        if $shift <= MAX_SHIFT && (($isize $operator $shift) $inverse $shift) == $isize {
+            $process.integer($isize $operator $shift)
+        } else {
+            let big_int: BigInt = $isize.into();
+            let shifted = big_int $operator $shift;
+
+            $process.integer(shifted)
+        }
+    }};
+}
@@ -126,9 +126,9 @@ macro_rules! number_to_integer {
             match number.into() {
                 NumberToInteger::Integer(integer) => Ok(integer),
                 NumberToInteger::F64(f) => {
-                    let ceiling = f.$f();
+                    let rounded = f.$f();
 
-                    Ok(f64_to_integer(process, ceiling))
+                    Ok(f64_to_integer(process, rounded))
                 }
                 NumberToInteger::NotANumber => Err(TypeError)
                     .context(term_is_not_number!(number))
@@ -0,0 +1,47 @@
+//! Floating-point functions.  Every function accepts integers and floats and returns a float.
+//!
+//! Like C-BEAM, arguments that aren't numbers are `badarg`, while arguments outside a function's
+//! domain, such as `sqrt(-1)`, and results that overflow are `badarith`.
+pub mod atan2_2;
+pub mod cos_1;
+pub mod exp_1;
+pub mod log_1;
+pub mod pi_0;
+pub mod pow_2;
+pub mod sin_1;
+pub mod sqrt_1;
+pub mod tan_1;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception::{self, *};
+use liblumen_alloc::erts::process::trace::Trace;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_is_not_number;
+
+fn module() -> Atom {
+    Atom::from_str("math")
+}
+
+/// Returns `f` as a float, or `badarith` with the `context` if `f` is NaN or infinite.
+fn f64_to_float<C>(process: &Process, f: f64, context: C) -> exception::Result<Term>
+where
+    C: FnOnce() -> String,
+{
+    if f.is_finite() {
+        Ok(process.float(f))
+    } else {
+        Err(badarith(Trace::capture(), Some(anyhow!(context()).into())).into())
+    }
+}
+
+fn term_try_into_f64(name: &str, value: Term) -> exception::Result<f64> {
+    value
+        .try_into()
+        .with_context(|| term_is_not_number(name, value))
+        .map_err(From::from)
+}
@@ -0,0 +1,15 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::term_try_into_f64;
+
+/// The angle in radians, in `-pi..=pi`, of the point (`x`, `y`), so unlike `atan(y / x)`, the
+/// signs of both `y` and `x` determine the quadrant
+#[native_implemented::function(math:atan2/2)]
+pub fn result(process: &Process, y: Term, x: Term) -> exception::Result<Term> {
+    let y_f64 = term_try_into_f64("y", y)?;
+    let x_f64 = term_try_into_f64("x", x)?;
+
+    Ok(process.float(y_f64.atan2(x_f64)))
+}
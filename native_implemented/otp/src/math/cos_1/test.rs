@@ -0,0 +1,24 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::math::cos_1::result;
+use crate::test::with_process;
+
+#[test]
+fn with_zero_returns_one() {
+    with_process(|process| {
+        assert_eq!(result(process, process.integer(0)), Ok(process.float(1.0)));
+        assert_eq!(result(process, process.float(0.0)), Ok(process.float(1.0)));
+    });
+}
+
+#[test]
+fn without_number_errors_badarg() {
+    with_process(|process| {
+        let x = Atom::str_to_term("zero");
+
+        assert_badarg!(
+            result(process, x),
+            "x (zero) is not a number (integer or float)"
+        );
+    });
+}
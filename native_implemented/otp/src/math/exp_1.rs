@@ -0,0 +1,15 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::{f64_to_float, term_try_into_f64};
+
+/// `e` raised to the power of `x`
+#[native_implemented::function(math:exp/1)]
+pub fn result(process: &Process, x: Term) -> exception::Result<Term> {
+    let x_f64 = term_try_into_f64("x", x)?;
+
+    f64_to_float(process, x_f64.exp(), || {
+        format!("exp(x ({})) is too large for a float", x)
+    })
+}
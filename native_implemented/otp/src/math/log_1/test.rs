@@ -0,0 +1,27 @@
+use crate::math::log_1::result;
+use crate::test::with_process;
+
+#[test]
+fn with_zero_errors_badarith() {
+    with_process(|process| {
+        assert_badarith!(result(process, process.integer(0)));
+        assert_badarith!(result(process, process.float(0.0)));
+    });
+}
+
+#[test]
+fn with_negative_number_errors_badarith() {
+    with_process(|process| {
+        assert_badarith!(result(process, process.integer(-1)));
+    });
+}
+
+#[test]
+fn with_e_returns_one() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.float(std::f64::consts::E)),
+            Ok(process.float(1.0))
+        );
+    });
+}
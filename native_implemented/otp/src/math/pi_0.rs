@@ -0,0 +1,10 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(math:pi/0)]
+pub fn result(process: &Process) -> Term {
+    process.float(std::f64::consts::PI)
+}
@@ -0,0 +1,12 @@
+use crate::math::pi_0::result;
+use crate::test::with_process;
+
+#[test]
+fn returns_pi_to_full_float_precision() {
+    with_process(|process| {
+        let pi = result(process);
+
+        assert_eq!(pi, process.float(std::f64::consts::PI));
+        assert_eq!(pi.to_string(), "3.141592653589793");
+    });
+}
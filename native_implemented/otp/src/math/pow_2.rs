@@ -0,0 +1,19 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::{f64_to_float, term_try_into_f64};
+
+/// `x` raised to the power of `y`
+#[native_implemented::function(math:pow/2)]
+pub fn result(process: &Process, x: Term, y: Term) -> exception::Result<Term> {
+    let x_f64 = term_try_into_f64("x", x)?;
+    let y_f64 = term_try_into_f64("y", y)?;
+
+    f64_to_float(process, x_f64.powf(y_f64), || {
+        format!(
+            "x ({}) raised to y ({}) is not a real number or is too large for a float",
+            x, y
+        )
+    })
+}
@@ -0,0 +1,13 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::term_try_into_f64;
+
+/// `x` is in radians
+#[native_implemented::function(math:sin/1)]
+pub fn result(process: &Process, x: Term) -> exception::Result<Term> {
+    let x_f64 = term_try_into_f64("x", x)?;
+
+    Ok(process.float(x_f64.sin()))
+}
@@ -0,0 +1,15 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::{f64_to_float, term_try_into_f64};
+
+#[native_implemented::function(math:sqrt/1)]
+pub fn result(process: &Process, x: Term) -> exception::Result<Term> {
+    let x_f64 = term_try_into_f64("x", x)?;
+
+    f64_to_float(process, x_f64.sqrt(), || format!("x ({}) is negative", x))
+}
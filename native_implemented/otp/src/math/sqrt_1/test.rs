@@ -0,0 +1,58 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use crate::math::sqrt_1::result;
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_number_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_number(arc_process.clone()),
+            )
+        },
+        |(arc_process, x)| {
+            prop_assert_badarg!(
+                result(&arc_process, x),
+                format!("x ({}) is not a number (integer or float)", x)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_negative_number_errors_badarith() {
+    with_process(|process| {
+        assert_badarith!(result(process, process.integer(-1)));
+        assert_badarith!(result(process, process.float(-0.5)));
+    });
+}
+
+#[test]
+fn with_integer_returns_float() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.integer(2)),
+            Ok(process.float(std::f64::consts::SQRT_2))
+        );
+    });
+}
+
+#[test]
+fn with_float_returns_float() {
+    run!(
+        |arc_process| (Just(arc_process.clone()), 0.0..=std::f64::MAX),
+        |(arc_process, f)| {
+            prop_assert_eq!(
+                result(&arc_process, arc_process.float(f)),
+                Ok(arc_process.float(f.sqrt()))
+            );
+
+            Ok(())
+        },
+    );
+}
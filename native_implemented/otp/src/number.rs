@@ -1,8 +1,20 @@
 use num_bigint::BigInt;
 
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
 pub enum Operands {
     Bad,
     ISizes(isize, isize),
     Floats(f64, f64),
     BigInts(BigInt, BigInt),
 }
+
+/// Negates `i`, promoting to a big integer when the negation does not fit in a small integer, such
+/// as when negating `SmallInteger::MIN_VALUE`, or does not even fit in an `isize`.
+pub fn negate_isize(process: &Process, i: isize) -> Term {
+    match i.checked_neg() {
+        Some(negated_isize) => process.integer(negated_isize),
+        None => process.integer(-BigInt::from(i)),
+    }
+}
@@ -0,0 +1,72 @@
+//! Mirrors [orddict](http://erlang.org/doc/man/orddict.html) module
+
+pub mod erase_2;
+pub mod find_2;
+pub mod store_3;
+
+use std::cmp::Ordering;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::from_str("orddict")
+}
+
+/// Pairs each `{Key, Value}` tuple in `orddict`, which are sorted by `Key` in standard term order,
+/// with its `Key`.
+fn orddict_to_keyed_vec(orddict: Term) -> exception::Result<Vec<(Term, Term)>> {
+    match orddict.decode()? {
+        TypedTerm::Nil => Ok(Vec::new()),
+        TypedTerm::List(cons) => {
+            let mut keyed_vec = Vec::new();
+
+            for result in cons.into_iter() {
+                let element = result
+                    .map_err(|_| ImproperListError)
+                    .with_context(|| format!("orddict ({}) is improper", orddict))?;
+                let key = match element.decode()? {
+                    TypedTerm::Tuple(tuple) if tuple.len() == 2 => Some(tuple[0]),
+                    _ => None,
+                }
+                .with_context(|| {
+                    format!(
+                        "orddict ({}) element ({}) is not a {{Key, Value}} pair",
+                        orddict, element
+                    )
+                })?;
+
+                keyed_vec.push((key, element));
+            }
+
+            Ok(keyed_vec)
+        }
+        _ => Err(TypeError)
+            .context(format!("orddict ({}) is not a list", orddict))
+            .map_err(From::from),
+    }
+}
+
+fn keyed_vec_to_list(process: &Process, keyed_vec: Vec<(Term, Term)>) -> Term {
+    let pairs: Vec<Term> = keyed_vec.into_iter().map(|(_, pair)| pair).collect();
+
+    process.list_from_slice(&pairs)
+}
+
+/// Where `key` is in `keyed_vec`: `Ok` with the index of the pair whose key compares equal (`==`)
+/// to `key`, or `Err` with the index where a pair with `key` would be inserted to keep `keyed_vec`
+/// sorted.
+fn search(keyed_vec: &[(Term, Term)], key: Term) -> Result<usize, usize> {
+    for (index, (pair_key, _)) in keyed_vec.iter().enumerate() {
+        match key.cmp(pair_key) {
+            Ordering::Less => return Err(index),
+            Ordering::Equal => return Ok(index),
+            Ordering::Greater => continue,
+        }
+    }
+
+    Err(keyed_vec.len())
+}
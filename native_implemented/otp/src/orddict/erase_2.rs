@@ -0,0 +1,22 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `orddict` without the pair whose key compares equal to `key`.  If there is no such
+/// pair, `orddict` is returned unchanged.
+#[native_implemented::function(orddict:erase/2)]
+pub fn result(process: &Process, key: Term, orddict: Term) -> exception::Result<Term> {
+    let mut keyed_vec = super::orddict_to_keyed_vec(orddict)?;
+
+    match super::search(&keyed_vec, key) {
+        Ok(index) => {
+            keyed_vec.remove(index);
+
+            Ok(super::keyed_vec_to_list(process, keyed_vec))
+        }
+        Err(_) => Ok(orddict),
+    }
+}
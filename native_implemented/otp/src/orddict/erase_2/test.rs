@@ -0,0 +1,34 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::orddict::erase_2::result;
+use crate::test::with_process;
+
+#[test]
+fn with_key_removes_pair() {
+    with_process(|process| {
+        let a = Atom::str_to_term("a");
+        let b = Atom::str_to_term("b");
+        let b_pair = process.tuple_from_slice(&[b, process.integer(2)]);
+        let orddict =
+            process.list_from_slice(&[process.tuple_from_slice(&[a, process.integer(1)]), b_pair]);
+
+        assert_eq!(
+            result(process, a, orddict),
+            Ok(process.list_from_slice(&[b_pair]))
+        );
+    });
+}
+
+#[test]
+fn without_key_returns_orddict() {
+    with_process(|process| {
+        let orddict = process.list_from_slice(&[
+            process.tuple_from_slice(&[Atom::str_to_term("b"), process.integer(2)])
+        ]);
+
+        assert_eq!(
+            result(process, Atom::str_to_term("a"), orddict),
+            Ok(orddict)
+        );
+    });
+}
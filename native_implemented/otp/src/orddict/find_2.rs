@@ -0,0 +1,25 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `{ok, Value}` for the pair in `orddict` whose key compares equal to `key`, or `error`
+/// if there is no such pair.
+#[native_implemented::function(orddict:find/2)]
+pub fn result(process: &Process, key: Term, orddict: Term) -> exception::Result<Term> {
+    let keyed_vec = super::orddict_to_keyed_vec(orddict)?;
+
+    match super::search(&keyed_vec, key) {
+        Ok(index) => {
+            let (_, pair) = keyed_vec[index];
+            let pair_tuple: Boxed<Tuple> = pair.try_into().unwrap();
+
+            Ok(process.tuple_from_slice(&[Atom::str_to_term("ok"), pair_tuple[1]]))
+        }
+        Err(_) => Ok(Atom::str_to_term("error")),
+    }
+}
@@ -0,0 +1,35 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::orddict::find_2::result;
+use crate::test::with_process;
+
+#[test]
+fn with_key_returns_ok_value() {
+    with_process(|process| {
+        let a = Atom::str_to_term("a");
+        let b = Atom::str_to_term("b");
+        let orddict = process.list_from_slice(&[
+            process.tuple_from_slice(&[a, process.integer(1)]),
+            process.tuple_from_slice(&[b, process.integer(2)]),
+        ]);
+
+        assert_eq!(
+            result(process, b, orddict),
+            Ok(process.tuple_from_slice(&[Atom::str_to_term("ok"), process.integer(2)]))
+        );
+    });
+}
+
+#[test]
+fn without_key_returns_error() {
+    with_process(|process| {
+        let orddict = process.list_from_slice(&[
+            process.tuple_from_slice(&[Atom::str_to_term("b"), process.integer(2)])
+        ]);
+
+        assert_eq!(
+            result(process, Atom::str_to_term("a"), orddict),
+            Ok(Atom::str_to_term("error"))
+        );
+    });
+}
@@ -0,0 +1,21 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `orddict` with `{Key, Value}`, replacing the pair whose key compares equal to `key`, if
+/// any, or inserting the pair where it keeps the keys sorted.
+#[native_implemented::function(orddict:store/3)]
+pub fn result(process: &Process, key: Term, value: Term, orddict: Term) -> exception::Result<Term> {
+    let mut keyed_vec = super::orddict_to_keyed_vec(orddict)?;
+    let keyed_pair = (key, process.tuple_from_slice(&[key, value]));
+
+    match super::search(&keyed_vec, key) {
+        Ok(index) => keyed_vec[index] = keyed_pair,
+        Err(index) => keyed_vec.insert(index, keyed_pair),
+    }
+
+    Ok(super::keyed_vec_to_list(process, keyed_vec))
+}
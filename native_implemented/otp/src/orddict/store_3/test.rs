@@ -0,0 +1,74 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::orddict::store_3::result;
+use crate::test::with_process;
+
+#[test]
+fn without_list_errors_badarg() {
+    with_process(|process| {
+        let orddict = Atom::str_to_term("orddict");
+
+        assert_badarg!(
+            result(process, process.integer(1), process.integer(2), orddict),
+            format!("orddict ({}) is not a list", orddict)
+        );
+    });
+}
+
+#[test]
+fn without_pairs_errors_badarg() {
+    with_process(|process| {
+        let element = Atom::str_to_term("element");
+        let orddict = process.list_from_slice(&[element]);
+
+        assert_badarg!(
+            result(process, process.integer(1), process.integer(2), orddict),
+            format!(
+                "orddict ({}) element ({}) is not a {{Key, Value}} pair",
+                orddict, element
+            )
+        );
+    });
+}
+
+#[test]
+fn with_out_of_order_keys_keeps_keys_sorted() {
+    with_process(|process| {
+        let a = Atom::str_to_term("a");
+        let b = Atom::str_to_term("b");
+        let c = Atom::str_to_term("c");
+
+        let orddict = result(process, c, process.integer(3), Term::NIL).unwrap();
+        let orddict = result(process, a, process.integer(1), orddict).unwrap();
+        let orddict = result(process, b, process.integer(2), orddict).unwrap();
+
+        assert_eq!(
+            orddict,
+            process.list_from_slice(&[
+                process.tuple_from_slice(&[a, process.integer(1)]),
+                process.tuple_from_slice(&[b, process.integer(2)]),
+                process.tuple_from_slice(&[c, process.integer(3)]),
+            ])
+        );
+    });
+}
+
+#[test]
+fn with_existing_key_replaces_value_without_duplicating_key() {
+    with_process(|process| {
+        let a = Atom::str_to_term("a");
+        let b = Atom::str_to_term("b");
+        let orddict = process.list_from_slice(&[
+            process.tuple_from_slice(&[a, process.integer(1)]),
+            process.tuple_from_slice(&[b, process.integer(2)]),
+        ]);
+
+        assert_eq!(
+            result(process, a, process.integer(3), orddict),
+            Ok(process.list_from_slice(&[
+                process.tuple_from_slice(&[a, process.integer(3)]),
+                process.tuple_from_slice(&[b, process.integer(2)]),
+            ]))
+        );
+    });
+}
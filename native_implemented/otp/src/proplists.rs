@@ -0,0 +1,56 @@
+//! Mirrors [proplists](http://erlang.org/doc/man/proplists.html) module
+
+pub mod get_bool_2;
+pub mod get_value_2;
+pub mod get_value_3;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::from_str("proplists")
+}
+
+/// Returns the value of the first property in `list` whose key is exactly equal (`=:=`) to `key`.
+/// A bare atom `Key` is short for `{Key, true}`.  If the first property with `key` is a tuple that
+/// isn't a pair, then it has no value, so `None` is returned as when `key` is missing.
+fn get_value(key: Term, list: Term) -> exception::Result<Option<Term>> {
+    let key_typed_term = key.decode()?;
+
+    match list.decode()? {
+        TypedTerm::Nil => Ok(None),
+        TypedTerm::List(cons) => {
+            for result in cons.into_iter() {
+                let property = result
+                    .map_err(|_| ImproperListError)
+                    .with_context(|| format!("list ({}) is improper", list))?;
+                let property_typed_term = property.decode()?;
+
+                match &property_typed_term {
+                    TypedTerm::Atom(_) => {
+                        if property_typed_term.exact_eq(&key_typed_term) {
+                            return Ok(Some(true.into()));
+                        }
+                    }
+                    TypedTerm::Tuple(tuple) if 1 <= tuple.len() => {
+                        if tuple[0].decode()?.exact_eq(&key_typed_term) {
+                            return Ok(if tuple.len() == 2 {
+                                Some(tuple[1])
+                            } else {
+                                None
+                            });
+                        }
+                    }
+                    _ => (),
+                }
+            }
+
+            Ok(None)
+        }
+        _ => Err(TypeError)
+            .context(format!("list ({}) is not a list", list))
+            .map_err(From::from),
+    }
+}
@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `true` if the first property in `list` with `key` has the value `true`, such as a bare
+/// atom `Key` or `{Key, true}`; otherwise, `false`.
+#[native_implemented::function(proplists:get_bool/2)]
+pub fn result(key: Term, list: Term) -> exception::Result<Term> {
+    let value = super::get_value(key, list)?;
+
+    Ok((value == Some(true.into())).into())
+}
@@ -0,0 +1,34 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::proplists::get_bool_2::result;
+use crate::test::with_process;
+
+#[test]
+fn with_bare_atom_key_returns_true() {
+    with_process(|process| {
+        let key = Atom::str_to_term("key");
+
+        assert_eq!(
+            result(key, process.list_from_slice(&[key])),
+            Ok(true.into())
+        );
+    });
+}
+
+#[test]
+fn with_non_true_value_returns_false() {
+    with_process(|process| {
+        let key = Atom::str_to_term("key");
+        let list = process.list_from_slice(&[process.tuple_from_slice(&[key, process.integer(1)])]);
+
+        assert_eq!(result(key, list), Ok(false.into()));
+    });
+}
+
+#[test]
+fn without_key_returns_false() {
+    assert_eq!(
+        result(Atom::str_to_term("key"), Term::NIL),
+        Ok(false.into())
+    );
+}
@@ -0,0 +1,11 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::proplists::get_value_2::result;
+
+#[test]
+fn without_key_returns_undefined() {
+    assert_eq!(
+        result(Atom::str_to_term("key"), Term::NIL),
+        Ok(Atom::str_to_term("undefined"))
+    );
+}
@@ -0,0 +1,12 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns the value of the first property in `list` with `key`, or `default` if there is no
+/// such property.  A bare atom `Key` in `list` is short for `{Key, true}`.
+#[native_implemented::function(proplists:get_value/3)]
+pub fn result(key: Term, list: Term, default: Term) -> exception::Result<Term> {
+    super::get_value(key, list).map(|option| option.unwrap_or(default))
+}
@@ -0,0 +1,67 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::proplists::get_value_3::result;
+use crate::test::with_process;
+
+#[test]
+fn without_list_errors_badarg() {
+    let list = Atom::str_to_term("list");
+
+    assert_badarg!(
+        result(Atom::str_to_term("key"), list, Term::NIL),
+        format!("list ({}) is not a list", list)
+    );
+}
+
+#[test]
+fn with_bare_atom_key_returns_true() {
+    with_process(|process| {
+        let key = Atom::str_to_term("key");
+        let list = process.list_from_slice(&[key]);
+
+        assert_eq!(result(key, list, Term::NIL), Ok(true.into()));
+    });
+}
+
+#[test]
+fn with_pair_returns_value() {
+    with_process(|process| {
+        let key = Atom::str_to_term("key");
+        let value = process.integer(1);
+        let list = process.list_from_slice(&[
+            Atom::str_to_term("other"),
+            process.tuple_from_slice(&[key, value]),
+            process.tuple_from_slice(&[key, process.integer(2)]),
+        ]);
+
+        assert_eq!(result(key, list, Term::NIL), Ok(value));
+    });
+}
+
+#[test]
+fn without_key_returns_default() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[
+            Atom::str_to_term("other"),
+            process.tuple_from_slice(&[Atom::str_to_term("another"), process.integer(1)]),
+        ]);
+        let default = Atom::str_to_term("default");
+
+        assert_eq!(result(Atom::str_to_term("key"), list, default), Ok(default));
+    });
+}
+
+#[test]
+fn with_key_in_tuple_that_is_not_a_pair_returns_default() {
+    with_process(|process| {
+        let key = Atom::str_to_term("key");
+        let list = process.list_from_slice(&[process.tuple_from_slice(&[
+            key,
+            process.integer(1),
+            process.integer(2),
+        ])]);
+        let default = Atom::str_to_term("default");
+
+        assert_eq!(result(key, list, default), Ok(default));
+    });
+}
@@ -0,0 +1,59 @@
+//! Mirrors [sets](http://erlang.org/doc/man/sets.html) module
+//!
+//! Sets are represented as lists sorted in standard term order, without elements that are exactly
+//! equal (`=:=`), so that `sets:to_list/1` is deterministic.
+
+pub mod add_element_2;
+pub mod is_element_2;
+pub mod new_0;
+pub mod to_list_1;
+
+use std::cmp::Ordering;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::from_str("sets")
+}
+
+/// Where `element` is in `vec`: `Ok` with the index of the element exactly equal (`=:=`) to
+/// `element`, or `Err` with the index where `element` would be inserted to keep `vec` sorted.
+/// Elements that only compare equal (`==`), such as `1` and `1.0`, are kept in insertion order.
+fn search(vec: &[Term], element: Term) -> exception::Result<Result<usize, usize>> {
+    let element_typed_term = element.decode()?;
+
+    for (index, set_element) in vec.iter().enumerate() {
+        match element.cmp(set_element) {
+            Ordering::Less => return Ok(Err(index)),
+            Ordering::Equal => {
+                if set_element.decode()?.exact_eq(&element_typed_term) {
+                    return Ok(Ok(index));
+                }
+            }
+            Ordering::Greater => continue,
+        }
+    }
+
+    Ok(Err(vec.len()))
+}
+
+fn set_to_vec(set: Term) -> exception::Result<Vec<Term>> {
+    match set.decode()? {
+        TypedTerm::Nil => Ok(Vec::new()),
+        TypedTerm::List(cons) => cons
+            .into_iter()
+            .map(|result| {
+                result
+                    .map_err(|_| ImproperListError)
+                    .with_context(|| format!("set ({}) is improper", set))
+                    .map_err(From::from)
+            })
+            .collect(),
+        _ => Err(TypeError)
+            .context(format!("set ({}) is not a set", set))
+            .map_err(From::from),
+    }
+}
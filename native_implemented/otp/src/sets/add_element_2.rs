@@ -0,0 +1,22 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `set` with `element`.  If `set` already has an element exactly equal (`=:=`) to
+/// `element`, `set` is returned unchanged.
+#[native_implemented::function(sets:add_element/2)]
+pub fn result(process: &Process, element: Term, set: Term) -> exception::Result<Term> {
+    let mut vec = super::set_to_vec(set)?;
+
+    match super::search(&vec, element)? {
+        Ok(_) => Ok(set),
+        Err(index) => {
+            vec.insert(index, element);
+
+            Ok(process.list_from_slice(&vec))
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::sets::{add_element_2, new_0, to_list_1};
+use crate::test::with_process;
+
+#[test]
+fn without_set_errors_badarg() {
+    with_process(|process| {
+        let set = Atom::str_to_term("set");
+
+        assert_badarg!(
+            add_element_2::result(process, process.integer(1), set),
+            format!("set ({}) is not a set", set)
+        );
+    });
+}
+
+#[test]
+fn with_duplicate_does_not_grow_set() {
+    with_process(|process| {
+        let set = add_element_2::result(process, process.integer(1), new_0::result()).unwrap();
+        let set = add_element_2::result(process, process.integer(2), set).unwrap();
+
+        assert_eq!(
+            add_element_2::result(process, process.integer(1), set),
+            Ok(set)
+        );
+    });
+}
+
+#[test]
+fn with_compare_equal_element_grows_set() {
+    with_process(|process| {
+        let integer = process.integer(1);
+        let float = process.float(1.0);
+        let set = add_element_2::result(process, integer, new_0::result()).unwrap();
+
+        assert_eq!(
+            add_element_2::result(process, float, set),
+            Ok(process.list_from_slice(&[integer, float]))
+        );
+    });
+}
+
+#[test]
+fn with_out_of_order_elements_to_list_is_sorted() {
+    with_process(|process| {
+        let set = add_element_2::result(process, process.integer(3), new_0::result()).unwrap();
+        let set = add_element_2::result(process, process.integer(1), set).unwrap();
+        let set = add_element_2::result(process, process.integer(2), set).unwrap();
+
+        assert_eq!(
+            to_list_1::result(set),
+            Ok(process.list_from_slice(&[
+                process.integer(1),
+                process.integer(2),
+                process.integer(3)
+            ]))
+        );
+    });
+}
@@ -0,0 +1,13 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns whether `set` has an element exactly equal (`=:=`) to `element`.
+#[native_implemented::function(sets:is_element/2)]
+pub fn result(element: Term, set: Term) -> exception::Result<Term> {
+    let vec = super::set_to_vec(set)?;
+
+    Ok(super::search(&vec, element)?.is_ok().into())
+}
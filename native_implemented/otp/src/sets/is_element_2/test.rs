@@ -0,0 +1,23 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::sets::is_element_2::result;
+use crate::test::with_process;
+
+#[test]
+fn with_element_returns_true() {
+    with_process(|process| {
+        let element = process.integer(1);
+        let set = process.list_from_slice(&[element]);
+
+        assert_eq!(result(element, set), Ok(true.into()));
+    });
+}
+
+#[test]
+fn with_compare_equal_element_returns_false() {
+    with_process(|process| {
+        let set = process.list_from_slice(&[process.integer(1)]);
+
+        assert_eq!(result(process.float(1.0), set), Ok(false.into()));
+    });
+}
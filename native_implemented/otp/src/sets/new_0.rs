@@ -0,0 +1,7 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns an empty set.
+#[native_implemented::function(sets:new/0)]
+pub fn result() -> Term {
+    Term::NIL
+}
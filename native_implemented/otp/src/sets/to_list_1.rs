@@ -0,0 +1,13 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns the elements of `set` as a list in standard term order.
+#[native_implemented::function(sets:to_list/1)]
+pub fn result(set: Term) -> exception::Result<Term> {
+    super::set_to_vec(set)?;
+
+    Ok(set)
+}
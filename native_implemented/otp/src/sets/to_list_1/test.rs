@@ -0,0 +1,15 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::sets::to_list_1::result;
+
+#[test]
+fn without_set_errors_badarg() {
+    let set = Atom::str_to_term("set");
+
+    assert_badarg!(result(set), format!("set ({}) is not a set", set));
+}
+
+#[test]
+fn with_empty_set_returns_empty_list() {
+    assert_eq!(result(Term::NIL), Ok(Term::NIL));
+}
@@ -0,0 +1,57 @@
+pub mod length_1;
+pub mod split_2;
+pub mod split_3;
+pub mod to_lower_1;
+pub mod to_upper_1;
+pub mod trim_1;
+pub mod trim_2;
+pub mod trim_3;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::list_to_string::list_to_string;
+use crate::runtime::binary_to_string::binary_to_string;
+use crate::runtime::context::term_is_not_type;
+
+fn module() -> Atom {
+    Atom::from_str("string")
+}
+
+/// Maps each character of `string`, returning the same type of string data as was passed in: a
+/// charlist for a charlist and a binary for a binary.
+fn map_chars<F>(process: &Process, string: Term, f: F) -> exception::Result<Term>
+where
+    F: FnMut(char) -> char,
+{
+    let mapped: String = string_data_to_string(string)?.chars().map(f).collect();
+
+    Ok(string_to_string_data(process, &mapped, string))
+}
+
+/// Decodes string data, which may either be a charlist or a UTF-8 binary.
+fn string_data_to_string(string: Term) -> exception::Result<String> {
+    match string.decode()? {
+        TypedTerm::Nil | TypedTerm::List(_) => list_to_string(string),
+        _ if string.is_binary() => binary_to_string(string),
+        _ => Err(TypeError)
+            .context(term_is_not_type(
+                "string",
+                string,
+                "string data (a charlist or binary)",
+            ))
+            .map_err(From::from),
+    }
+}
+
+/// Encodes `s` as the same type of string data as `like`.
+fn string_to_string_data(process: &Process, s: &str, like: Term) -> Term {
+    if like.is_binary() {
+        process.binary_from_str(s)
+    } else {
+        process.charlist_from_str(s)
+    }
+}
@@ -0,0 +1,15 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns the number of characters (codepoints) in `string`.  For a binary this is the number of
+/// UTF-8 encoded codepoints, not `byte_size/1`.
+#[native_implemented::function(string:length/1)]
+pub fn result(process: &Process, string: Term) -> exception::Result<Term> {
+    let length = super::string_data_to_string(string)?.chars().count();
+
+    Ok(process.integer(length))
+}
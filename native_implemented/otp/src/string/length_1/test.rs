@@ -0,0 +1,50 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::string::length_1::result;
+use crate::test::with_process;
+
+#[test]
+fn with_charlist_returns_number_of_elements() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.charlist_from_str("héllo")),
+            Ok(process.integer(5))
+        );
+    });
+}
+
+#[test]
+fn with_multibyte_utf8_binary_returns_number_of_codepoints() {
+    with_process(|process| {
+        let binary = process.binary_from_str("héllo→");
+
+        assert_eq!(result(process, binary), Ok(process.integer(6)));
+    });
+}
+
+#[test]
+fn with_invalid_utf8_binary_errors_badarg() {
+    with_process(|process| {
+        let binary = process.binary_from_bytes(&[0x61, 0xFF]);
+
+        assert_badarg!(
+            result(process, binary),
+            format!("binary ({}) cannot be converted to String", binary)
+        );
+    });
+}
+
+#[test]
+fn without_string_data_errors_badarg() {
+    with_process(|process| {
+        let string = Atom::str_to_term("atom");
+
+        assert_badarg!(
+            result(process, string),
+            format!(
+                "string ({}) is not string data (a charlist or binary)",
+                string
+            )
+        );
+    });
+}
@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::split_3::{split, Where};
+
+/// Splits `string` at the first occurrence of `search_pattern`.
+#[native_implemented::function(string:split/2)]
+pub fn result(process: &Process, string: Term, search_pattern: Term) -> exception::Result<Term> {
+    split(process, string, search_pattern, Where::Leading)
+}
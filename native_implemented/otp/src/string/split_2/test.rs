@@ -0,0 +1,31 @@
+use crate::string::split_2::result;
+use crate::test::with_process;
+
+#[test]
+fn with_search_pattern_splits_at_first_occurrence() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.charlist_from_str("a,b,c"),
+                process.charlist_from_str(",")
+            ),
+            Ok(process.list_from_slice(&[
+                process.charlist_from_str("a"),
+                process.charlist_from_str("b,c")
+            ]))
+        );
+    });
+}
+
+#[test]
+fn without_search_pattern_returns_string() {
+    with_process(|process| {
+        let string = process.binary_from_str("a,b,c");
+
+        assert_eq!(
+            result(process, string, process.binary_from_str(";")),
+            Ok(process.list_from_slice(&[string]))
+        );
+    });
+}
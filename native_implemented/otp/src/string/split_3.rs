@@ -0,0 +1,91 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::{TryFrom, TryInto};
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_is_not_type;
+
+/// Splits `string` at `search_pattern`, returning a list of the parts as the same type of string
+/// data as `string`.
+///
+/// * `leading` - splits at the first occurrence of `search_pattern`.
+/// * `trailing` - splits at the last occurrence of `search_pattern`.
+/// * `all` - splits at every occurrence of `search_pattern`.
+#[native_implemented::function(string:split/3)]
+pub fn result(
+    process: &Process,
+    string: Term,
+    search_pattern: Term,
+    r#where: Term,
+) -> exception::Result<Term> {
+    let r#where: Where = r#where.try_into()?;
+
+    split(process, string, search_pattern, r#where)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(in crate::string) enum Where {
+    Leading,
+    Trailing,
+    All,
+}
+
+impl TryFrom<Term> for Where {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> Result<Self, Self::Error> {
+        let atom: Atom = term
+            .try_into()
+            .with_context(|| term_is_not_type("where", term, "leading, trailing, or all"))?;
+
+        match atom.name() {
+            "leading" => Ok(Self::Leading),
+            "trailing" => Ok(Self::Trailing),
+            "all" => Ok(Self::All),
+            _ => Err(anyhow!(term_is_not_type(
+                "where",
+                term,
+                "leading, trailing, or all"
+            ))),
+        }
+    }
+}
+
+pub(in crate::string) fn split(
+    process: &Process,
+    string: Term,
+    search_pattern: Term,
+    r#where: Where,
+) -> exception::Result<Term> {
+    let string_string = super::string_data_to_string(string)?;
+    let pattern_string = super::string_data_to_string(search_pattern)?;
+
+    let parts: Vec<&str> = if pattern_string.is_empty() {
+        vec![&string_string]
+    } else {
+        match r#where {
+            Where::Leading => string_string.splitn(2, pattern_string.as_str()).collect(),
+            Where::Trailing => {
+                let mut parts: Vec<&str> =
+                    string_string.rsplitn(2, pattern_string.as_str()).collect();
+                parts.reverse();
+
+                parts
+            }
+            Where::All => string_string.split(pattern_string.as_str()).collect(),
+        }
+    };
+
+    let part_terms: Vec<Term> = parts
+        .into_iter()
+        .map(|part| super::string_to_string_data(process, part, string))
+        .collect();
+
+    Ok(process.list_from_slice(&part_terms))
+}
@@ -0,0 +1,57 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::string::split_3::result;
+use crate::test::with_process;
+
+#[test]
+fn without_where_errors_badarg() {
+    with_process(|process| {
+        let r#where = Atom::str_to_term("both");
+
+        assert_badarg!(
+            result(
+                process,
+                process.charlist_from_str("a,b"),
+                process.charlist_from_str(","),
+                r#where
+            ),
+            format!("where ({}) is not leading, trailing, or all", r#where)
+        );
+    });
+}
+
+#[test]
+fn with_trailing_splits_at_last_occurrence() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("a,b,c"),
+                process.charlist_from_str(","),
+                Atom::str_to_term("trailing")
+            ),
+            Ok(process
+                .list_from_slice(&[process.binary_from_str("a,b"), process.binary_from_str("c")]))
+        );
+    });
+}
+
+#[test]
+fn with_all_splits_at_every_occurrence() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("a::b::::c"),
+                process.binary_from_str("::"),
+                Atom::str_to_term("all")
+            ),
+            Ok(process.list_from_slice(&[
+                process.binary_from_str("a"),
+                process.binary_from_str("b"),
+                process.binary_from_str(""),
+                process.binary_from_str("c")
+            ]))
+        );
+    });
+}
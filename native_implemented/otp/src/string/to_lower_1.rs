@@ -0,0 +1,22 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Converts each character of `string` to lower case, returning the same type of string data.
+///
+/// Characters whose lower case form is more than one character are left unchanged, so that the
+/// result has the same length as `string`.
+#[native_implemented::function(string:to_lower/1)]
+pub fn result(process: &Process, string: Term) -> exception::Result<Term> {
+    super::map_chars(process, string, |c| {
+        let mut lower = c.to_lowercase();
+
+        match (lower.next(), lower.next()) {
+            (Some(lower_c), None) => lower_c,
+            _ => c,
+        }
+    })
+}
@@ -0,0 +1,22 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Converts each character of `string` to upper case, returning the same type of string data.
+///
+/// Characters whose upper case form is more than one character, such as `ß`, are left unchanged,
+/// so that the result has the same length as `string`.
+#[native_implemented::function(string:to_upper/1)]
+pub fn result(process: &Process, string: Term) -> exception::Result<Term> {
+    super::map_chars(process, string, |c| {
+        let mut upper = c.to_uppercase();
+
+        match (upper.next(), upper.next()) {
+            (Some(upper_c), None) => upper_c,
+            _ => c,
+        }
+    })
+}
@@ -0,0 +1,49 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::string::to_upper_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_string_data_errors_badarg() {
+    with_process(|process| {
+        let string = Atom::str_to_term("abc");
+
+        assert_badarg!(
+            result(process, string),
+            format!(
+                "string ({}) is not string data (a charlist or binary)",
+                string
+            )
+        );
+    });
+}
+
+#[test]
+fn with_ascii_charlist_returns_charlist() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.charlist_from_str("abc1_")),
+            Ok(process.charlist_from_str("ABC1_"))
+        );
+    });
+}
+
+#[test]
+fn with_latin1_charlist_returns_charlist() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.charlist_from_str("café")),
+            Ok(process.charlist_from_str("CAFÉ"))
+        );
+    });
+}
+
+#[test]
+fn with_binary_returns_binary() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.binary_from_str("abcé")),
+            Ok(process.binary_from_str("ABCÉ"))
+        );
+    });
+}
@@ -0,0 +1,12 @@
+use crate::string::trim_1::result;
+use crate::test::with_process;
+
+#[test]
+fn with_whitespace_at_both_ends_trims_both_ends() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.charlist_from_str("\t a b\r\n")),
+            Ok(process.charlist_from_str("a b"))
+        );
+    });
+}
@@ -0,0 +1,18 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::trim_3::{is_white_space, trim, Direction};
+
+/// Removes whitespace from the `leading`, `trailing`, or `both` ends of `string`.
+#[native_implemented::function(string:trim/2)]
+pub fn result(process: &Process, string: Term, direction: Term) -> exception::Result<Term> {
+    let direction: Direction = direction.try_into()?;
+
+    trim(process, string, direction, is_white_space)
+}
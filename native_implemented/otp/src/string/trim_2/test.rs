@@ -0,0 +1,33 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::string::trim_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_direction_errors_badarg() {
+    with_process(|process| {
+        let direction = Atom::str_to_term("all");
+
+        assert_badarg!(
+            result(process, process.charlist_from_str(" a "), direction),
+            format!(
+                "direction ({}) is not leading, trailing, or both",
+                direction
+            )
+        );
+    });
+}
+
+#[test]
+fn with_leading_only_trims_leading_whitespace() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("  a  "),
+                Atom::str_to_term("leading")
+            ),
+            Ok(process.binary_from_str("a  "))
+        );
+    });
+}
@@ -0,0 +1,94 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::{TryFrom, TryInto};
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::list_to_string::list_to_string;
+use crate::runtime::context::term_is_not_type;
+
+/// Removes the `characters` from the `leading`, `trailing`, or `both` ends of `string`, returning
+/// the same type of string data as `string`.
+#[native_implemented::function(string:trim/3)]
+pub fn result(
+    process: &Process,
+    string: Term,
+    direction: Term,
+    characters: Term,
+) -> exception::Result<Term> {
+    let direction: Direction = direction.try_into()?;
+    let characters: Vec<char> = list_to_string(characters)?.chars().collect();
+
+    trim(process, string, direction, |c| characters.contains(&c))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(in crate::string) enum Direction {
+    Leading,
+    Trailing,
+    Both,
+}
+
+impl TryFrom<Term> for Direction {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> Result<Self, Self::Error> {
+        let atom: Atom = term
+            .try_into()
+            .with_context(|| term_is_not_type("direction", term, "leading, trailing, or both"))?;
+
+        match atom.name() {
+            "leading" => Ok(Self::Leading),
+            "trailing" => Ok(Self::Trailing),
+            "both" => Ok(Self::Both),
+            _ => Err(anyhow!(term_is_not_type(
+                "direction",
+                term,
+                "leading, trailing, or both"
+            ))),
+        }
+    }
+}
+
+/// Whitespace as defined by `Pattern_White_Space` in Unicode, which is what `string:trim/1,2`
+/// trim.
+pub(in crate::string) fn is_white_space(c: char) -> bool {
+    matches!(
+        c,
+        '\t' | '\n'
+            | '\u{B}'
+            | '\u{C}'
+            | '\r'
+            | ' '
+            | '\u{85}'
+            | '\u{200E}'
+            | '\u{200F}'
+            | '\u{2028}'
+            | '\u{2029}'
+    )
+}
+
+pub(in crate::string) fn trim<F>(
+    process: &Process,
+    string: Term,
+    direction: Direction,
+    is_trimmed: F,
+) -> exception::Result<Term>
+where
+    F: Fn(char) -> bool,
+{
+    let string_string = super::string_data_to_string(string)?;
+
+    let trimmed = match direction {
+        Direction::Leading => string_string.trim_start_matches(|c: char| is_trimmed(c)),
+        Direction::Trailing => string_string.trim_end_matches(|c: char| is_trimmed(c)),
+        Direction::Both => string_string.trim_matches(|c: char| is_trimmed(c)),
+    };
+
+    Ok(super::string_to_string_data(process, trimmed, string))
+}
@@ -0,0 +1,34 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::string::trim_3::result;
+use crate::test::with_process;
+
+#[test]
+fn with_trailing_trims_only_trailing_characters() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.charlist_from_str(".x.a.x."),
+                Atom::str_to_term("trailing"),
+                process.charlist_from_str(".x")
+            ),
+            Ok(process.charlist_from_str(".x.a"))
+        );
+    });
+}
+
+#[test]
+fn with_characters_does_not_trim_whitespace() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str(" a."),
+                Atom::str_to_term("both"),
+                process.charlist_from_str(".")
+            ),
+            Ok(process.binary_from_str(" a"))
+        );
+    });
+}
@@ -5,6 +5,7 @@ pub mod loop_0;
 pub mod process;
 pub mod return_from_fn_0;
 pub mod return_from_fn_1;
+pub mod spin_0;
 
 // wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest,
 // so disable property-based tests and associated helpers completely for wasm32
@@ -20,12 +20,15 @@ pub fn init() -> Arc<Process> {
     runtime::test::once(&[
         erlang::apply_3::function_symbol(),
         erlang::exit_1::function_symbol(),
+        erlang::hibernate_3::function_symbol(),
+        erlang::make_tuple_2::function_symbol(),
         erlang::number_or_badarith_1::function_symbol(),
         erlang::self_0::function_symbol(),
         super::anonymous_0::function_symbol(),
         super::anonymous_1::function_symbol(),
         super::init::start_0::function_symbol(),
         loop_0::function_symbol(),
+        super::spin_0::function_symbol(),
     ]);
 
     set_log_exit(false);
@@ -0,0 +1,13 @@
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+pub use super::module;
+
+/// A tight recursive loop that never waits, so it only stops running when its reductions are used
+/// up.
+#[native_implemented::function(test:spin/0)]
+fn result(process: &Process) -> Term {
+    process.queue_frame_with_arguments(frame().with_arguments(false, &[]));
+
+    Term::NONE
+}
@@ -1,5 +1,51 @@
+use proptest::arbitrary::any;
 use proptest::collection::SizeRange;
-use proptest::strategy::{BoxedStrategy, Strategy};
+use proptest::prop_oneof;
+use proptest::strategy::{BoxedStrategy, Just, Strategy};
+
+/// Valid UTF-8 with an invalid sequence spliced in at a `char` boundary, so that the invalid
+/// sequence can't accidentally combine with its neighbors into a valid one.
+pub fn invalid_utf8() -> BoxedStrategy<Vec<u8>> {
+    (any::<String>(), invalid_utf8_sequence())
+        .prop_flat_map(|(string, invalid_sequence)| {
+            let char_boundary_vec: Vec<usize> = string
+                .char_indices()
+                .map(|(index, _)| index)
+                .chain(std::iter::once(string.len()))
+                .collect();
+
+            (
+                Just(string),
+                Just(invalid_sequence),
+                proptest::sample::select(char_boundary_vec),
+            )
+        })
+        .prop_map(|(string, invalid_sequence, index)| {
+            let mut byte_vec = string.into_bytes();
+            byte_vec.splice(index..index, invalid_sequence);
+
+            byte_vec
+        })
+        .boxed()
+}
+
+fn invalid_utf8_sequence() -> BoxedStrategy<Vec<u8>> {
+    prop_oneof![
+        // bytes that never appear in UTF-8
+        Just(vec![0xC0]),
+        Just(vec![0xC1]),
+        (0xF5_u8..=0xFF_u8).prop_map(|byte| vec![byte]),
+        // continuation byte without a leading byte
+        (0x80_u8..=0xBF_u8).prop_map(|byte| vec![byte]),
+        // overlong encoding of `/`
+        Just(vec![0xC0, 0xAF]),
+        // UTF-16 surrogate half
+        Just(vec![0xED, 0xA0, 0x80]),
+        // leading byte of a 3-byte sequence truncated after 1 continuation byte
+        Just(vec![0xE2, 0x82]),
+    ]
+    .boxed()
+}
 
 pub fn with_size_range(size_range: SizeRange) -> BoxedStrategy<Vec<u8>> {
     proptest::collection::vec(proptest::prelude::any::<u8>(), size_range).boxed()
@@ -22,6 +22,7 @@ pub mod index;
 pub mod integer;
 pub mod list;
 pub mod map;
+pub mod nested;
 pub mod pid;
 pub mod tuple;
 
@@ -464,6 +465,15 @@ pub fn map(arc_process: Arc<Process>) -> BoxedStrategy<Term> {
     map::intermediate(super::term(arc_process.clone()), size_range(), arc_process)
 }
 
+pub fn map_with_size_range(
+    size_range: SizeRange,
+    key: BoxedStrategy<Term>,
+    value: BoxedStrategy<Term>,
+    arc_process: Arc<Process>,
+) -> BoxedStrategy<Term> {
+    map::with_size_range(size_range, key, value, arc_process)
+}
+
 pub fn map_list_or_bitstring(arc_process: Arc<Process>) -> BoxedStrategy<Term> {
     prop_oneof![
         map(arc_process.clone()),
@@ -487,6 +497,11 @@ fn negative_big_integer_float_integral_i64() -> Option<BoxedStrategy<i64>> {
     }
 }
 
+/// Recursively nested tuples and lists that are never more than `max_depth` containers deep.
+pub fn nested(max_depth: u32, arc_process: Arc<Process>) -> BoxedStrategy<Term> {
+    nested::with_max_depth(max_depth, size_range(), arc_process)
+}
+
 pub fn non_existent_atom(suffix: &str) -> String {
     format!("{}_{}", NON_EXISTENT_ATOM_PREFIX, suffix)
 }
@@ -585,6 +600,16 @@ pub fn tuple(arc_process: Arc<Process>) -> BoxedStrategy<Term> {
     tuple::intermediate(super::term(arc_process.clone()), size_range(), arc_process)
 }
 
+/// Tuples whose arity is in `arity_range` and whose elements are drawn from `element`, so that
+/// tuple BIF tests can plug in the elements they need.
+pub fn tuple_with_arity_range(
+    arity_range: SizeRange,
+    element: BoxedStrategy<Term>,
+    arc_process: Arc<Process>,
+) -> BoxedStrategy<Term> {
+    tuple::intermediate(element, arity_range, arc_process)
+}
+
 pub fn tuple_map_list_or_bitstring(arc_process: Arc<Process>) -> BoxedStrategy<Term> {
     prop_oneof![
         tuple(arc_process.clone()),
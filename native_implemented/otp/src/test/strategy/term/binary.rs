@@ -1,3 +1,6 @@
+#[cfg(test)]
+mod test;
+
 use std::sync::Arc;
 
 use proptest::arbitrary::any;
@@ -7,8 +10,8 @@ use proptest::strategy::{BoxedStrategy, Just, Strategy};
 use liblumen_alloc::erts::term::prelude::Term;
 use liblumen_alloc::erts::Process;
 
-use crate::test::strategy::size_range;
 use crate::test::strategy::term::binary::sub::{bit_count, bit_offset, byte_count, byte_offset};
+use crate::test::strategy::{byte_vec, size_range};
 
 pub mod heap;
 pub mod sub;
@@ -25,6 +28,14 @@ pub fn heap(arc_process: Arc<Process>) -> BoxedStrategy<Term> {
     heap::with_size_range(size_range(), arc_process)
 }
 
+/// Binaries that contain at least one invalid UTF-8 sequence, so they are the complement of
+/// `is_utf8`.
+pub fn invalid_utf8(arc_process: Arc<Process>) -> BoxedStrategy<Term> {
+    byte_vec::invalid_utf8()
+        .prop_flat_map(move |byte_vec| containing_bytes(byte_vec, arc_process.clone()))
+        .boxed()
+}
+
 pub fn is_utf8(arc_process: Arc<Process>) -> BoxedStrategy<Term> {
     any::<String>()
         .prop_flat_map(move |string| {
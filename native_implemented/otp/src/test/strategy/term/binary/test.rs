@@ -0,0 +1,37 @@
+use proptest::prop_assert;
+
+use crate::runtime::binary_to_string::binary_to_string;
+use crate::test::strategy;
+
+#[test]
+fn invalid_utf8_is_not_utf8() {
+    run!(
+        |arc_process| strategy::term::binary::invalid_utf8(arc_process.clone()),
+        |binary| {
+            prop_assert!(binary_to_string(binary).is_err());
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn invalid_utf8_byte_vec_fails_from_utf8() {
+    run!(|_| strategy::byte_vec::invalid_utf8(), |byte_vec| {
+        prop_assert!(std::str::from_utf8(&byte_vec).is_err());
+
+        Ok(())
+    },);
+}
+
+#[test]
+fn is_utf8_is_utf8() {
+    run!(
+        |arc_process| strategy::term::binary::is_utf8(arc_process.clone()),
+        |binary| {
+            prop_assert!(binary_to_string(binary).is_ok());
+
+            Ok(())
+        },
+    );
+}
@@ -1,21 +1,55 @@
+#[cfg(test)]
+mod test;
+
 use std::sync::Arc;
 
 use proptest::collection::SizeRange;
 use proptest::strategy::{BoxedStrategy, Strategy};
 
-use liblumen_alloc::erts::term::prelude::Term;
+use liblumen_alloc::erts::term::prelude::*;
 use liblumen_alloc::erts::Process;
 
+/// Entries with pairwise distinct keys.  Keys are deduplicated with `=:=`, keeping the first
+/// occurrence, so the number of entries can be less than the lower bound of `size_range` when the
+/// key strategy generates duplicates.
+pub fn entry_vec(
+    size_range: SizeRange,
+    key: BoxedStrategy<Term>,
+    value: BoxedStrategy<Term>,
+) -> BoxedStrategy<Vec<(Term, Term)>> {
+    proptest::collection::vec((key, value), size_range)
+        .prop_map(|entry_vec| {
+            let mut unique_entry_vec: Vec<(Term, Term)> = Vec::with_capacity(entry_vec.len());
+
+            for (key, value) in entry_vec {
+                if !unique_entry_vec
+                    .iter()
+                    .any(|(unique_key, _)| unique_key.exact_eq(&key))
+                {
+                    unique_entry_vec.push((key, value));
+                }
+            }
+
+            unique_entry_vec
+        })
+        .boxed()
+}
+
 pub fn intermediate(
     key_or_value: BoxedStrategy<Term>,
     size_range: SizeRange,
     arc_process: Arc<Process>,
 ) -> BoxedStrategy<Term> {
-    proptest::collection::hash_map(key_or_value.clone(), key_or_value, size_range)
-        .prop_map(move |mut hash_map| {
-            let entry_vec: Vec<(Term, Term)> = hash_map.drain().collect();
+    with_size_range(size_range, key_or_value.clone(), key_or_value, arc_process)
+}
 
-            arc_process.map_from_slice(&entry_vec)
-        })
+pub fn with_size_range(
+    size_range: SizeRange,
+    key: BoxedStrategy<Term>,
+    value: BoxedStrategy<Term>,
+    arc_process: Arc<Process>,
+) -> BoxedStrategy<Term> {
+    entry_vec(size_range, key, value)
+        .prop_map(move |entry_vec| arc_process.map_from_slice(&entry_vec))
         .boxed()
 }
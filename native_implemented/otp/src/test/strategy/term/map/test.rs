@@ -0,0 +1,54 @@
+use std::convert::TryInto;
+
+use proptest::strategy::{Just, Strategy};
+use proptest::{prop_assert, prop_assert_eq};
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::test::strategy;
+
+#[test]
+fn entry_vec_has_unique_keys() {
+    run!(
+        |arc_process| {
+            strategy::term::map::entry_vec(
+                strategy::size_range(),
+                strategy::term(arc_process.clone()),
+                strategy::term(arc_process),
+            )
+        },
+        |entry_vec| {
+            for (index, (key, _)) in entry_vec.iter().enumerate() {
+                prop_assert!(!entry_vec[(index + 1)..]
+                    .iter()
+                    .any(|(other_key, _)| other_key.exact_eq(key)));
+            }
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn map_size_is_number_of_unique_keys() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::map::entry_vec(
+                    strategy::size_range(),
+                    strategy::term(arc_process.clone()),
+                    strategy::term(arc_process),
+                ),
+            )
+        },
+        |(arc_process, entry_vec)| {
+            let map = arc_process.map_from_slice(&entry_vec);
+            let boxed_map: Boxed<Map> = map.try_into().unwrap();
+
+            prop_assert_eq!(boxed_map.len(), entry_vec.len());
+
+            Ok(())
+        },
+    );
+}
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod test;
+
+use std::sync::Arc;
+
+use proptest::collection::SizeRange;
+use proptest::prop_oneof;
+use proptest::strategy::{BoxedStrategy, Strategy};
+
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::erts::Process;
+
+use super::{list, tuple};
+
+/// Containers used at each level of nesting.  Maps are left out for the same reason as in
+/// `super::container`: generated keys can collide and silently shrink the container.
+pub fn container(
+    element: BoxedStrategy<Term>,
+    size_range: SizeRange,
+    arc_process: Arc<Process>,
+) -> BoxedStrategy<Term> {
+    prop_oneof![
+        tuple::intermediate(element.clone(), size_range.clone(), arc_process.clone()),
+        proptest::collection::vec(element, size_range)
+            .prop_map(move |vec| arc_process.list_from_slice(&vec))
+            .boxed(),
+    ]
+    .boxed()
+}
+
+/// The number of container levels in `term`.  Leaves, including the empty list and empty tuple,
+/// have a depth of `0`.
+pub fn depth(term: Term) -> u32 {
+    match term.decode().unwrap() {
+        TypedTerm::Tuple(tuple) => tuple
+            .iter()
+            .map(|element| depth(*element) + 1)
+            .max()
+            .unwrap_or(0),
+        TypedTerm::List(cons) => cons
+            .into_iter()
+            .map(|result| match result {
+                Ok(element) => depth(element) + 1,
+                Err(ImproperList { tail }) => depth(tail),
+            })
+            .max()
+            .unwrap_or(0),
+        TypedTerm::Map(map) => map
+            .iter()
+            .map(|(key, value)| std::cmp::max(depth(*key), depth(*value)) + 1)
+            .max()
+            .unwrap_or(0),
+        _ => 0,
+    }
+}
+
+/// Both depth and branching are bounded so that the number of generated terms stays linear in
+/// `max_depth` instead of exploding combinatorially.
+pub fn with_max_depth(
+    max_depth: u32,
+    size_range: SizeRange,
+    arc_process: Arc<Process>,
+) -> BoxedStrategy<Term> {
+    let container_arc_process = arc_process.clone();
+    let branch_size = size_range.end_incl() as u32;
+
+    super::leaf(super::super::RANGE_INCLUSIVE, arc_process)
+        .prop_recursive(
+            max_depth,
+            branch_size * (max_depth + 1),
+            branch_size,
+            move |element| container(element, size_range.clone(), container_arc_process.clone()),
+        )
+        .boxed()
+}
@@ -0,0 +1,30 @@
+use proptest::prop_assert;
+use proptest::strategy::{Just, Strategy};
+
+use crate::test::strategy;
+use crate::test::strategy::term::nested::depth;
+
+#[test]
+fn never_exceeds_max_depth() {
+    run!(
+        |arc_process| {
+            (Just(arc_process.clone()), 0_u32..=8_u32).prop_flat_map(|(arc_process, max_depth)| {
+                (
+                    Just(max_depth),
+                    strategy::term::nested(max_depth, arc_process),
+                )
+            })
+        },
+        |(max_depth, term)| {
+            prop_assert!(
+                depth(term) <= max_depth,
+                "term ({}) has depth ({}) greater than max_depth ({})",
+                term,
+                depth(term),
+                max_depth
+            );
+
+            Ok(())
+        },
+    );
+}
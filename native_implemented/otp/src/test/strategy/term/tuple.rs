@@ -1,3 +1,6 @@
+#[cfg(test)]
+mod test;
+
 use std::convert::TryInto;
 use std::sync::Arc;
 
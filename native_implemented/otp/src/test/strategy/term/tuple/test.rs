@@ -0,0 +1,49 @@
+use std::convert::TryInto;
+
+use proptest::prop_assert;
+use proptest::strategy::{Just, Strategy};
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::test::strategy;
+
+#[test]
+fn with_arity_range_has_arity_in_range() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                0_usize..=4_usize,
+                0_usize..=4_usize,
+            )
+                .prop_flat_map(|(arc_process, min_arity, extra_arity)| {
+                    let max_arity = min_arity + extra_arity;
+
+                    (
+                        Just(min_arity),
+                        Just(max_arity),
+                        strategy::term::tuple_with_arity_range(
+                            (min_arity..=max_arity).into(),
+                            strategy::term::atom(),
+                            arc_process,
+                        ),
+                    )
+                })
+        },
+        |(min_arity, max_arity, tuple)| {
+            let boxed_tuple: Boxed<Tuple> = tuple.try_into().unwrap();
+            let arity = boxed_tuple.len();
+
+            prop_assert!(
+                (min_arity <= arity) && (arity <= max_arity),
+                "arity ({}) is not in {}..={}",
+                arity,
+                min_arity,
+                max_arity
+            );
+            prop_assert!(boxed_tuple.iter().all(|element| element.is_atom()));
+
+            Ok(())
+        },
+    );
+}
@@ -0,0 +1,169 @@
+pub mod characters_to_binary_1;
+pub mod characters_to_binary_2;
+pub mod characters_to_list_1;
+pub mod characters_to_list_2;
+
+use std::convert::{TryFrom, TryInto};
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_is_not_type;
+
+fn module() -> Atom {
+    Atom::from_str("unicode")
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Encoding {
+    Latin1,
+    Unicode,
+}
+
+impl TryFrom<Term> for Encoding {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> Result<Self, Self::Error> {
+        let atom: Atom = term
+            .try_into()
+            .with_context(|| term_is_not_type("encoding", term, "latin1, unicode, or utf8"))?;
+
+        match atom.name() {
+            "latin1" => Ok(Self::Latin1),
+            "unicode" | "utf8" => Ok(Self::Unicode),
+            _ => Err(anyhow!(term_is_not_type(
+                "encoding",
+                term,
+                "latin1, unicode, or utf8"
+            ))),
+        }
+    }
+}
+
+/// Where conversion stopped before the end of the data, with the unconverted rest of the data.
+enum Stop {
+    /// The rest starts with an invalid character or invalid UTF-8.
+    Error(Term),
+    /// The rest is a binary ending in a UTF-8 sequence that is valid, but incomplete.
+    Incomplete(Term),
+}
+
+/// Converts `data`, which is chardata (a possibly deep list of characters and binaries) in
+/// `in_encoding`, to a string.  Malformed data is not an error, but is returned as
+/// `{error, Converted, Rest}` or `{incomplete, Converted, Rest}`, where `Converted` is the
+/// data converted before the malformed part, encoded the same as a successful conversion by
+/// `to_term`.
+fn characters_to_term(
+    process: &Process,
+    data: Term,
+    in_encoding: Encoding,
+    to_term: fn(&Process, &str) -> Term,
+) -> exception::Result<Term> {
+    let mut string = String::new();
+
+    let term = match push_chardata(process, data, data, in_encoding, &mut string)? {
+        None => to_term(process, &string),
+        Some(Stop::Error(rest)) => {
+            process.tuple_from_slice(&[atom!("error"), to_term(process, &string), rest])
+        }
+        Some(Stop::Incomplete(rest)) => {
+            process.tuple_from_slice(&[atom!("incomplete"), to_term(process, &string), rest])
+        }
+    };
+
+    Ok(term)
+}
+
+fn push_chardata(
+    process: &Process,
+    data: Term,
+    chardata: Term,
+    in_encoding: Encoding,
+    string: &mut String,
+) -> exception::Result<Option<Stop>> {
+    match chardata.decode()? {
+        TypedTerm::Nil => Ok(None),
+        TypedTerm::List(_) => {
+            let mut list = chardata;
+
+            while let TypedTerm::List(cons) = list.decode()? {
+                let head = cons.head;
+
+                match head.decode()? {
+                    TypedTerm::SmallInteger(_) | TypedTerm::BigInteger(_) => {
+                        match head_to_char(head, in_encoding) {
+                            Some(c) => string.push(c),
+                            None => return Ok(Some(Stop::Error(list))),
+                        }
+                    }
+                    _ => {
+                        if let Some(stop) = push_chardata(process, data, head, in_encoding, string)?
+                        {
+                            let stop = match stop {
+                                Stop::Error(rest) => Stop::Error(process.cons(rest, cons.tail)),
+                                Stop::Incomplete(rest) => {
+                                    Stop::Incomplete(process.cons(rest, cons.tail))
+                                }
+                            };
+
+                            return Ok(Some(stop));
+                        }
+                    }
+                }
+
+                list = cons.tail;
+            }
+
+            push_chardata(process, data, list, in_encoding, string)
+        }
+        _ if chardata.is_binary() => {
+            let bytes: Vec<u8> = chardata.try_into()?;
+
+            match in_encoding {
+                Encoding::Latin1 => {
+                    string.extend(bytes.iter().map(|byte| *byte as char));
+
+                    Ok(None)
+                }
+                Encoding::Unicode => match std::str::from_utf8(&bytes) {
+                    Ok(s) => {
+                        string.push_str(s);
+
+                        Ok(None)
+                    }
+                    Err(utf8_error) => {
+                        let valid_up_to = utf8_error.valid_up_to();
+                        string.push_str(std::str::from_utf8(&bytes[..valid_up_to]).unwrap());
+                        let rest = process.binary_from_bytes(&bytes[valid_up_to..]);
+
+                        let stop = match utf8_error.error_len() {
+                            Some(_) => Stop::Error(rest),
+                            None => Stop::Incomplete(rest),
+                        };
+
+                        Ok(Some(stop))
+                    }
+                },
+            }
+        }
+        _ => Err(TypeError)
+            .context(format!(
+                "data ({}) element ({}) is not a character, binary, or nested chardata",
+                data, chardata
+            ))
+            .map_err(From::from),
+    }
+}
+
+fn head_to_char(head: Term, in_encoding: Encoding) -> Option<char> {
+    let c: char = head.try_into().ok()?;
+
+    match in_encoding {
+        Encoding::Latin1 if (c as u32) > 0xFF => None,
+        _ => Some(c),
+    }
+}
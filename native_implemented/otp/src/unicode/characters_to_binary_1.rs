@@ -0,0 +1,16 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::{characters_to_term, Encoding};
+
+/// Converts `data`, which is unicode chardata, to a UTF-8 binary.
+#[native_implemented::function(unicode:characters_to_binary/1)]
+pub fn result(process: &Process, data: Term) -> exception::Result<Term> {
+    characters_to_term(process, data, Encoding::Unicode, |process, s| {
+        process.binary_from_str(s)
+    })
+}
@@ -0,0 +1,63 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::test::with_process;
+use crate::unicode::characters_to_binary_1::result;
+
+#[test]
+fn with_deep_chardata_returns_utf8_binary() {
+    with_process(|process| {
+        let data = process.list_from_slice(&[
+            process.charlist_from_str("hé"),
+            process.binary_from_str("llo"),
+            process.integer('→'),
+        ]);
+
+        assert_eq!(result(process, data), Ok(process.binary_from_str("héllo→")));
+    });
+}
+
+#[test]
+fn with_invalid_character_returns_error_with_converted_and_rest() {
+    with_process(|process| {
+        let rest = process.list_from_slice(&[process.integer(-1), process.integer('b')]);
+        let data = process.cons(process.integer('a'), rest);
+
+        assert_eq!(
+            result(process, data),
+            Ok(process.tuple_from_slice(&[atom!("error"), process.binary_from_str("a"), rest]))
+        );
+    });
+}
+
+#[test]
+fn with_incomplete_utf8_returns_incomplete_with_converted_and_rest() {
+    with_process(|process| {
+        let data = process.binary_from_bytes(&[b'a', 0xE2, 0x86]);
+
+        assert_eq!(
+            result(process, data),
+            Ok(process.tuple_from_slice(&[
+                atom!("incomplete"),
+                process.binary_from_str("a"),
+                process.binary_from_bytes(&[0xE2, 0x86])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn without_chardata_errors_badarg() {
+    with_process(|process| {
+        let element = Atom::str_to_term("a");
+        let data = process.list_from_slice(&[element]);
+
+        assert_badarg!(
+            result(process, data),
+            format!(
+                "data ({}) element ({}) is not a character, binary, or nested chardata",
+                data, element
+            )
+        );
+    });
+}
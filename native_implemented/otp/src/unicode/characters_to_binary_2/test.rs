@@ -0,0 +1,30 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::test::with_process;
+use crate::unicode::characters_to_binary_2::result;
+
+#[test]
+fn with_latin1_binary_returns_utf8_binary() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_bytes(&[b'c', b'a', b'f', 0xE9]),
+                Atom::str_to_term("latin1")
+            ),
+            Ok(process.binary_from_str("café"))
+        );
+    });
+}
+
+#[test]
+fn without_supported_encoding_errors_badarg() {
+    with_process(|process| {
+        let in_encoding = Atom::str_to_term("utf16");
+
+        assert_badarg!(
+            result(process, process.binary_from_str("a"), in_encoding),
+            format!("encoding ({}) is not latin1, unicode, or utf8", in_encoding)
+        );
+    });
+}
@@ -0,0 +1,36 @@
+use liblumen_alloc::atom;
+
+use crate::test::with_process;
+use crate::unicode::characters_to_list_1::result;
+
+#[test]
+fn with_utf8_binary_returns_characters() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.binary_from_str("héllo→")),
+            Ok(process.charlist_from_str("héllo→"))
+        );
+    });
+}
+
+#[test]
+fn with_invalid_utf8_returns_error_with_converted_and_rest() {
+    with_process(|process| {
+        let data = process.list_from_slice(&[
+            process.binary_from_bytes(&[b'a', 0xFF, b'b']),
+            process.integer('c'),
+        ]);
+
+        assert_eq!(
+            result(process, data),
+            Ok(process.tuple_from_slice(&[
+                atom!("error"),
+                process.charlist_from_str("a"),
+                process.cons(
+                    process.binary_from_bytes(&[0xFF, b'b']),
+                    process.list_from_slice(&[process.integer('c')])
+                )
+            ]))
+        );
+    });
+}
@@ -0,0 +1,24 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::{characters_to_term, Encoding};
+
+/// Converts `data`, which is chardata in `in_encoding` (`latin1`, `unicode`, or `utf8`), to
+/// a list of characters.
+///
+/// Malformed data does not raise, but returns `{error, Converted, Rest}` or, for a binary ending in
+/// an incomplete UTF-8 sequence, `{incomplete, Converted, Rest}`.
+#[native_implemented::function(unicode:characters_to_list/2)]
+pub fn result(process: &Process, data: Term, in_encoding: Term) -> exception::Result<Term> {
+    let in_encoding: Encoding = in_encoding.try_into()?;
+
+    characters_to_term(process, data, in_encoding, |process, s| {
+        process.charlist_from_str(s)
+    })
+}
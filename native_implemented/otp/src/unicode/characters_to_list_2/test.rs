@@ -0,0 +1,32 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::test::with_process;
+use crate::unicode::characters_to_list_2::result;
+
+#[test]
+fn with_latin1_binary_returns_characters() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_bytes(&[b'c', b'a', b'f', 0xE9]),
+                Atom::str_to_term("latin1")
+            ),
+            Ok(process.charlist_from_str("café"))
+        );
+    });
+}
+
+#[test]
+fn with_latin1_and_character_above_255_returns_error() {
+    with_process(|process| {
+        let rest = process.list_from_slice(&[process.integer('→')]);
+        let data = process.cons(process.integer('a'), rest);
+
+        assert_eq!(
+            result(process, data, Atom::str_to_term("latin1")),
+            Ok(process.tuple_from_slice(&[atom!("error"), process.charlist_from_str("a"), rest]))
+        );
+    });
+}
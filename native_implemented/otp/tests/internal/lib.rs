@@ -1,7 +1,25 @@
+#[path = "lib/binary.rs"]
+pub mod binary;
 #[path = "lib/erlang.rs"]
 pub mod erlang;
+#[path = "lib/ets.rs"]
+pub mod ets;
+#[path = "lib/io.rs"]
+pub mod io;
+#[path = "lib/io_lib.rs"]
+pub mod io_lib;
+#[path = "lib/lists.rs"]
+pub mod lists;
 #[path = "lib/maps.rs"]
 pub mod maps;
+#[path = "lib/math.rs"]
+pub mod math;
+#[path = "lib/receive.rs"]
+pub mod receive;
+#[path = "lib/string.rs"]
+pub mod string;
+#[path = "lib/unicode.rs"]
+pub mod unicode;
 
 test_stderr_substrings!(
     backtrace,
@@ -0,0 +1,4 @@
+#[path = "binary/part_3.rs"]
+pub mod part_3;
+#[path = "binary/replace_4.rs"]
+pub mod replace_4;
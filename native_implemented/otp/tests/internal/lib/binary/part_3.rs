@@ -0,0 +1 @@
+test_stdout!(with_negative_length_past_start_errors_badarg, "<<1,2>>\n{caught, error, badarg}\n");
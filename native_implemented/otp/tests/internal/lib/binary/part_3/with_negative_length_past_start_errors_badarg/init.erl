@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display(binary:part(<<0, 1, 2, 3>>, 3, -2)),
+  test:caught(fun () ->
+    binary:part(<<0, 1, 2, 3>>, 1, -2)
+  end).
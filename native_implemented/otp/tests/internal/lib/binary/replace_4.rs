@@ -0,0 +1,4 @@
+test_stdout!(
+    with_global_replaces_every_match,
+    "<<\"aXcaXc\">>\n<<\"aXcabc\">>\n"
+);
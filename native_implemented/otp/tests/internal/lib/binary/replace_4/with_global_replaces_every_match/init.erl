@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display(binary:replace(<<"abcabc">>, <<"b">>, <<"X">>, [global])),
+  display(binary:replace(<<"abcabc">>, <<"b">>, <<"X">>, [])).
@@ -32,6 +32,8 @@ pub mod binary_to_list_3;
 pub mod binary_to_term_1;
 #[path = "erlang/binary_to_term_2.rs"]
 pub mod binary_to_term_2;
+#[path = "erlang/bitstring_to_list_1.rs"]
+pub mod bitstring_to_list_1;
 #[path = "erlang/bnot_1.rs"]
 pub mod bnot_1;
 #[path = "erlang/bor_2.rs"]
@@ -78,6 +80,8 @@ pub mod error_1;
 pub mod error_2;
 #[path = "erlang/exit_1.rs"]
 pub mod exit_1;
+#[path = "erlang/external_size_1.rs"]
+pub mod external_size_1;
 #[path = "erlang/float_1.rs"]
 pub mod float_1;
 #[path = "erlang/float_to_binary_1.rs"]
@@ -92,6 +96,10 @@ pub mod float_to_list_2;
 pub mod floor_1;
 #[path = "erlang/function_exported_3.rs"]
 pub mod function_exported_3;
+#[path = "erlang/garbage_collect_0.rs"]
+pub mod garbage_collect_0;
+#[path = "erlang/garbage_collect_1.rs"]
+pub mod garbage_collect_1;
 #[path = "erlang/get_0.rs"]
 pub mod get_0;
 #[path = "erlang/get_1.rs"]
@@ -100,8 +108,20 @@ pub mod get_1;
 pub mod get_keys_0;
 #[path = "erlang/get_keys_1.rs"]
 pub mod get_keys_1;
+#[path = "erlang/group_leader_0.rs"]
+pub mod group_leader_0;
+#[path = "erlang/group_leader_2.rs"]
+pub mod group_leader_2;
+#[path = "erlang/halt_0.rs"]
+pub mod halt_0;
+#[path = "erlang/halt_1.rs"]
+pub mod halt_1;
+#[path = "erlang/halt_2.rs"]
+pub mod halt_2;
 #[path = "erlang/hd_1.rs"]
 pub mod hd_1;
+#[path = "erlang/hibernate_3.rs"]
+pub mod hibernate_3;
 #[path = "erlang/insert_element_3.rs"]
 pub mod insert_element_3;
 #[path = "erlang/integer_to_binary_1.rs"]
@@ -128,20 +148,52 @@ pub mod is_map_1;
 pub mod is_number_1;
 #[path = "erlang/is_pid_1.rs"]
 pub mod is_pid_1;
+#[path = "erlang/is_port_1.rs"]
+pub mod is_port_1;
 #[path = "erlang/is_process_alive_1.rs"]
 pub mod is_process_alive_1;
+#[path = "erlang/is_record_2.rs"]
+pub mod is_record_2;
+#[path = "erlang/is_record_3.rs"]
+pub mod is_record_3;
+#[path = "erlang/is_reference_1.rs"]
+pub mod is_reference_1;
 #[path = "erlang/link_1.rs"]
 pub mod link_1;
+#[path = "erlang/list_to_binary_1.rs"]
+pub mod list_to_binary_1;
+#[path = "erlang/list_to_bitstring_1.rs"]
+pub mod list_to_bitstring_1;
+#[path = "erlang/list_to_ref_1.rs"]
+pub mod list_to_ref_1;
 #[path = "erlang/load_nif_2.rs"]
 pub mod load_nif_2;
+#[path = "erlang/make_fun_3.rs"]
+pub mod make_fun_3;
 #[path = "erlang/module_loaded_1.rs"]
 pub mod module_loaded_1;
 #[path = "erlang/nif_error_1.rs"]
 pub mod nif_error_1;
+#[path = "erlang/node_1.rs"]
+pub mod node_1;
+#[path = "erlang/nodes_0.rs"]
+pub mod nodes_0;
 #[path = "erlang/or_2.rs"]
 pub mod or_2;
+#[path = "erlang/pid_to_list_1.rs"]
+pub mod pid_to_list_1;
 #[path = "erlang/process_flag_2.rs"]
 pub mod process_flag_2;
+#[path = "erlang/process_info_1.rs"]
+pub mod process_info_1;
+#[path = "erlang/process_info_2.rs"]
+pub mod process_info_2;
+#[path = "erlang/put_2.rs"]
+pub mod put_2;
+#[path = "erlang/ref_to_list_1.rs"]
+pub mod ref_to_list_1;
+#[path = "erlang/send_nosuspend_2.rs"]
+pub mod send_nosuspend_2;
 #[path = "erlang/seq_trace_2.rs"]
 pub mod seq_trace_2;
 #[path = "erlang/seq_trace_info_1.rs"]
@@ -164,7 +216,13 @@ pub mod spawn_monitor_3;
 pub mod spawn_opt_2;
 #[path = "erlang/spawn_opt_4.rs"]
 pub mod spawn_opt_4;
+#[path = "erlang/spawn_request_4.rs"]
+pub mod spawn_request_4;
 #[path = "erlang/system_flag_2.rs"]
 pub mod system_flag_2;
+#[path = "erlang/term_to_binary_1.rs"]
+pub mod term_to_binary_1;
 #[path = "erlang/tl_1.rs"]
 pub mod tl_1;
+#[path = "erlang/yield_0.rs"]
+pub mod yield_0;
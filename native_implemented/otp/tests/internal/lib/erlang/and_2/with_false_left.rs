@@ -1,2 +1,2 @@
 test_stdout!(without_boolean_right_errors_badarg, "{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n");
-test_stdout!(with_boolean_right_returns_false, "false\ntrue\n");
+test_stdout!(with_boolean_right_returns_false, "false\nfalse\n");
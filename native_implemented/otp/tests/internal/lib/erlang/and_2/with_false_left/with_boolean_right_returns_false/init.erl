@@ -3,5 +3,5 @@
 -import(erlang, [display/1]).
 
 start() ->
-  display(false or false),
-  display(false or true).
+  display(false and false),
+  display(false and true).
@@ -4,3 +4,4 @@ test_stdout!(
     with_used_with_binary_returns_how_many_bytes_were_consumed_along_with_term,
     "{hello, 9}\n{hello, 9}\ntrue\nhello\n"
 );
+test_stdout!(with_used_with_concatenated_terms_decodes_each_term, "{hello, 9}\n{42, 3}\n0\n");
@@ -0,0 +1,16 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [binary_to_term/2, display/1]).
+
+start() ->
+  %% `term_to_binary(hello)` followed by `term_to_binary(42)`
+  Binary = <<131, 100, 0, 5, 104, 101, 108, 108, 111, 131, 97, 42>>,
+  Rest = decode(Binary),
+  FinalRest = decode(Rest),
+  display(byte_size(FinalRest)).
+
+decode(Binary) ->
+  {Term, Used} = binary_to_term(Binary, [used]),
+  display({Term, Used}),
+  {_, Rest} = split_binary(Binary, Used),
+  Rest.
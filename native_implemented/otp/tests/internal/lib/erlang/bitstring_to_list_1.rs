@@ -0,0 +1,2 @@
+test_stdout!(with_12_bit_bitstring_returns_byte_and_4_bit_bitstring, "[171, <<12:4>>]\n");
+test_stdout!(with_binary_returns_list_of_bytes, "[1, 2, 3]\n");
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Bitstring = <<16#ABC:12>>,
+  display(bitstring_to_list(Bitstring)).
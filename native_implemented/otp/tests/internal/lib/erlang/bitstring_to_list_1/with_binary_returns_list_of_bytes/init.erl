@@ -0,0 +1,6 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display(bitstring_to_list(<<1, 2, 3>>)).
@@ -8,3 +8,7 @@ test_stdout!(
     with_positive_with_overflow_returns_big_integer,
     "true\n18446744073709551616\n"
 );
+test_stdout!(
+    with_positive_with_isize_overflow_returns_big_integer,
+    "true\n13835058055282163712\n"
+);
@@ -11,3 +11,15 @@ test_stdout!(
     "true\n1\n"
 );
 test_stdout!(with_positive_with_underflow_returns_zero, "0\n");
+test_stdout!(
+    with_negative_with_isize_overflow_shifts_left_and_returns_big_integer,
+    "true\n13835058055282163712\n"
+);
+test_stdout!(
+    with_positive_with_dropped_low_bit_returns_small_integer,
+    "true\n2\n"
+);
+test_stdout!(
+    with_negative_with_dropped_low_bit_rounds_down,
+    "true\n-3\n"
+);
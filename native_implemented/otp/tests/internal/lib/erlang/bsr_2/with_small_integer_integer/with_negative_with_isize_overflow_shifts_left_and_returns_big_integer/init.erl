@@ -0,0 +1,13 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lumen, [is_big_integer/1, is_small_integer/1]).
+
+start() ->
+  % The shift is less than 64, but the shifted value doesn't fit in 64 bits
+  Integer = 2#11,
+  true = is_small_integer(Integer),
+  Shift = -62,
+  Final = Integer bsr Shift,
+  display(is_big_integer(Final)),
+  display(Final).
@@ -0,0 +1,14 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lumen, [is_small_integer/1]).
+
+start() ->
+  % The low bit is shifted out, so shifting back left does not give the original integer
+  Integer = 2#101,
+  true = is_small_integer(Integer),
+  Shift = 1,
+  true = (Shift > 0),
+  Final = Integer bsr Shift,
+  display(is_small_integer(Final)),
+  display(Final).
@@ -1,3 +1,7 @@
 test_stdout!(without_number_errors_badarg, "{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n");
 test_stdout!(with_integer_returns_integer, "-1\n0\n1\n");
 test_stdout!(with_float_round_up_to_next_integer, "-1\n-1\n0\n1\n1\n");
+test_stdout!(
+    with_big_float_returns_big_integer,
+    "true\n100000000000000000000\ntrue\n-100000000000000000000\n"
+);
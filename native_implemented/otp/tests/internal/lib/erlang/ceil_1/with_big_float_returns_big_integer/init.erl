@@ -0,0 +1,13 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lumen, [is_big_integer/1]).
+
+start() ->
+  test(1.0e20),
+  test(-1.0e20).
+
+test(Float) ->
+  Integer = ceil(Float),
+  display(is_big_integer(Integer)),
+  display(Integer).
@@ -2,3 +2,8 @@
 
 test_stdout!(with_atom, "atom\n");
 test_stdout!(with_small_integer, "1\n0\n");
+test_stdout!(
+    with_nested_tuple,
+    "{outer, {inner, [1, 2]}, <<\"binary\">>}\n"
+);
+test_stdout!(returns_true, "ok\ntrue\n");
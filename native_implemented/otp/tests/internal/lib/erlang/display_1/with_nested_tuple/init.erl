@@ -0,0 +1,6 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display({outer, {inner, [1, 2]}, <<"binary">>}).
@@ -1,2 +1,3 @@
+test_stdout!(with_entries_clears_all_entries, "[]\nundefined\nundefined\n");
 test_stdout!(with_entries_returns_list, "[{key, value}]\n");
 test_stdout!(without_entries_returns_empty_list, "[]\n");
@@ -0,0 +1,11 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1, erase/0, get/0, get/1, put/2]).
+
+start() ->
+  put(key1, value1),
+  put(key2, value2),
+  erase(),
+  display(get()),
+  display(get(key1)),
+  display(get(key2)).
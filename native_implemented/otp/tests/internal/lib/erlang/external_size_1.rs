@@ -0,0 +1,4 @@
+test_stdout!(
+    is_byte_size_of_term_to_binary,
+    "true\ntrue\ntrue\ntrue\ntrue\ntrue\ntrue\ntrue\ntrue\ntrue\ntrue\ntrue\n"
+);
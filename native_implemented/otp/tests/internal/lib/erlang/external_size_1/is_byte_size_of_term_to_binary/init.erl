@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  test:each(fun (Term) ->
+    display(external_size(Term) == byte_size(term_to_binary(Term)))
+  end).
@@ -4,3 +4,7 @@ test_stdout!(
     with_float_rounds_down_to_previous_integer,
     "{-1.2, -2}\n{-1.0, -1}\n{-0.3, -1}\n{0.0, 0}\n{0.4, 0}\n{1.0, 1}\n{1.5, 1}\n"
 );
+test_stdout!(
+    with_big_float_returns_big_integer,
+    "true\n100000000000000000000\ntrue\n-100000000000000000000\n"
+);
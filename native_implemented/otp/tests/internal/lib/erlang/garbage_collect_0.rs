@@ -0,0 +1 @@
+test_stdout!(returns_true, "{garbage_collect, true}\n");
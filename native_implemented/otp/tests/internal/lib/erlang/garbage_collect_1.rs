@@ -0,0 +1 @@
+test_stdout!(with_dead_pid_returns_false, "{garbage_collect, false}\n");
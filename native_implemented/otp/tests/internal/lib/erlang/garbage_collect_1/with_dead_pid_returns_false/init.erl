@@ -0,0 +1,10 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  {Child, MonitorReference} = spawn_monitor(fun () -> ok end),
+  receive
+    {'DOWN', MonitorReference, process, _, _} -> ok
+  end,
+  display({garbage_collect, garbage_collect(Child)}).
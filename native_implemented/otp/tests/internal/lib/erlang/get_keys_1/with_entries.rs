@@ -5,3 +5,7 @@ test_stdout!(
 );
 // From https://github.com/erlang/otp/blob/a62aed81c56c724f7dd7040adecaa28a78e5d37f/erts/doc/src/erlang.xml#L2104-L2112
 test_stdout!(doc_test, "true\ntrue\ntrue\ntrue\ntrue\ntrue\n");
+test_stdout!(
+    with_integer_and_float_values_returns_exactly_equal_keys,
+    "[integer]\n[float]\n"
+);
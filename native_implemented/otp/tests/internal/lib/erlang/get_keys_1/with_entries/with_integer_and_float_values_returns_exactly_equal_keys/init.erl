@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1, get_keys/1, put/2]).
+
+start() ->
+  put(integer, 1),
+  put(float, 1.0),
+  display(get_keys(1)),
+  display(get_keys(1.0)).
@@ -0,0 +1 @@
+test_stdout!(spawned_process_inherits_parent_group_leader, "{child, inherited, true}\n");
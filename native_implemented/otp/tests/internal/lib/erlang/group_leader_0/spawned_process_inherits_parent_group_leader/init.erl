@@ -0,0 +1,16 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Parent = self(),
+  GroupLeader = group_leader(),
+  spawn(fun () ->
+    Parent ! {child, group_leader, group_leader()}
+  end),
+  receive
+    {child, group_leader, ChildGroupLeader} ->
+      display({child, inherited, ChildGroupLeader == GroupLeader})
+  after 10 ->
+    display(timeout)
+  end.
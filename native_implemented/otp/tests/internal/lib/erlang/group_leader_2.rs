@@ -0,0 +1 @@
+test_stdout!(with_dead_pid_errors_badarg, "{caught, error, badarg}\n");
@@ -0,0 +1,14 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  {Child, MonitorReference} = spawn_monitor(fun () -> ok end),
+  receive
+    {'DOWN', MonitorReference, process, _, _} -> ok
+  end,
+  try group_leader(group_leader(), Child) of
+    Result -> display({returned, Result})
+  catch
+    Class:Exception -> display({caught, Class, Exception})
+  end.
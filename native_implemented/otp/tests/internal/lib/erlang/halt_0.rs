@@ -0,0 +1 @@
+test_stdout!(with_display_flushes_stdout, "before_halt\n");
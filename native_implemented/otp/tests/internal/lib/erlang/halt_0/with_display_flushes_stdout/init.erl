@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display(before_halt),
+  halt(),
+  display(after_halt).
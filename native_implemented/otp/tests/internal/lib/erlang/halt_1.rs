@@ -0,0 +1,2 @@
+test_stdout!(with_zero_after_display_flushes_stdout, "before_halt\n");
+test_stdout!(with_string_halts_after_flushing_stdout, "before_halt\n");
@@ -0,0 +1 @@
+test_stdout!(with_flush_true_after_display_flushes_stdout, "before_halt\n");
@@ -0,0 +1,4 @@
+test_stdout!(
+    resumes_with_module_function_arguments_after_message,
+    "{child, resumed, hibernated}\n{child, received, wake_up}\n{child, exited, normal}\n"
+);
@@ -0,0 +1,35 @@
+-module(init).
+-export([start/0, resume/1]).
+-import(erlang, [display/1]).
+
+start() ->
+  {Child, MonitorReference} = spawn_monitor(fun () ->
+    erlang:hibernate(init, resume, [hibernated])
+  end),
+  wait_until_waiting(Child),
+  Child ! wake_up,
+  receive
+    {'DOWN', MonitorReference, process, _, Reason} ->
+      display({child, exited, Reason})
+  after 10 ->
+    display(timeout)
+  end.
+
+resume(State) ->
+  display({child, resumed, State}),
+  receive
+    Message -> display({child, received, Message})
+  end.
+
+%% Only send the message once the child is suspended in `hibernate`, so that it is the message that
+%% wakes it up
+wait_until_waiting(Pid) ->
+  case process_info(Pid, status) of
+    {status, waiting} ->
+      ok;
+    _ ->
+      receive
+      after 1 ->
+        wait_until_waiting(Pid)
+      end
+  end.
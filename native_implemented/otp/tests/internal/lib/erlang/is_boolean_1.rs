@@ -3,3 +3,4 @@ test_stdout!(
     "false\nfalse\nfalse\nfalse\nfalse\nfalse\nfalse\nfalse\nfalse\nfalse\nfalse\nfalse\n"
 );
 test_stdout!(with_boolean_returns_true, "true\ntrue\n");
+test_stdout!(with_non_boolean_atom_returns_false, "false\nfalse\n");
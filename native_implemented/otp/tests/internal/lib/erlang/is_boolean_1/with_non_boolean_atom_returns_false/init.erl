@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display(is_boolean(foo)),
+  display(is_boolean(maybe)).
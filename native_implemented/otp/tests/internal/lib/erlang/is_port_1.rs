@@ -0,0 +1,4 @@
+test_stdout!(
+    without_port_returns_false,
+    "false\nfalse\nfalse\nfalse\nfalse\nfalse\nfalse\nfalse\nfalse\nfalse\nfalse\nfalse\n"
+);
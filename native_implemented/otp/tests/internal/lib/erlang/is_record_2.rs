@@ -0,0 +1,2 @@
+test_stdout!(with_record_tag_returns_true, "true\n");
+test_stdout!(without_record_tag_returns_false, "false\n");
@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+-record(person, {name, age}).
+
+start() ->
+  display(is_record(#person{name = "Alice", age = 30}, person)).
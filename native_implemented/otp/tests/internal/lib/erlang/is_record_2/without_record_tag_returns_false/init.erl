@@ -0,0 +1,6 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display(is_record({animal, "Rex", 3}, person)).
@@ -0,0 +1,3 @@
+test_stdout!(with_record_tag_with_size_returns_true, "true\n");
+test_stdout!(with_record_tag_without_size_returns_false, "false\n");
+test_stdout!(without_integer_size_errors_badarg, "{caught, error, badarg}\n");
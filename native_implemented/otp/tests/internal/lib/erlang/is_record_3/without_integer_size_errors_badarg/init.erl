@@ -0,0 +1,14 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Size = id(three),
+  try erlang:is_record({person, "Alice", 30}, person, Size) of
+    Result -> display({returned, Result})
+  catch
+    Class:Reason -> display({caught, Class, Reason})
+  end.
+
+id(X) ->
+  X.
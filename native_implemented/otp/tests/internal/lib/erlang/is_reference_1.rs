@@ -0,0 +1,5 @@
+test_stdout!(
+    without_reference_returns_false,
+    "false\nfalse\nfalse\nfalse\nfalse\nfalse\nfalse\nfalse\nfalse\nfalse\nfalse\n"
+);
+test_stdout!(with_reference_returns_true, "true\n");
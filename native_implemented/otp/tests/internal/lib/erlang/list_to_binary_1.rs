@@ -0,0 +1,2 @@
+test_stdout!(with_byte_aligned_bitstring_element_returns_binary, "<<0,1,2>>\n");
+test_stdout!(without_byte_aligned_bitstring_element_errors_badarg, "{caught, error, badarg}\n");
@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  % A 16-bit bitstring built from two 8-bit segments is byte-aligned
+  Bitstring = <<1:8, 2:8>>,
+  display(list_to_binary([0, Bitstring])).
@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+
+start() ->
+  Bitstring = <<1:4>>,
+  test:caught(fun () ->
+    list_to_binary([0, Bitstring])
+  end).
@@ -0,0 +1,2 @@
+test_stdout!(with_4_bit_bitstrings_returns_binary, "<<165>>\n");
+test_stdout!(with_3_bit_bitstrings_returns_6_bit_bitstring, "<<43:6>>\n");
@@ -0,0 +1,6 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display(list_to_bitstring([<<2#101:3>>, <<2#011:3>>])).
@@ -0,0 +1 @@
+test_stdout!(round_trips_through_ref_to_list, "true\n");
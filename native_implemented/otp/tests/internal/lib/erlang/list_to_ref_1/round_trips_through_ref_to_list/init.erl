@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Reference = make_ref(),
+  display(list_to_ref(ref_to_list(Reference)) == Reference).
@@ -0,0 +1,5 @@
+test_stdout!(with_exported_function_can_be_applied, "3\n");
+test_stdout!(
+    without_exported_function_when_applied_exits_undef,
+    "{caught, exit, undef}\n"
+);
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Fun = erlang:make_fun(erlang, '+', 2),
+  display(apply(Fun, [1, 2])).
@@ -0,0 +1,11 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Fun = erlang:make_fun(init, missing, 0),
+  try apply(Fun, []) of
+    Result -> display({returned, Result})
+  catch
+    Class:Exception -> display({caught, Class, Exception})
+  end.
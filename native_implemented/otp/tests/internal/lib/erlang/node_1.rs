@@ -0,0 +1 @@
+test_stdout!(with_self_returns_node, "true\nnonode@nohost\n");
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1, node/0, node/1, self/0]).
+
+start() ->
+  display(node(self()) == node()),
+  display(node(make_ref())).
@@ -0,0 +1 @@
+test_stdout!(returns_empty_list, "[]\n");
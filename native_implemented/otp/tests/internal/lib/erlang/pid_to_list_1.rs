@@ -0,0 +1 @@
+test_stdout!(round_trips_through_list_to_pid, "true\n");
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Self = self(),
+  display(list_to_pid(pid_to_list(Self)) == Self).
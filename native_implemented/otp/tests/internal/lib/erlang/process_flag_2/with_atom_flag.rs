@@ -1,3 +1,5 @@
+#[path = "with_atom_flag/with_priority_flag.rs"]
+pub mod with_priority_flag;
 #[path = "with_atom_flag/with_trap_exit_flag.rs"]
 pub mod with_trap_exit_flag;
 
@@ -0,0 +1,6 @@
+// `without_priority_value_errors_badarg` in unit tests
+// `with_priority_value_returns_old_priority` in unit tests
+test_stdout!(
+    with_high_priority_is_scheduled_before_normal_priority,
+    "{old_priority, normal}\nhigh\nnormal\n"
+);
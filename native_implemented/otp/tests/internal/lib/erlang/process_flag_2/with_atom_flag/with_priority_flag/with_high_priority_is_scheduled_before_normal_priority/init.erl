@@ -0,0 +1,30 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Normal = spawn(fun () ->
+    wait_for_go(),
+    display(normal)
+  end),
+  High = spawn(fun () ->
+    display({old_priority, process_flag(priority, high)}),
+    wait_for_go(),
+    display(high)
+  end),
+  %% Let both children block in `receive`, so they are runnable at the same time after `go`
+  wait(),
+  Normal ! go,
+  High ! go,
+  wait().
+
+wait_for_go() ->
+  receive
+    go -> ok
+  end.
+
+wait() ->
+  receive
+  after
+    10 -> ok
+  end.
@@ -0,0 +1 @@
+test_stdout!(contains_message_queue_len, "{message_queue_len, 2}\n");
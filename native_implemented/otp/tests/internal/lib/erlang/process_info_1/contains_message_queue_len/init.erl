@@ -0,0 +1,10 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Self = self(),
+  Self ! first,
+  Self ! second,
+  Info = process_info(Self),
+  display(lists:keyfind(message_queue_len, 1, Info)).
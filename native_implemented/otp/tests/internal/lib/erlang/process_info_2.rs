@@ -0,0 +1 @@
+test_stdout!(message_queue_len_counts_sent_messages, "{message_queue_len, 2}\n");
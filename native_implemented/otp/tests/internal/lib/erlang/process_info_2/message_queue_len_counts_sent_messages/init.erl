@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Self = self(),
+  Self ! first,
+  Self ! second,
+  display(process_info(Self, message_queue_len)).
@@ -0,0 +1 @@
+test_stdout!(with_key_returns_previous_value, "undefined\nfirst\nsecond\n");
@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1, get/1, put/2]).
+
+start() ->
+  display(put(key, first)),
+  display(put(key, second)),
+  display(get(key)).
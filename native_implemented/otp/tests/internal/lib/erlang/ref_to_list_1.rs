@@ -0,0 +1 @@
+test_stdout!(with_make_ref_renders_ref_form, "true\n");
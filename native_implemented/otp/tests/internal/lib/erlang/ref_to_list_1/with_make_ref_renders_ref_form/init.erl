@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  case ref_to_list(make_ref()) of
+    "#Ref<" ++ _ -> display(true);
+    Other -> display({unexpected, Other})
+  end.
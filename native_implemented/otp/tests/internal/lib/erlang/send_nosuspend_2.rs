@@ -0,0 +1 @@
+test_stdout!(with_local_pid_returns_true, "{sent, true}\n{received, message}\n");
@@ -0,0 +1,11 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display({sent, erlang:send_nosuspend(self(), message)}),
+  receive
+    Message -> display({received, Message})
+  after 10 ->
+    display(timeout)
+  end.
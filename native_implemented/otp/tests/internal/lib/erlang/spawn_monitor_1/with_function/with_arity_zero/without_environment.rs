@@ -6,3 +6,7 @@ test_stdout!(
     with_normal_exit_in_child_process_sends_exit_message_to_parent,
     "{child, exited, normal}\n"
 );
+test_stdout!(
+    with_error_on_start_in_child_process_sends_down_message_with_reason_to_parent,
+    "{child, exited, crash_on_start}\n"
+);
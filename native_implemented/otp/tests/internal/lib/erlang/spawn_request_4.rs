@@ -0,0 +1,3 @@
+test_stdout!(with_successful_local_spawn_sends_spawn_reply, "{spawn_reply, true, ok, true}\n");
+test_stdout!(with_monitor_request_id_is_monitor_reference, "{child, exited, normal}\n");
+test_stdout!(with_monitor_spawn_reply_arrives_before_down, "spawn_reply\ndown\n");
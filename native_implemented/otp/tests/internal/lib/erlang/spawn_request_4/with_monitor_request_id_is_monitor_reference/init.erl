@@ -0,0 +1,18 @@
+-module(init).
+-export([start/0, child/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  RequestId = spawn_request(init, child, [], [monitor]),
+  receive
+    {spawn_reply, RequestId, ok, _Pid} -> ok
+  end,
+  receive
+    {'DOWN', RequestId, process, _, Reason} ->
+      display({child, exited, Reason})
+  after 10 ->
+    display(timeout)
+  end.
+
+child() ->
+  ok.
@@ -0,0 +1,22 @@
+-module(init).
+-export([start/0, child/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  RequestId = spawn_request(init, child, [], [monitor]),
+  %% Give the child time to exit before looking at the mailbox
+  receive
+  after 10 ->
+    ok
+  end,
+  receive
+    {spawn_reply, RequestId, ok, _Pid} -> display(spawn_reply);
+    {'DOWN', RequestId, process, _, _} -> display(down)
+  end,
+  receive
+    {spawn_reply, RequestId, ok, _} -> display(spawn_reply);
+    {'DOWN', RequestId, process, _, _} -> display(down)
+  end.
+
+child() ->
+  ok.
@@ -0,0 +1,15 @@
+-module(init).
+-export([start/0, child/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  RequestId = spawn_request(init, child, [], []),
+  receive
+    {spawn_reply, ReplyRequestId, Status, Pid} ->
+      display({spawn_reply, ReplyRequestId == RequestId, Status, is_pid(Pid)})
+  after 10 ->
+    display(timeout)
+  end.
+
+child() ->
+  ok.
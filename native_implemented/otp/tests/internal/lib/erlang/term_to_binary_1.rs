@@ -0,0 +1 @@
+test_stdout!(with_binary_literal_encodes_binary_ext, "<<131,109,0,0,0,3,1,2,3>>\n");
@@ -0,0 +1,4 @@
+test_stdout!(
+    with_busy_processes_interleaves_progress,
+    "{a, 3}\n{b, 3}\n{a, 2}\n{b, 2}\n{a, 1}\n{b, 1}\n"
+);
@@ -0,0 +1,17 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Parent = self(),
+  spawn(fun () -> count(Parent, a, 3) end),
+  spawn(fun () -> count(Parent, b, 3) end),
+  receive done -> ok end,
+  receive done -> ok end.
+
+count(Parent, _Name, 0) ->
+  Parent ! done;
+count(Parent, Name, N) ->
+  display({Name, N}),
+  true = erlang:yield(),
+  count(Parent, Name, N - 1).
@@ -0,0 +1,8 @@
+#[path = "ets/lookup_2.rs"]
+pub mod lookup_2;
+#[path = "ets/new_2.rs"]
+pub mod new_2;
+#[path = "ets/next_2.rs"]
+pub mod next_2;
+#[path = "ets/update_counter_3.rs"]
+pub mod update_counter_3;
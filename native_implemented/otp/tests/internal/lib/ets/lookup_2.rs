@@ -0,0 +1 @@
+test_stdout!(with_inserted_key_returns_object, "[{key, value}]\n[]\n");
@@ -0,0 +1,10 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(ets, [insert/2, lookup/2, new/2]).
+
+start() ->
+  Table = new(table, [set, public]),
+  insert(Table, {key, value}),
+  display(lookup(Table, key)),
+  display(lookup(Table, other_key)).
@@ -0,0 +1 @@
+test_stdout!(with_named_table_returns_name, "table\n[{key, value}]\n");
@@ -0,0 +1,10 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(ets, [insert/2, lookup/2, new/2]).
+
+start() ->
+  Table = new(table, [named_table]),
+  display(Table),
+  insert(table, {key, value}),
+  display(lookup(table, key)).
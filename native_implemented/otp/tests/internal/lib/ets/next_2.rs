@@ -0,0 +1,4 @@
+test_stdout!(
+    with_set_table_traverses_all_keys,
+    "3\n[a, b, c]\n'$end_of_table'\n"
+);
@@ -0,0 +1,20 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(ets, [first/1, insert/2, new/2, next/2]).
+
+start() ->
+  Table = new(table, [set]),
+  insert(Table, [{a, 1}, {b, 2}, {c, 3}]),
+  Keys = traverse(Table, first(Table), []),
+  display(length(Keys)),
+  display(lists:sort(Keys)),
+  display(last(Table, Keys)).
+
+traverse(_Table, '$end_of_table', Acc) ->
+  Acc;
+traverse(Table, Key, Acc) ->
+  traverse(Table, next(Table, Key), [Key | Acc]).
+
+last(Table, [Last | _]) ->
+  next(Table, Last).
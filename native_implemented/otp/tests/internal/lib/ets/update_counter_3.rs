@@ -0,0 +1,4 @@
+test_stdout!(
+    with_increment_and_threshold_returns_new_value,
+    "1\n2\n3\n0\n"
+);
@@ -0,0 +1,12 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(ets, [insert/2, new/2, update_counter/3]).
+
+start() ->
+  Table = new(table, []),
+  insert(Table, {counter, 0}),
+  display(update_counter(Table, counter, 1)),
+  display(update_counter(Table, counter, {2, 1})),
+  display(update_counter(Table, counter, {2, 1, 3, 0})),
+  display(update_counter(Table, counter, {2, 1, 3, 0})).
@@ -0,0 +1,4 @@
+#[path = "io/format_1.rs"]
+pub mod format_1;
+#[path = "io/format_2.rs"]
+pub mod format_2;
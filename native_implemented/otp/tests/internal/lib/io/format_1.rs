@@ -0,0 +1 @@
+test_stdout!(returns_ok, "hello\nok\n");
@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(io, [format/1]).
+
+start() ->
+  Returned = format("hello~n"),
+  display(Returned).
@@ -0,0 +1,10 @@
+test_stdout!(with_p_pretty_prints_nested_term, "{a,[1,2]}\n");
+test_stdout!(
+    with_p_and_w_distinguishes_printable_lists,
+    "\"abc\"\n[97,98,99]\nstring 255 ~\n"
+);
+test_stdout!(
+    with_group_leader_waits_for_io_reply,
+    "{put_chars, <<\"hello\">>}\n{returned, ok}\n"
+);
+test_stdout!(with_error_io_reply_errors_badarg, "{caught, error, badarg}\n");
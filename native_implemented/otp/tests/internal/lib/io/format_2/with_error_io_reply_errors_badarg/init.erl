@@ -0,0 +1,17 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  GroupLeader = spawn(fun () ->
+    receive
+      {io_request, From, ReplyAs, {put_chars, unicode, _}} ->
+        From ! {io_reply, ReplyAs, {error, arguments}}
+    end
+  end),
+  group_leader(GroupLeader, self()),
+  try io:format("~w", [hello]) of
+    Result -> display({returned, Result})
+  catch
+    Class:Exception -> display({caught, Class, Exception})
+  end.
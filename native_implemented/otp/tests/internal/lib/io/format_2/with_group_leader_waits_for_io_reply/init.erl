@@ -0,0 +1,14 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  GroupLeader = spawn(fun () ->
+    receive
+      {io_request, From, ReplyAs, {put_chars, unicode, Chars}} ->
+        display({put_chars, Chars}),
+        From ! {io_reply, ReplyAs, ok}
+    end
+  end),
+  group_leader(GroupLeader, self()),
+  display({returned, io:format("~w", [hello])}).
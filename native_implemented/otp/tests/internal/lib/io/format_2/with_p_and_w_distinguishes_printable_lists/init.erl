@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(io, [format/2]).
+
+start() ->
+  format("~p~n~w~n", ["abc", "abc"]),
+  format("~s ~b ~~~n", [<<"string">>, 255]).
@@ -0,0 +1,6 @@
+-module(init).
+-export([start/0]).
+-import(io, [format/2]).
+
+start() ->
+  format("~p~n", [{a, [1, 2]}]).
@@ -0,0 +1,2 @@
+#[path = "io_lib/format_2.rs"]
+pub mod format_2;
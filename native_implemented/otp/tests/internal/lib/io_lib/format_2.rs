@@ -0,0 +1 @@
+test_stdout!(with_b_returns_charlist, "\"255\"\n");
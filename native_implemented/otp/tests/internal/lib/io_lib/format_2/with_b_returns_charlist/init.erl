@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(io_lib, [format/2]).
+
+start() ->
+  display(format("~b", [255])).
@@ -0,0 +1,52 @@
+#[path = "lists/any_2.rs"]
+pub mod any_2;
+#[path = "lists/concat_1.rs"]
+pub mod concat_1;
+#[path = "lists/dropwhile_2.rs"]
+pub mod dropwhile_2;
+#[path = "lists/filter_2.rs"]
+pub mod filter_2;
+#[path = "lists/flatmap_2.rs"]
+pub mod flatmap_2;
+#[path = "lists/foldl_3.rs"]
+pub mod foldl_3;
+#[path = "lists/foldr_3.rs"]
+pub mod foldr_3;
+#[path = "lists/foreach_2.rs"]
+pub mod foreach_2;
+#[path = "lists/keysort_2.rs"]
+pub mod keysort_2;
+#[path = "lists/keystore_4.rs"]
+pub mod keystore_4;
+#[path = "lists/keytake_3.rs"]
+pub mod keytake_3;
+#[path = "lists/map_2.rs"]
+pub mod map_2;
+#[path = "lists/max_1.rs"]
+pub mod max_1;
+#[path = "lists/min_1.rs"]
+pub mod min_1;
+#[path = "lists/nthtail_2.rs"]
+pub mod nthtail_2;
+#[path = "lists/partition_2.rs"]
+pub mod partition_2;
+#[path = "lists/sort_1.rs"]
+pub mod sort_1;
+#[path = "lists/sort_2.rs"]
+pub mod sort_2;
+#[path = "lists/splitwith_2.rs"]
+pub mod splitwith_2;
+#[path = "lists/sublist_2.rs"]
+pub mod sublist_2;
+#[path = "lists/sum_1.rs"]
+pub mod sum_1;
+#[path = "lists/takewhile_2.rs"]
+pub mod takewhile_2;
+#[path = "lists/uniq_2.rs"]
+pub mod uniq_2;
+#[path = "lists/usort_1.rs"]
+pub mod usort_1;
+#[path = "lists/zip_2.rs"]
+pub mod zip_2;
+#[path = "lists/zipwith_3.rs"]
+pub mod zipwith_3;
@@ -0,0 +1 @@
+test_stdout!(stops_at_first_true, "1\n2\ntrue\n");
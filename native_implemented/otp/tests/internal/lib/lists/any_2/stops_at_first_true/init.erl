@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [any/2]).
+
+start() ->
+  display(any(fun (X) -> display(X), X > 1 end, [1, 2, 3])).
@@ -0,0 +1,4 @@
+test_stdout!(
+    with_atom_integer_and_string_returns_charlist,
+    "\"foo1bar\"\n"
+);
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [concat/1]).
+
+start() ->
+  display(concat([foo, 1, "bar"])).
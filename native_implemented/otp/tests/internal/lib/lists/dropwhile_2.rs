@@ -0,0 +1 @@
+test_stdout!(returns_rest_from_first_false, "[3, 1]\n");
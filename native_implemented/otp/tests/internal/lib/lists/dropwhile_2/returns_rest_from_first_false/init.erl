@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [dropwhile/2]).
+
+start() ->
+  display(dropwhile(fun (X) -> X < 3 end, [1, 2, 3, 1])).
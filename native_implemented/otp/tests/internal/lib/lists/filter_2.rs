@@ -0,0 +1,2 @@
+test_stdout!(with_even_predicate, "[2, 4, 6]\n");
+test_stdout!(with_non_boolean_predicate_result_errors_badarg, "{caught, error, badarg}\n");
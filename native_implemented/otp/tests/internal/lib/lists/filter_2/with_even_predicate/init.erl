@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [filter/2]).
+
+start() ->
+  display(filter(fun(X) -> X rem 2 == 0 end, [1, 2, 3, 4, 5, 6])).
@@ -0,0 +1,11 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [filter/2]).
+
+start() ->
+  try filter(fun(X) -> X end, [1]) of
+    Filtered -> display({filtered, Filtered})
+  catch
+    Class:Reason -> display({caught, Class, Reason})
+  end.
@@ -0,0 +1,4 @@
+test_stdout!(
+    with_list_returning_fun_returns_flattened_list,
+    "[1, 1, 2, 2, 3, 3]\n"
+);
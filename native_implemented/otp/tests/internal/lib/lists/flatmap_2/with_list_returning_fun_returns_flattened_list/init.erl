@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [flatmap/2]).
+
+start() ->
+  display(flatmap(fun (X) -> [X, X] end, [1, 2, 3])).
@@ -0,0 +1 @@
+test_stdout!(with_sum, "10\n[3, 2, 1]\n");
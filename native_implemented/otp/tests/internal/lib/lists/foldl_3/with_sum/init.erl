@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [foldl/3]).
+
+start() ->
+  display(foldl(fun(X, Sum) -> X + Sum end, 0, [1, 2, 3, 4])),
+  display(foldl(fun(X, Acc) -> [X | Acc] end, [], [1, 2, 3])).
@@ -0,0 +1 @@
+test_stdout!(processes_right_to_left, "[1, 2, 3]\n");
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [foldr/3]).
+
+start() ->
+  display(foldr(fun(X, Acc) -> [X | Acc] end, [], [1, 2, 3])).
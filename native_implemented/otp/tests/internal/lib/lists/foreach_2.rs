@@ -0,0 +1 @@
+test_stdout!(calls_fun_in_order_and_returns_ok, "1\n2\n3\nok\n");
@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [foreach/2]).
+
+start() ->
+  Returned = foreach(fun(X) -> display(X) end, [1, 2, 3]),
+  display(Returned).
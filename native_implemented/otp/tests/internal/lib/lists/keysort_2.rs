@@ -0,0 +1 @@
+test_stdout!(with_equal_keys_is_stable, "[{a, 1}, {d, 1}, {b, 2}, {c, 2}]\n");
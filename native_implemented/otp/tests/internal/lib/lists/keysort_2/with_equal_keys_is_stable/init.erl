@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [keysort/2]).
+
+start() ->
+  display(keysort(2, [{b, 2}, {a, 1}, {c, 2}, {d, 1}])).
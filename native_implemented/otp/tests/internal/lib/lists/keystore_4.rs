@@ -0,0 +1,4 @@
+test_stdout!(
+    without_key_appends_new_tuple,
+    "[{a, 1}, {b, 2}]\n[{a, 3}]\n"
+);
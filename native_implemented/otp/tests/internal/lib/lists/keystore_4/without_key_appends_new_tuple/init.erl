@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [keystore/4]).
+
+start() ->
+  display(keystore(b, 1, [{a, 1}], {b, 2})),
+  display(keystore(a, 1, [{a, 1}], {a, 3})).
@@ -0,0 +1,4 @@
+test_stdout!(
+    returns_value_or_false,
+    "{value, {b, 2}, [{a, 1}, c]}\nfalse\n"
+);
@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [keytake/3]).
+
+start() ->
+  display(keytake(b, 1, [{a, 1}, {b, 2}, c])),
+  display(keytake(d, 1, [{a, 1}, c])).
@@ -0,0 +1 @@
+test_stdout!(with_doubling_fun, "[2, 4, 6]\n");
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [map/2]).
+
+start() ->
+  display(map(fun(X) -> X * 2 end, [1, 2, 3])).
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [max/1]).
+
+start() ->
+  display(max([3, atom, {tuple}, []])).
@@ -0,0 +1 @@
+test_stdout!(with_mixed_types_uses_term_order, "3\n");
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [min/1]).
+
+start() ->
+  display(min([atom, {tuple}, [], 3])).
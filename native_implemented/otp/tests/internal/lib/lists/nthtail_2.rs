@@ -0,0 +1,4 @@
+test_stdout!(
+    with_n_greater_than_length_errors_badarg,
+    "[3]\n{caught, error, badarg}\n"
+);
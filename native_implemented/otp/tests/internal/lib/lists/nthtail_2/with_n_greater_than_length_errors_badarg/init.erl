@@ -0,0 +1,12 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [nthtail/2]).
+
+start() ->
+  display(nthtail(2, [1, 2, 3])),
+  try nthtail(4, [1, 2, 3]) of
+    Tail -> display({tail, Tail})
+  catch
+    Class:Reason -> display({caught, Class, Reason})
+  end.
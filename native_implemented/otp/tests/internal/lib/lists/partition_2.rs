@@ -0,0 +1 @@
+test_stdout!(with_even_predicate, "{[2, 4], [1, 3, 5]}\n");
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [partition/2]).
+
+start() ->
+  display(partition(fun(X) -> X rem 2 == 0 end, [1, 2, 3, 4, 5])).
@@ -0,0 +1 @@
+test_stdout!(with_mixed_types_sorts_in_term_order, "[1, 2.0, 3, a, b, {1}, [], [1]]\n");
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [sort/1]).
+
+start() ->
+  display(sort([[1], b, [], {1}, 3, a, 2.0, 1])).
@@ -0,0 +1 @@
+test_stdout!(with_descending_comparator, "[5, 4, 3, 2, 1]\n[{1, b}, {1, d}, {2, a}, {2, c}]\n");
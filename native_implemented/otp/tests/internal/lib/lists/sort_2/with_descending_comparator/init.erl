@@ -0,0 +1,10 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [sort/2]).
+
+start() ->
+  display(sort(fun(A, B) -> A >= B end, [3, 1, 5, 2, 4])),
+  %% stable for elements that compare equal
+  ByKey = fun({A, _}, {B, _}) -> A =< B end,
+  display(sort(ByKey, [{2, a}, {1, b}, {2, c}, {1, d}])).
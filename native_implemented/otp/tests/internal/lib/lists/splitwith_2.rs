@@ -0,0 +1,4 @@
+test_stdout!(
+    splits_at_first_false,
+    "{[1, 2], [3, 1]}\n{caught, error, badarg}\n"
+);
@@ -0,0 +1,12 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [splitwith/2]).
+
+start() ->
+  display(splitwith(fun (X) -> X < 3 end, [1, 2, 3, 1])),
+  try splitwith(fun (X) -> X end, [1]) of
+    Split -> display({split, Split})
+  catch
+    Class:Reason -> display({caught, Class, Reason})
+  end.
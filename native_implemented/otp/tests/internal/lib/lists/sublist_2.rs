@@ -0,0 +1,4 @@
+test_stdout!(
+    with_len_greater_than_length_returns_list,
+    "[1, 2]\n[2, 3]\n"
+);
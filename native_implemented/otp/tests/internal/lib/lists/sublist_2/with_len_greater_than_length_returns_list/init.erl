@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [sublist/2, sublist/3]).
+
+start() ->
+  display(sublist([1, 2], 5)),
+  display(sublist([1, 2, 3], 2, 5)).
@@ -0,0 +1 @@
+test_stdout!(with_integers_and_floats_returns_float, "6.5\n");
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [sum/1]).
+
+start() ->
+  display(sum([1, 2.5, 3])).
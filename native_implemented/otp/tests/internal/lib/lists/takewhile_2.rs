@@ -0,0 +1 @@
+test_stdout!(stops_at_first_false, "[1, 2]\n");
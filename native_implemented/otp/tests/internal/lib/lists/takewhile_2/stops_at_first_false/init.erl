@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [takewhile/2]).
+
+start() ->
+  display(takewhile(fun (X) -> X < 3 end, [1, 2, 3, 1])).
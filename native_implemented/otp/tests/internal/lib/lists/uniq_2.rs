@@ -0,0 +1 @@
+test_stdout!(keeps_first_element_with_each_key, "[{a, 1}, {b, 2}]\n");
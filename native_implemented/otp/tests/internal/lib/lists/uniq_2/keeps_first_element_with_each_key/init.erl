@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1, element/2]).
+-import(lists, [uniq/2]).
+
+start() ->
+  display(uniq(fun (T) -> element(2, T) end, [{a, 1}, {b, 2}, {c, 1}, {d, 2}])).
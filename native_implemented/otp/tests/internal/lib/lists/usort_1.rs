@@ -0,0 +1 @@
+test_stdout!(with_integer_and_float_keeps_both, "[1, 1.0, a]\n");
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [usort/1]).
+
+start() ->
+  display(usort([a, 1, 1.0, a, 1])).
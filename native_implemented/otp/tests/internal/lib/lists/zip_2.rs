@@ -0,0 +1,2 @@
+test_stdout!(with_same_length_lists, "[{a, 1}, {b, 2}]\n");
+test_stdout!(with_different_length_lists_errors_badarg, "{caught, error, badarg}\n");
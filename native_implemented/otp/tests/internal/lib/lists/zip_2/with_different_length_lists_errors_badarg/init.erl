@@ -0,0 +1,11 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [zip/2]).
+
+start() ->
+  try zip([a, b], [1]) of
+    Zipped -> display({zipped, Zipped})
+  catch
+    Class:Reason -> display({caught, Class, Reason})
+  end.
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [zip/2]).
+
+start() ->
+  display(zip([a, b], [1, 2])).
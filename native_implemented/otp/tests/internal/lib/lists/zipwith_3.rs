@@ -0,0 +1 @@
+test_stdout!(with_adding_fun, "[11, 22]\n");
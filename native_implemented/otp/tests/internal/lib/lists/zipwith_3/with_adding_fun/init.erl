@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lists, [zipwith/3]).
+
+start() ->
+  display(zipwith(fun(X, Y) -> X + Y end, [1, 2], [10, 20])).
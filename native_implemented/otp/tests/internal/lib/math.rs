@@ -0,0 +1,6 @@
+#[path = "math/cos_1.rs"]
+pub mod cos_1;
+#[path = "math/pi_0.rs"]
+pub mod pi_0;
+#[path = "math/sqrt_1.rs"]
+pub mod sqrt_1;
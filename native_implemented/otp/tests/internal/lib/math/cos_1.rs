@@ -0,0 +1 @@
+test_stdout!(with_zero_returns_one, "1.0\n1.0\n");
@@ -0,0 +1 @@
+test_stdout!(returns_pi, "3.141592653589793\n");
@@ -0,0 +1 @@
+test_stdout!(with_integer_returns_float, "1.4142135623730951\n{caught, error, badarith}\n");
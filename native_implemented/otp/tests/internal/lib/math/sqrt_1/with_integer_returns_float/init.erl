@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display(math:sqrt(2)),
+  test:caught(fun () ->
+    math:sqrt(-1)
+  end).
@@ -0,0 +1,11 @@
+test_stdout!(with_timeout_without_message_runs_after, "timeout\n");
+test_stdout!(with_matching_message_does_not_wait_for_timeout, "{received, message}\n");
+test_stdout!(with_message_sent_while_waiting_resumes_and_receives, "{received, message}\n");
+test_stdout!(
+    with_non_matching_message_before_matching_message_receives_matching,
+    "{received, second}\n{left, first}\n"
+);
+test_stdout!(
+    with_non_matching_message_while_waiting_resumes_until_matching,
+    "{received, second}\n{left, first}\n"
+);
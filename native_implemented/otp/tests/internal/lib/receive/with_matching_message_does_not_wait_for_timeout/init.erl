@@ -0,0 +1,11 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  self() ! message,
+  receive
+    message -> display({received, message})
+  after 50 ->
+    display(timeout)
+  end.
@@ -0,0 +1,17 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Parent = self(),
+  spawn(fun () ->
+    receive
+    after 10 ->
+      Parent ! message
+    end
+  end),
+  receive
+    message -> display({received, message})
+  after 1000 ->
+    display(timeout)
+  end.
@@ -0,0 +1,18 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Self = self(),
+  Self ! first,
+  Self ! second,
+  receive
+    second -> display({received, second})
+  after 50 ->
+    display(timeout)
+  end,
+  receive
+    Left -> display({left, Left})
+  after 0 ->
+    display(empty)
+  end.
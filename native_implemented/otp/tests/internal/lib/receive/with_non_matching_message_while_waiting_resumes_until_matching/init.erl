@@ -0,0 +1,26 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Parent = self(),
+  spawn(fun () ->
+    receive
+    after 10 ->
+      Parent ! first
+    end,
+    receive
+    after 10 ->
+      Parent ! second
+    end
+  end),
+  receive
+    second -> display({received, second})
+  after 1000 ->
+    display(timeout)
+  end,
+  receive
+    Left -> display({left, Left})
+  after 0 ->
+    display(empty)
+  end.
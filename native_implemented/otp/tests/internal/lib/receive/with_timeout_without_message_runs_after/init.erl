@@ -0,0 +1,10 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  receive
+    Message -> display({received, Message})
+  after 50 ->
+    display(timeout)
+  end.
@@ -0,0 +1,10 @@
+#[path = "string/length_1.rs"]
+pub mod length_1;
+#[path = "string/split_3.rs"]
+pub mod split_3;
+#[path = "string/to_lower_1.rs"]
+pub mod to_lower_1;
+#[path = "string/to_upper_1.rs"]
+pub mod to_upper_1;
+#[path = "string/trim_3.rs"]
+pub mod trim_3;
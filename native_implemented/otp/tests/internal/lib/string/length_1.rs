@@ -0,0 +1 @@
+test_stdout!(with_multibyte_utf8_binary, "8\n6\n");
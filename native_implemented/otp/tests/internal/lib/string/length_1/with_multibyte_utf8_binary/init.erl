@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [byte_size/1, display/1]).
+
+start() ->
+  Binary = <<"héllo→"/utf8>>,
+  display(byte_size(Binary)),
+  display(string:length(Binary)).
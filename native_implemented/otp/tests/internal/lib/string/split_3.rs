@@ -0,0 +1 @@
+test_stdout!(with_all_splits_at_every_occurrence, "[\"a\", \"b\", [], \"c\"]\n");
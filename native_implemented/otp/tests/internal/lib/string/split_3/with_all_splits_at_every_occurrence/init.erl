@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(string, [split/3]).
+
+start() ->
+  display(split("a,b,,c", ",", all)).
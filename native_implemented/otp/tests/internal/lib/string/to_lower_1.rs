@@ -0,0 +1 @@
+test_stdout!(with_binary_and_latin1_charlist, "<<\"abc\">>\ntrue\n");
@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(string, [to_lower/1]).
+
+start() ->
+  display(to_lower(<<"ABC">>)),
+  display(to_lower([$C, $A, $F, 201]) =:= [$c, $a, $f, 233]).
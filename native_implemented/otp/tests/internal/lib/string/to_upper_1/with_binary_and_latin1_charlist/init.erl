@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(string, [to_upper/1]).
+
+start() ->
+  display(to_upper(<<"abc">>)),
+  display(to_upper([$c, $a, $f, 233]) =:= [$C, $A, $F, 201]).
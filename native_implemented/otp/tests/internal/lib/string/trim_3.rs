@@ -0,0 +1 @@
+test_stdout!(with_trailing_and_custom_characters, "<<\"  abc\">>\n");
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(string, [trim/3]).
+
+start() ->
+  display(trim(<<"  abc.!.">>, trailing, ".!")).
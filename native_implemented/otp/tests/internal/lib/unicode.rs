@@ -0,0 +1,2 @@
+#[path = "unicode/characters_to_binary_1.rs"]
+pub mod characters_to_binary_1;
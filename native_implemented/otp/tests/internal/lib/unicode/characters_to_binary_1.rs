@@ -0,0 +1,4 @@
+test_stdout!(
+    with_valid_and_malformed_data,
+    "<<104,195,169,108,108,111>>\n{error, <<\"a\">>, <<255,98>>}\n"
+);
@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(unicode, [characters_to_binary/1]).
+
+start() ->
+  display(characters_to_binary(["h", 233, <<"llo">>])),
+  display(characters_to_binary(<<"a", 255, "b">>)).
@@ -63,6 +63,23 @@ pub fn try_split_at(bytes: &[u8], mid: usize) -> InternalResult<(&[u8], &[u8])>
     }
 }
 
+/// Checks that `len` elements, each encoded in at least `min_element_byte_len` bytes, could fit in
+/// `bytes`, so that a malformed length can't cause a huge allocation before decoding runs out of
+/// bytes.
+pub fn check_len(bytes: &[u8], len: usize, min_element_byte_len: usize) -> InternalResult<()> {
+    let available = bytes.len();
+
+    match len.checked_mul(min_element_byte_len) {
+        Some(needed) if needed <= available => Ok(()),
+        _ => Err(DecodeError::LengthExceedsBytes {
+            len,
+            available,
+            backtrace: Backtrace::capture(),
+        }
+        .into()),
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum DecodeError {
     #[error("needed {needed} bytes, but only {available} available")]
@@ -71,6 +88,12 @@ pub enum DecodeError {
         available: usize,
         backtrace: Backtrace,
     },
+    #[error("length ({len}) cannot fit in the {available} bytes available")]
+    LengthExceedsBytes {
+        len: usize,
+        available: usize,
+        backtrace: Backtrace,
+    },
     #[error("unexpected version ({version})")]
     UnexpectedVersion { version: u8, backtrace: Backtrace },
     #[error("unexpected tag ({tag})")]
@@ -194,6 +217,9 @@ fn decode_vec_term<'a>(
     bytes: &'a [u8],
     len: usize,
 ) -> InternalResult<(Vec<Term>, &'a [u8])> {
+    // every element has at least a tag byte
+    check_len(bytes, len, 1)?;
+
     let mut element_vec: Vec<Term> = Vec::with_capacity(len);
     let mut remaining_bytes = bytes;
 
@@ -4,7 +4,7 @@ use liblumen_alloc::erts::exception::InternalResult;
 use liblumen_alloc::erts::term::prelude::*;
 use liblumen_alloc::erts::Process;
 
-use super::{term, u32};
+use super::{check_len, term, u32};
 
 pub fn decode<'a>(
     process: &Process,
@@ -13,6 +13,8 @@ pub fn decode<'a>(
 ) -> InternalResult<(Term, &'a [u8])> {
     let (pair_len_u32, after_len_bytes) = u32::decode(bytes)?;
     let pair_len_usize = pair_len_u32 as usize;
+    // every pair has at least a tag byte for its key and its value
+    check_len(after_len_bytes, pair_len_usize, 2)?;
     let mut hash_map: HashMap<Term, Term> = HashMap::with_capacity(pair_len_usize);
     let mut remaining_bytes = after_len_bytes;
 
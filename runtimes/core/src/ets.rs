@@ -0,0 +1,285 @@
+//! Node-level registry of ETS tables, mapping table references and names to `Table`s.
+//!
+//! Objects are copied out of the inserting process into their own `HeapFragment`, so they outlive
+//! that process, and are copied back into the calling process when read.
+use std::convert::TryInto;
+use std::hash::{Hash, Hasher};
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use anyhow::*;
+use dashmap::mapref::entry::Entry;
+use dashmap::DashMap;
+use hashbrown::HashMap;
+use lazy_static::lazy_static;
+
+use liblumen_core::locks::RwLock;
+
+use liblumen_alloc::erts::exception::{self, AllocResult};
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::{CloneToProcess, HeapFragment, Process};
+
+lazy_static! {
+    static ref TABLE_BY_REFERENCE: DashMap<Reference, Arc<Table>> = Default::default();
+    static ref REFERENCE_BY_NAME: DashMap<Atom, Reference> = Default::default();
+}
+
+pub fn name_to_table(name: &Atom) -> Option<Arc<Table>> {
+    REFERENCE_BY_NAME
+        .get(name)
+        .and_then(|reference| reference_to_table(reference.value()))
+}
+
+pub fn reference_to_table(reference: &Reference) -> Option<Arc<Table>> {
+    TABLE_BY_REFERENCE
+        .get(reference)
+        .map(|table| table.value().clone())
+}
+
+/// Registers `table` by its reference and, if it is a named table, by its name.  Returns `false`
+/// without registering `table` if its name is already taken.
+pub fn register(table: Table) -> bool {
+    if let Some(name) = table.name {
+        match REFERENCE_BY_NAME.entry(name) {
+            Entry::Occupied(_) => return false,
+            Entry::Vacant(vacant) => {
+                vacant.insert(table.reference);
+            }
+        }
+    }
+
+    TABLE_BY_REFERENCE.insert(table.reference, Arc::new(table));
+
+    true
+}
+
+/// Unregisters `table`, so it can no longer be found by its reference or name.  Its objects are
+/// freed once the last `Arc` to it is dropped.
+pub fn unregister(table: &Table) {
+    if let Some(name) = table.name {
+        REFERENCE_BY_NAME.remove(&name);
+    }
+
+    TABLE_BY_REFERENCE.remove(&table.reference);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Access {
+    /// Any process can read or write
+    Public,
+    /// Any process can read, but only the owner can write
+    Protected,
+    /// Only the owner can read or write
+    Private,
+}
+
+impl Default for Access {
+    fn default() -> Self {
+        Access::Protected
+    }
+}
+
+pub struct Table {
+    pub reference: Reference,
+    pub name: Option<Atom>,
+    pub owner: Pid,
+    pub access: Access,
+    pub key_position: OneBasedIndex,
+    objects: RwLock<HashMap<Key, Object>>,
+}
+
+impl Table {
+    pub fn new(
+        reference: Reference,
+        name: Option<Atom>,
+        owner: Pid,
+        access: Access,
+        key_position: OneBasedIndex,
+    ) -> Self {
+        Self {
+            reference,
+            name,
+            owner,
+            access,
+            key_position,
+            objects: Default::default(),
+        }
+    }
+
+    pub fn is_readable_by(&self, pid: Pid) -> bool {
+        match self.access {
+            Access::Public | Access::Protected => true,
+            Access::Private => pid == self.owner,
+        }
+    }
+
+    pub fn is_writable_by(&self, pid: Pid) -> bool {
+        match self.access {
+            Access::Public => true,
+            Access::Protected | Access::Private => pid == self.owner,
+        }
+    }
+
+    /// Deletes the object with `key`, if any.
+    pub fn delete(&self, key: Term) {
+        self.objects.write().remove(&Key(key));
+    }
+
+    /// Returns the first key in the table's iteration order, copied to `process`, or `None` if the
+    /// table is empty.
+    pub fn first(&self, process: &Process) -> Option<Term> {
+        self.objects
+            .read()
+            .keys()
+            .next()
+            .map(|key| key.0.clone_to_process(process))
+    }
+
+    /// Inserts all `tuples` at once, replacing any objects with the same keys.  Each tuple must
+    /// have at least `key_position` elements.
+    pub fn insert(&self, tuples: &[Term]) -> AllocResult<()> {
+        let mut objects = Vec::with_capacity(tuples.len());
+
+        for tuple in tuples {
+            objects.push(Object::new(*tuple, self.key_position)?);
+        }
+
+        let mut writable_objects = self.objects.write();
+
+        for object in objects {
+            let key = object.key(self.key_position);
+            // `insert` would keep the old key, which lives in the replaced object's fragment
+            writable_objects.remove(&key);
+            writable_objects.insert(key, object);
+        }
+
+        Ok(())
+    }
+
+    pub fn is_member(&self, key: Term) -> bool {
+        self.objects.read().contains_key(&Key(key))
+    }
+
+    /// Returns the list of objects with `key`, copied to `process`.
+    pub fn lookup(&self, process: &Process, key: Term) -> Term {
+        match self.objects.read().get(&Key(key)) {
+            Some(object) => {
+                let tuple = object.tuple.clone_to_process(process);
+
+                process.list_from_slice(&[tuple])
+            }
+            None => Term::NIL,
+        }
+    }
+
+    /// Atomically replaces the object with `key` by the tuple that `update` returns for it and
+    /// returns the new object copied to `process`, or returns `None` without calling `update` if
+    /// there is no object with `key`.  The table stays locked until the new object is stored, so
+    /// concurrent updates are never lost.  `update` must not change the key.
+    pub fn update<F>(
+        &self,
+        process: &Process,
+        key: Term,
+        update: F,
+    ) -> exception::Result<Option<Term>>
+    where
+        F: FnOnce(Boxed<Tuple>) -> exception::Result<Term>,
+    {
+        let mut writable_objects = self.objects.write();
+        let key = Key(key);
+
+        let tuple = match writable_objects.get(&key) {
+            Some(object) => object.boxed_tuple(),
+            None => return Ok(None),
+        };
+        let updated_tuple = update(tuple)?;
+        let updated_object = Object::new(updated_tuple, self.key_position)?;
+        let updated_key = updated_object.key(self.key_position);
+        assert!(updated_key == key);
+        // `updated_tuple` can share elements with the old object, whose fragment is freed when it
+        // is removed, so return a copy of the new object instead.
+        let copied_tuple = updated_object.tuple.clone_to_process(process);
+
+        writable_objects.remove(&key);
+        writable_objects.insert(updated_key, updated_object);
+
+        Ok(Some(copied_tuple))
+    }
+
+    /// Returns the key after `key` in the table's iteration order, copied to `process`, or `None`
+    /// if `key` is the last key.  The iteration order is stable as long as the table is not
+    /// modified.
+    pub fn next(&self, process: &Process, key: Term) -> anyhow::Result<Option<Term>> {
+        let readable_objects = self.objects.read();
+        let mut keys = readable_objects.keys();
+        let key = Key(key);
+
+        if keys.any(|candidate| candidate == &key) {
+            Ok(keys.next().map(|next| next.0.clone_to_process(process)))
+        } else {
+            Err(anyhow!("key ({}) is not in table", key.0))
+        }
+    }
+}
+
+// Private
+
+/// A key compared with `=:=`, as `set` tables do.
+#[derive(Clone, Copy)]
+struct Key(Term);
+
+impl Eq for Key {}
+
+impl Hash for Key {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl PartialEq for Key {
+    fn eq(&self, other: &Key) -> bool {
+        self.0
+            .decode()
+            .unwrap()
+            .exact_eq(&other.0.decode().unwrap())
+    }
+}
+
+/// A tuple copied into its own `HeapFragment`, which is freed when the object is dropped.
+struct Object {
+    heap_fragment: NonNull<HeapFragment>,
+    tuple: Term,
+}
+
+impl Object {
+    fn new(tuple: Term, key_position: OneBasedIndex) -> AllocResult<Self> {
+        let (tuple, heap_fragment) = tuple.clone_to_fragment()?;
+        let object = Self {
+            heap_fragment,
+            tuple,
+        };
+        assert!(object.boxed_tuple().get_element(key_position).is_ok());
+
+        Ok(object)
+    }
+
+    fn boxed_tuple(&self) -> Boxed<Tuple> {
+        self.tuple.try_into().unwrap()
+    }
+
+    fn key(&self, key_position: OneBasedIndex) -> Key {
+        Key(self.boxed_tuple().get_element(key_position).unwrap())
+    }
+}
+
+impl Drop for Object {
+    fn drop(&mut self) {
+        unsafe {
+            self.heap_fragment.as_ptr().drop_in_place();
+        }
+    }
+}
+
+// The fragment is only ever accessed through the table's lock
+unsafe impl Send for Object {}
+unsafe impl Sync for Object {}
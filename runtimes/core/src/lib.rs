@@ -10,6 +10,7 @@ pub mod binary_to_string;
 pub mod builtins;
 pub mod context;
 pub mod distribution;
+pub mod ets;
 pub mod integer_to_string;
 pub mod process;
 pub mod proplist;
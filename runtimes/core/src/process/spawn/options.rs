@@ -7,7 +7,7 @@ use anyhow::*;
 use liblumen_alloc::erts::exception::Alloc;
 use liblumen_alloc::erts::process::alloc::{default_heap_size, heap, next_heap_size};
 use liblumen_alloc::erts::process::priority::Priority;
-use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::process::{Process, Reductions};
 use liblumen_alloc::erts::term::prelude::*;
 use liblumen_alloc::ModuleFunctionArity;
 
@@ -45,6 +45,12 @@ impl MaxHeapSize {
     pub fn error_logger(&mut self, error_logger: bool) {
         self.error_logger = Some(error_logger);
     }
+
+    /// Whether the process is killed when its heap exceeds `size`.  Unless given, it is, like the
+    /// `kill` flag of `max_heap_size` in `erlang:process_flag/2`.
+    fn is_kill(&self) -> bool {
+        self.kill.unwrap_or(true)
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -59,6 +65,8 @@ pub struct Options {
     pub min_bin_vheap_size: Option<usize>,
     pub max_heap_size: Option<MaxHeapSize>,
     pub message_queue_data: MessageQueueData,
+    /// When reduction budget is not set, the process uses `DEFAULT_REDUCTION_BUDGET`
+    pub reduction_budget: Option<Reductions>,
 }
 
 impl Options {
@@ -66,7 +74,7 @@ impl Options {
         match self.priority {
             Some(priority) => priority,
             None => match parent_process {
-                Some(process) => process.priority,
+                Some(process) => process.priority(),
                 None => Default::default(),
             },
         }
@@ -96,7 +104,7 @@ impl Options {
     }
 
     pub fn sized_heap(&self) -> Result<(*mut Term, usize), Alloc> {
-        let heap_size = self.heap_size().map_err(|_| Alloc::new())?;
+        let heap_size = self.heap_size();
         let heap = heap(heap_size)?;
 
         Ok((heap, heap_size))
@@ -129,30 +137,27 @@ impl Options {
             heap_size,
         );
 
+        if let Some(reduction_budget) = self.reduction_budget {
+            process.set_reduction_budget(reduction_budget);
+        }
+
+        if let Some(max_heap_size) = self.max_heap_size {
+            process.set_max_heap_size(max_heap_size.size, max_heap_size.is_kill());
+        }
+
         Ok(process)
     }
 
     // Private
 
     /// `heap` size in words.
-    fn heap_size(&self) -> Result<usize, anyhow::Error> {
-        let size = match self.min_heap_size {
+    ///
+    /// `max_heap_size` does not bound the initial heap: like the BEAM, it is only checked when
+    /// the heap is garbage collected, so a `min_heap_size` above it is still allocated in full.
+    fn heap_size(&self) -> usize {
+        match self.min_heap_size {
             Some(min_heap_size) => next_heap_size(min_heap_size),
             None => default_heap_size(),
-        };
-        match self.max_heap_size {
-            Some(MaxHeapSize {
-                size: max_size,
-                kill,
-                ..
-            }) if size >= max_size => {
-                if kill.unwrap_or_default() {
-                    Err(anyhow!("exceeded maximum heap size of {}", max_size))
-                } else {
-                    Ok(max_size)
-                }
-            }
-            _ => Ok(size),
         }
     }
 
@@ -203,10 +208,10 @@ impl Options {
                     let max_heap_size_config: Result<Boxed<Map>, _> = element.try_into();
                     match max_heap_size_config {
                         Ok(max_heap_config) => {
-                            let max_heap_size_opt = Atom::from_str("max_heap_size");
+                            let size_opt = Atom::from_str("size");
                             let max_heap_size = max_heap_config
-                                .get(max_heap_size_opt.encode().unwrap())
-                                .ok_or_else(|| anyhow!("missing max_heap_size key in map"))
+                                .get(size_opt.encode().unwrap())
+                                .ok_or_else(|| anyhow!("missing size key in map"))
                                 .and_then(|term| {
                                     term.try_into()
                                         .map_err(|err: TryIntoIntegerError| anyhow!(err))
@@ -254,6 +259,24 @@ impl Options {
 
                     Ok(self)
                 }
+                "reduction_budget" => {
+                    let reduction_budget: usize =
+                        tuple[1].try_into().context("reduction_budget")?;
+                    let reduction_budget: Reductions = reduction_budget
+                        .try_into()
+                        .ok()
+                        .filter(|reduction_budget| *reduction_budget > 0)
+                        .ok_or_else(|| {
+                            anyhow!(
+                                "reduction_budget ({}) is not in 1..={}",
+                                reduction_budget,
+                                Reductions::MAX
+                            )
+                        })?;
+                    self.reduction_budget = Some(reduction_budget);
+
+                    Ok(self)
+                }
                 name => Err(TryPropListFromTermError::KeywordKeyName(name).into()),
             }
         } else {
@@ -273,6 +296,7 @@ impl Default for Options {
             min_bin_vheap_size: None,
             max_heap_size: None,
             message_queue_data: Default::default(),
+            reduction_budget: None,
         }
     }
 }
@@ -282,8 +306,9 @@ const SUPPORTED_OPTIONS_CONTEXT: &str = "supported options are :link, :monitor,
      {:max_heap_size, words :: pos_integer() | #{size => non_neg_integer(), kill => boolean(), error_logger => boolean()}}, \
      {:message_queue_data, :off_heap | :on_heap}, \
      {:min_bin_vheap_size, words :: pos_integer()}, \
-     {:min_heap_size, words :: pos_integer()}, and \
-     {:priority, level :: :low | :normal | :high | :max}";
+     {:min_heap_size, words :: pos_integer()}, \
+     {:priority, level :: :low | :normal | :high | :max}, and \
+     {:reduction_budget, reductions :: pos_integer()}";
 
 impl TryFrom<Term> for Options {
     type Error = anyhow::Error;
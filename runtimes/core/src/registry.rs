@@ -37,6 +37,14 @@ pub fn names(process: &Process) -> exception::Result<Term> {
     Ok(acc)
 }
 
+/// The number of processes that are still alive
+pub fn process_count() -> usize {
+    WEAK_PROCESS_CONTROL_BLOCK_BY_PID
+        .iter()
+        .filter(|weak_process| weak_process.value().upgrade().is_some())
+        .count()
+}
+
 pub fn pid_to_process(pid: &Pid) -> Option<Arc<Process>> {
     WEAK_PROCESS_CONTROL_BLOCK_BY_PID
         .get(pid)
@@ -39,6 +39,44 @@ fn current_from_id(id: &ID) -> Option<Arc<dyn Scheduler>> {
     })
 }
 
+/// The number of schedulers that are registered and still running
+pub fn count() -> usize {
+    SCHEDULER_BY_ID
+        .lock()
+        .values()
+        .filter(|weak_scheduler| weak_scheduler.upgrade().is_some())
+        .count()
+}
+
+/// The registered and still running schedulers other than the scheduler with `id`, for it to
+/// `steal` from when it is idle.
+pub fn others(id: &ID) -> Vec<Arc<dyn Scheduler>> {
+    // Collected, so that `SCHEDULER_BY_ID` isn't locked while the run queues are locked
+    SCHEDULER_BY_ID
+        .lock()
+        .iter()
+        .filter(|(other_id, _)| *other_id != id)
+        .filter_map(|(_, weak_scheduler)| weak_scheduler.upgrade())
+        .collect()
+}
+
+/// Steals a runnable process for the scheduler with `thief_id` from the scheduler in `candidates`
+/// with the most runnable processes.  The stolen process is scheduled with the thief, but it is up
+/// to the thief to enqueue it.
+pub fn steal_for(thief_id: &ID, candidates: &[Arc<dyn Scheduler>]) -> Option<Arc<Process>> {
+    let victim = candidates
+        .iter()
+        .filter(|candidate| &candidate.id() != thief_id)
+        .max_by_key(|candidate| candidate.runnable_len())
+        .filter(|candidate| 1 < candidate.runnable_len())?;
+    // The victim's run queue lock is released before the thief's is taken, so that two
+    // schedulers stealing from each other can't deadlock.
+    let arc_process = victim.steal_from()?;
+    arc_process.schedule_with(*thief_id);
+
+    Some(arc_process)
+}
+
 pub fn from_id(id: &ID) -> Option<Arc<dyn Scheduler>> {
     current_from_id(id).or_else(|| {
         SCHEDULER_BY_ID
@@ -161,6 +199,13 @@ pub trait Scheduler: Debug + Send + Sync {
     fn run_queue_len(&self, priority: Priority) -> usize;
     /// Returns the length of the current scheduler's run queue
     fn run_queues_len(&self) -> usize;
+    /// Returns the number of processes in the current scheduler's run queues that can run now
+    fn runnable_len(&self) -> usize {
+        // `Priority::Low` and `Priority::Normal` share a run queue
+        self.run_queue_len(Priority::Normal)
+            + self.run_queue_len(Priority::High)
+            + self.run_queue_len(Priority::Max)
+    }
     /// Schedules the given process for execution
     fn schedule(&self, process: Process) -> Arc<Process>;
     /// Spawns the init process, should be called immediately after
@@ -193,8 +238,41 @@ pub trait Scheduler: Debug + Send + Sync {
         function: Atom,
         arguments: Vec<Term>,
         options: Options,
+    ) -> anyhow::Result<Spawned> {
+        self.spawn_module_function_arguments_before_schedule(
+            parent,
+            module,
+            function,
+            arguments,
+            options,
+            &mut |_, _| (),
+        )
+    }
+    /// Spawns a new process like `spawn_module_function_arguments`, but calls `before_schedule`
+    /// with the child process and its connection to `parent` before the child is scheduled.
+    ///
+    /// As the child cannot run until it is scheduled, any message `before_schedule` sends to
+    /// `parent` is ordered before any message caused by the child, such as a `'DOWN'` message.
+    fn spawn_module_function_arguments_before_schedule(
+        &self,
+        parent: Option<&Process>,
+        module: Atom,
+        function: Atom,
+        arguments: Vec<Term>,
+        options: Options,
+        before_schedule: &mut dyn FnMut(&Process, &Connection),
     ) -> anyhow::Result<Spawned>;
     fn shutdown(&self) -> anyhow::Result<()>;
+    /// Steals a runnable process from the busiest of `candidates` to run on this scheduler when it
+    /// is idle.  Returns the stolen process, which is now scheduled on this scheduler.
+    fn steal(&self, _candidates: &[Arc<dyn Scheduler>]) -> Option<Arc<Process>> {
+        None
+    }
+    /// Gives up a runnable process for an idle scheduler to run instead.  The thief is responsible
+    /// for scheduling the process with itself.
+    fn steal_from(&self) -> Option<Arc<Process>> {
+        None
+    }
     fn stop_waiting(&self, process: &Process);
 }
 
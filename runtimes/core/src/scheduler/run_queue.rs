@@ -47,7 +47,7 @@ impl Queues {
     }
 
     pub fn enqueue(&mut self, arc_process: Arc<Process>) {
-        match arc_process.priority {
+        match arc_process.priority() {
             Priority::Low | Priority::Normal => self.normal_low.enqueue(arc_process),
             Priority::High => self.high.enqueue(arc_process),
             Priority::Max => self.max.enqueue(arc_process),
@@ -77,6 +77,27 @@ impl Queues {
         }
     }
 
+    /// Removes a runnable process for an idle scheduler to run instead.
+    ///
+    /// At least one runnable process is always left, so that processes don't bounce back and forth
+    /// between schedulers that are equally busy.  The lowest priority process that was enqueued
+    /// last is taken, as it would be the last to run here.
+    pub fn steal(&mut self) -> Option<Arc<Process>> {
+        if self.runnable_len() <= 1 {
+            None
+        } else {
+            self.normal_low
+                .steal()
+                .or_else(|| self.high.steal())
+                .or_else(|| self.max.steal())
+        }
+    }
+
+    /// The number of processes that can run now, so excluding waiting processes
+    pub fn runnable_len(&self) -> usize {
+        self.normal_low.len() + self.high.len() + self.max.len()
+    }
+
     pub fn stop_waiting(&mut self, process: &Process) {
         match self.waiting.get(process) {
             Some(arc_process) => {
@@ -179,6 +200,10 @@ impl Immediate {
     pub fn enqueue(&mut self, process: Arc<Process>) {
         self.0.push_back(process);
     }
+
+    pub fn steal(&mut self) -> Option<Arc<Process>> {
+        self.0.pop_back()
+    }
 }
 
 /// A run queue where the `Arc<Process` is run only when its delay is `0`.  This allows
@@ -218,6 +243,12 @@ impl Delayed {
         let delayed_process = DelayedProcess::new(arc_process);
         self.0.push_back(delayed_process);
     }
+
+    pub fn steal(&mut self) -> Option<Arc<Process>> {
+        self.0
+            .pop_back()
+            .map(|delayed_process| delayed_process.arc_process)
+    }
 }
 
 type Delay = u8;
@@ -231,7 +262,7 @@ struct DelayedProcess {
 impl DelayedProcess {
     fn new(arc_process: Arc<Process>) -> DelayedProcess {
         DelayedProcess {
-            delay: Self::priority_to_delay(arc_process.priority),
+            delay: Self::priority_to_delay(arc_process.priority()),
             arc_process,
         }
     }
@@ -246,3 +277,66 @@ impl DelayedProcess {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::ptr;
+
+    use liblumen_alloc::erts::term::prelude::Atom;
+    use liblumen_alloc::ModuleFunctionArity;
+
+    #[test]
+    fn steal_leaves_last_runnable_process() {
+        let mut queues = Queues::default();
+        queues.enqueue(process(Priority::Normal));
+
+        assert!(queues.steal().is_none());
+        assert_eq!(queues.runnable_len(), 1);
+    }
+
+    #[test]
+    fn steal_takes_last_enqueued_lowest_priority_process() {
+        let mut queues = Queues::default();
+        let first_normal = process(Priority::Normal);
+        let last_normal = process(Priority::Normal);
+        let high = process(Priority::High);
+        queues.enqueue(first_normal.clone());
+        queues.enqueue(last_normal.clone());
+        queues.enqueue(high.clone());
+
+        assert_eq!(queues.steal(), Some(last_normal.clone()));
+        assert!(!queues.contains(&last_normal));
+        assert!(queues.contains(&first_normal));
+        assert!(queues.contains(&high));
+        assert_eq!(queues.runnable_len(), 2);
+    }
+
+    #[test]
+    fn steal_does_not_take_or_count_waiting_processes() {
+        let mut queues = Queues::default();
+        let waiting = process(Priority::Normal);
+        waiting.wait();
+        assert!(queues.requeue(waiting.clone()).is_none());
+        queues.enqueue(process(Priority::Normal));
+
+        assert_eq!(queues.runnable_len(), 1);
+        assert!(queues.steal().is_none());
+        assert!(queues.contains(&waiting));
+    }
+
+    fn process(priority: Priority) -> Arc<Process> {
+        Arc::new(Process::new(
+            priority,
+            None,
+            ModuleFunctionArity {
+                module: Atom::from_str("test"),
+                function: Atom::from_str("steal"),
+                arity: 0,
+            },
+            ptr::null_mut(),
+            0,
+        ))
+    }
+}
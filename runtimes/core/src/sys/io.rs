@@ -8,6 +8,32 @@ extern "C" {
     pub fn console_log(s: &str);
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+pub fn print(s: &str) {
+    use std::io::Write;
+
+    // a closed or broken stdout must not take the runtime down with it
+    let mut stdout = std::io::stdout();
+    let _ = stdout.write_all(s.as_bytes());
+    let _ = stdout.flush();
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn print(s: &str) {
+    console_log(s);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn flush() {
+    use std::io::Write;
+
+    // there is nowhere left to report a failure to flush to
+    let _ = std::io::stdout().flush();
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn flush() {}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub fn puts(s: &str) {
     println!("{}", s);
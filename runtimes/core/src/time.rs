@@ -40,21 +40,19 @@ pub fn convert(time: BigInt, from_unit: Unit, to_unit: Unit) -> BigInt {
     if from_unit == to_unit {
         time
     } else {
-        let from_hertz = from_unit.hertz();
-        let to_hertz = to_unit.hertz();
-
-        if from_hertz <= to_hertz {
-            time * ((to_hertz / from_hertz) as i32)
+        let from_hertz: BigInt = from_unit.hertz().into();
+        let to_hertz: BigInt = to_unit.hertz().into();
+        // Scale before dividing, so that hertz that don't divide evenly, such as 3 to 1000, don't
+        // lose precision
+        let numerator = time * to_hertz;
+        let zero: BigInt = Zero::zero();
+
+        // mimic behavior of erts_napi_convert_time_unit, so that rounding is the same: floor
+        // division instead of `BigInt`'s truncating division
+        if zero <= numerator {
+            numerator / from_hertz
         } else {
-            // mimic behavior of erts_napi_convert_time_unit, so that rounding is the same
-            let denominator: BigInt = (from_hertz / to_hertz).into();
-            let zero: BigInt = Zero::zero();
-
-            if zero <= time {
-                time / denominator
-            } else {
-                (time - (denominator.clone() - 1)) / denominator
-            }
+            (numerator - (from_hertz.clone() - 1)) / from_hertz
         }
     }
 }
@@ -38,8 +38,8 @@ extern crate chrono;
 use anyhow::anyhow;
 
 pub use lumen_rt_core::{
-    base, binary_to_string, context, distribution, integer_to_string, proplist, registry, send,
-    test, time, timer,
+    base, binary_to_string, context, distribution, ets, integer_to_string, proplist, registry,
+    send, test, time, timer,
 };
 
 #[cfg(not(any(test, target_arch = "wasm32")))]
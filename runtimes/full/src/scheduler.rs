@@ -13,13 +13,15 @@ pub use liblumen_alloc::erts::scheduler::{id, ID};
 use liblumen_alloc::erts::term::prelude::*;
 use liblumen_alloc::{Arity, ModuleFunctionArity, Ran};
 
-use lumen_rt_core::process::spawn::options::Options;
+use lumen_rt_core::process::spawn::options::{Connection, Options};
 use lumen_rt_core::process::{log_exit, propagate_exit, CURRENT_PROCESS};
 use lumen_rt_core::registry::put_pid_to_process;
 pub use lumen_rt_core::scheduler::{
     current, from_id, run_through, Scheduled, SchedulerDependentAlloc, Spawned,
 };
-use lumen_rt_core::scheduler::{run_queue, unregister, Run, Scheduler as SchedulerTrait};
+use lumen_rt_core::scheduler::{
+    others, run_queue, steal_for, unregister, Run, Scheduler as SchedulerTrait,
+};
 use lumen_rt_core::timer::Hierarchy;
 
 use crate::process::out_of_code;
@@ -67,8 +69,10 @@ impl Scheduler {
     /// > -- [The Scheduler Loop](https://blog.stenmans.org/theBeamBook/#_the_scheduler_loop)
     pub fn run(&self) {
         loop {
-            // TODO sleep or steal if nothing run
-            let _ = self.run_once();
+            if !self.run_once() && self.steal(&others(&self.id)).is_none() {
+                // TODO sleep if nothing to steal
+                std::thread::yield_now();
+            }
         }
     }
 
@@ -224,7 +228,7 @@ impl SchedulerTrait for Scheduler {
                 }
                 Run::Delayed => continue,
                 Run::Waiting => break true,
-                // TODO steal processes or sleep if nothing to steal
+                // `run` steals processes when nothing was run
                 Run::None => break false,
             }
         }
@@ -238,6 +242,10 @@ impl SchedulerTrait for Scheduler {
         self.run_queues.read().len()
     }
 
+    fn runnable_len(&self) -> usize {
+        self.run_queues.read().runnable_len()
+    }
+
     fn schedule(&self, process: Process) -> Arc<Process> {
         debug_assert_ne!(
             Some(self.id),
@@ -316,13 +324,14 @@ impl SchedulerTrait for Scheduler {
         })
     }
 
-    fn spawn_module_function_arguments(
+    fn spawn_module_function_arguments_before_schedule(
         &self,
         parent: Option<&Process>,
         module: Atom,
         function: Atom,
         arguments: Vec<Term>,
         options: Options,
+        before_schedule: &mut dyn FnMut(&Process, &Connection),
     ) -> anyhow::Result<Spawned> {
         let (heap, heap_size) = options.sized_heap()?;
         let priority = options.cascaded_priority(parent);
@@ -345,6 +354,7 @@ impl SchedulerTrait for Scheduler {
         Self::runnable(&process, frame_with_arguments);
 
         let connection = options.connect(parent, &process);
+        before_schedule(&process, &connection);
 
         let arc_process = match parent {
             Some(parent) => parent.scheduler().unwrap().schedule(process),
@@ -357,6 +367,17 @@ impl SchedulerTrait for Scheduler {
         })
     }
 
+    fn steal(&self, candidates: &[Arc<dyn SchedulerTrait>]) -> Option<Arc<Process>> {
+        let arc_process = steal_for(&self.id, candidates)?;
+        self.run_queues.write().enqueue(arc_process.clone());
+
+        Some(arc_process)
+    }
+
+    fn steal_from(&self) -> Option<Arc<Process>> {
+        self.run_queues.write().steal()
+    }
+
     fn stop_waiting(&self, process: &Process) {
         process.stop_waiting();
         self.run_queues.write().stop_waiting(process);
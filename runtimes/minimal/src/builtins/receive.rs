@@ -149,7 +149,10 @@ pub extern "C-unwind" fn builtin_receive_start(timeout: Term) -> ReceiveContext
 /// to next.
 #[export_name = "__lumen_builtin_receive_next"]
 pub extern "C-unwind" fn builtin_receive_next(context: &mut ReceiveContext) -> ReceiveState {
-    let p = current_process();
+    next(&current_process(), context)
+}
+
+fn next(p: &Process, context: &mut ReceiveContext) -> ReceiveState {
     let mbox_lock = p.mailbox.lock();
     let mut mbox = mbox_lock.borrow_mut();
 
@@ -184,7 +187,10 @@ pub extern "C-unwind" fn builtin_receive_next(context: &mut ReceiveContext) -> R
 /// live at this stage.
 #[export_name = "__lumen_builtin_receive_pop"]
 pub extern "C-unwind" fn builtin_receive_pop(context: &mut ReceiveContext) {
-    let p = current_process();
+    pop(&current_process(), context)
+}
+
+fn pop(p: &Process, context: &mut ReceiveContext) {
     let mbox_lock = p.mailbox.lock();
     let mut mbox = mbox_lock.borrow_mut();
     // Remove the message at the current cursor
@@ -200,3 +206,141 @@ pub extern "C-unwind" fn builtin_receive_pop(context: &mut ReceiveContext) {
 pub extern "C-unwind" fn builtin_receive_done(context: &mut ReceiveContext) {
     context.cancel_timer();
 }
+
+/// The outcome of `receive_matching`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Received {
+    /// A message accepted by the match predicate was removed from the mailbox
+    Message(Term),
+    /// No accepted message was available, so the process was transitioned to the waiting state.
+    ///
+    /// Call `receive_matching` again with the same context once the process is resumed.
+    Wait,
+    /// The receive timed out
+    Timeout,
+}
+
+/// Starts a receive for natives that match messages in Rust rather than in generated code.
+pub fn receive_start(arc_process: Arc<Process>, timeout: Timeout) -> ReceiveContext {
+    ReceiveContext::new(arc_process, timeout)
+}
+
+/// Drives the receive state machine for `process` with `matches` in place of the generated
+/// `peek`.
+///
+/// Messages rejected by `matches` are not scanned again after resuming from `Received::Wait`, as
+/// the cursor in `context` stays on the last rejected message, so only newly arrived messages are
+/// peeked.  The context is cleaned up when returning `Received::Message` or `Received::Timeout`.
+pub fn receive_matching<F>(
+    process: &Process,
+    context: &mut ReceiveContext,
+    mut matches: F,
+) -> Received
+where
+    F: FnMut(Term) -> bool,
+{
+    loop {
+        match next(process, context) {
+            ReceiveState::Peek => {
+                let data = unsafe { &*context.message }.data();
+
+                if matches(data) {
+                    pop(process, context);
+                    builtin_receive_done(context);
+
+                    return Received::Message(data);
+                }
+            }
+            ReceiveState::Wait => return Received::Wait,
+            ReceiveState::Timeout => {
+                builtin_receive_done(context);
+
+                return Received::Timeout;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use liblumen_alloc::erts::process::alloc::{default_heap_size, heap};
+    use liblumen_alloc::erts::process::{Priority, Status};
+    use liblumen_alloc::erts::ModuleFunctionArity;
+
+    #[test]
+    fn receive_matching_skips_rejected_messages_and_removes_accepted_one() {
+        let arc_process = process();
+        let rejected = Atom::str_to_term("rejected");
+        let accepted = Atom::str_to_term("accepted");
+        arc_process.send_from_self(rejected);
+        arc_process.send_from_self(accepted);
+
+        let mut context = receive_start(arc_process.clone(), Timeout::Infinity);
+
+        assert_eq!(
+            receive_matching(&arc_process, &mut context, |message| message == accepted),
+            Received::Message(accepted)
+        );
+
+        let mut mailbox_data = Vec::new();
+        arc_process
+            .mailbox
+            .lock()
+            .borrow()
+            .iter()
+            .for_each(|message| mailbox_data.push(message.data()));
+
+        assert_eq!(mailbox_data, vec![rejected]);
+    }
+
+    #[test]
+    fn receive_matching_waits_without_rescanning_rejected_messages() {
+        let arc_process = process();
+        arc_process.send_from_self(Atom::str_to_term("first"));
+        arc_process.send_from_self(Atom::str_to_term("second"));
+
+        let accepted = Atom::str_to_term("accepted");
+        let mut peeked = Vec::new();
+        let mut context = receive_start(arc_process.clone(), Timeout::Infinity);
+
+        assert_eq!(
+            receive_matching(&arc_process, &mut context, |message| {
+                peeked.push(message);
+                message == accepted
+            }),
+            Received::Wait
+        );
+        assert_eq!(*arc_process.status.read(), Status::Waiting);
+        assert_eq!(peeked.len(), 2);
+
+        arc_process.send_from_self(accepted);
+        peeked.clear();
+
+        assert_eq!(
+            receive_matching(&arc_process, &mut context, |message| {
+                peeked.push(message);
+                message == accepted
+            }),
+            Received::Message(accepted)
+        );
+        assert_eq!(peeked, vec![accepted]);
+    }
+
+    fn process() -> Arc<Process> {
+        let heap_size = default_heap_size();
+
+        Arc::new(Process::new(
+            Priority::Normal,
+            None,
+            ModuleFunctionArity {
+                module: Atom::from_str("test"),
+                function: Atom::from_str("receive"),
+                arity: 0,
+            },
+            heap(heap_size).unwrap(),
+            heap_size,
+        ))
+    }
+}
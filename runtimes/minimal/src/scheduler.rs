@@ -21,7 +21,7 @@ use liblumen_core::locks::RwLock;
 use liblumen_core::util::thread_local::ThreadLocalCell;
 use liblumen_term::TermKind;
 
-use lumen_rt_core::process::spawn::options::Options;
+use lumen_rt_core::process::spawn::options::{Connection, Options};
 use lumen_rt_core::process::{log_exit, propagate_exit, CURRENT_PROCESS};
 use lumen_rt_core::registry::put_pid_to_process;
 use lumen_rt_core::scheduler::Scheduler as SchedulerTrait;
@@ -323,13 +323,14 @@ impl SchedulerTrait for Scheduler {
         })
     }
 
-    fn spawn_module_function_arguments(
+    fn spawn_module_function_arguments_before_schedule(
         &self,
         parent: Option<&Process>,
         module: Atom,
         function: Atom,
         arguments: Vec<Term>,
         options: Options,
+        before_schedule: &mut dyn FnMut(&Process, &Connection),
     ) -> anyhow::Result<Spawned> {
         let (heap, heap_size) = options.sized_heap()?;
         let priority = options.cascaded_priority(parent);
@@ -351,6 +352,7 @@ impl SchedulerTrait for Scheduler {
         Self::runnable(&process, init_fn, env);
 
         let connection = options.connect(parent, &process);
+        before_schedule(&process, &connection);
 
         let arc_process = match parent {
             Some(parent) => parent.scheduler().unwrap().schedule(process),